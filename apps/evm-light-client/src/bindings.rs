@@ -25,6 +25,28 @@ pub unsafe fn _export_light_client_update_cabi<T: Guest>(arg0: *mut u8, arg1: us
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
+pub unsafe fn _export_light_client_update_and_commit_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+) {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    T::light_client_update_and_commit(_rt::string_lift(bytes0));
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_light_client_update_optimistic_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+) {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    T::light_client_update_optimistic(_rt::string_lift(bytes0));
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
 pub unsafe fn _export_light_client_update_for_block_number_cabi<T: Guest>(
     arg0: *mut u8,
     arg1: usize,
@@ -86,16 +108,186 @@ pub unsafe fn _export_light_client_persist_cabi<T: Guest>(arg0: *mut u8, arg1: u
     let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
     T::light_client_persist(_rt::string_lift(bytes0));
 }
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_light_client_verify_account_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+) {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    T::light_client_verify_account(_rt::string_lift(bytes0));
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_light_client_verify_storage_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+) {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    T::light_client_verify_storage(_rt::string_lift(bytes0));
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_light_client_verify_log_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+) {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    T::light_client_verify_log(_rt::string_lift(bytes0));
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_light_client_verify_execution_header_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+) {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    T::light_client_verify_execution_header(_rt::string_lift(bytes0));
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_light_client_verify_transaction_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+) {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    T::light_client_verify_transaction(_rt::string_lift(bytes0));
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_light_client_get_state_cabi<T: Guest>(arg0: *mut u8, arg1: usize) {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    T::light_client_get_state(_rt::string_lift(bytes0));
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_light_client_get_header_at_slot_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+) {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    T::light_client_get_header_at_slot(_rt::string_lift(bytes0));
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_light_client_get_header_at_block_number_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+) {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    T::light_client_get_header_at_block_number(_rt::string_lift(bytes0));
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_light_client_audit_cabi<T: Guest>(arg0: *mut u8, arg1: usize) {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    T::light_client_audit(_rt::string_lift(bytes0));
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_light_client_submit_misbehaviour_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+) {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    T::light_client_submit_misbehaviour(_rt::string_lift(bytes0));
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_light_client_unfreeze_cabi<T: Guest>(arg0: *mut u8, arg1: usize) {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    T::light_client_unfreeze(_rt::string_lift(bytes0));
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_light_client_add_member_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+) {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    T::light_client_add_member(_rt::string_lift(bytes0));
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_light_client_remove_member_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+) {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    T::light_client_remove_member(_rt::string_lift(bytes0));
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_light_client_get_acl_cabi<T: Guest>(arg0: *mut u8, arg1: usize) {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    T::light_client_get_acl(_rt::string_lift(bytes0));
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_light_client_claim_owner_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+) {
+    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    T::light_client_claim_owner(_rt::string_lift(bytes0));
+}
 pub trait Guest {
     fn register_routes();
     fn light_client_init(cmd: _rt::String);
     fn light_client_update(cmd: _rt::String);
+    fn light_client_update_and_commit(cmd: _rt::String);
+    fn light_client_update_optimistic(cmd: _rt::String);
     fn light_client_update_for_block_number(cmd: _rt::String);
     fn light_client_update_for_period(cmd: _rt::String);
     fn light_client_update_for_slot(cmd: _rt::String);
     fn light_client_fetch_header_from_slot(cmd: _rt::String);
     fn light_client_fetch_block_from_slot(cmd: _rt::String);
     fn light_client_persist(cmd: _rt::String);
+    fn light_client_verify_account(cmd: _rt::String);
+    fn light_client_verify_storage(cmd: _rt::String);
+    fn light_client_verify_log(cmd: _rt::String);
+    fn light_client_verify_execution_header(cmd: _rt::String);
+    fn light_client_verify_transaction(cmd: _rt::String);
+    fn light_client_get_state(cmd: _rt::String);
+    fn light_client_get_header_at_slot(cmd: _rt::String);
+    fn light_client_get_header_at_block_number(cmd: _rt::String);
+    fn light_client_audit(cmd: _rt::String);
+    fn light_client_submit_misbehaviour(cmd: _rt::String);
+    fn light_client_unfreeze(cmd: _rt::String);
+    fn light_client_add_member(cmd: _rt::String);
+    fn light_client_remove_member(cmd: _rt::String);
+    fn light_client_get_acl(cmd: _rt::String);
+    fn light_client_claim_owner(cmd: _rt::String);
 }
 #[doc(hidden)]
 macro_rules! __export_world_evm_light_client_cabi {
@@ -108,8 +300,14 @@ macro_rules! __export_world_evm_light_client_cabi {
         #[export_name = "light-client-update"] unsafe extern "C" fn
         export_light_client_update(arg0 : * mut u8, arg1 : usize,) { $($path_to_types)*::
         _export_light_client_update_cabi::<$ty > (arg0, arg1) } #[export_name =
-        "light-client-update-for-block-number"] unsafe extern "C" fn
-        export_light_client_update_for_block_number(arg0 : * mut u8, arg1 : usize,) {
+        "light-client-update-and-commit"] unsafe extern "C" fn
+        export_light_client_update_and_commit(arg0 : * mut u8, arg1 : usize,) {
+        $($path_to_types)*:: _export_light_client_update_and_commit_cabi::<$ty > (arg0,
+        arg1) } #[export_name = "light-client-update-optimistic"] unsafe extern "C" fn
+        export_light_client_update_optimistic(arg0 : * mut u8, arg1 : usize,) {
+        $($path_to_types)*:: _export_light_client_update_optimistic_cabi::<$ty > (arg0,
+        arg1) } #[export_name = "light-client-update-for-block-number"] unsafe extern "C"
+        fn export_light_client_update_for_block_number(arg0 : * mut u8, arg1 : usize,) {
         $($path_to_types)*:: _export_light_client_update_for_block_number_cabi::<$ty >
         (arg0, arg1) } #[export_name = "light-client-update-for-period"] unsafe extern
         "C" fn export_light_client_update_for_period(arg0 : * mut u8, arg1 : usize,) {
@@ -126,9 +324,1322 @@ macro_rules! __export_world_evm_light_client_cabi {
         _export_light_client_fetch_block_from_slot_cabi::<$ty > (arg0, arg1) }
         #[export_name = "light-client-persist"] unsafe extern "C" fn
         export_light_client_persist(arg0 : * mut u8, arg1 : usize,) {
-        $($path_to_types)*:: _export_light_client_persist_cabi::<$ty > (arg0, arg1) } };
+        $($path_to_types)*:: _export_light_client_persist_cabi::<$ty > (arg0, arg1) }
+        #[export_name = "light-client-verify-account"] unsafe extern "C" fn
+        export_light_client_verify_account(arg0 : * mut u8, arg1 : usize,) {
+        $($path_to_types)*:: _export_light_client_verify_account_cabi::<$ty > (arg0,
+        arg1) } #[export_name = "light-client-verify-storage"] unsafe extern "C" fn
+        export_light_client_verify_storage(arg0 : * mut u8, arg1 : usize,) {
+        $($path_to_types)*:: _export_light_client_verify_storage_cabi::<$ty > (arg0,
+        arg1) } #[export_name = "light-client-verify-log"] unsafe extern "C" fn
+        export_light_client_verify_log(arg0 : * mut u8, arg1 : usize,) {
+        $($path_to_types)*:: _export_light_client_verify_log_cabi::<$ty > (arg0, arg1) }
+        #[export_name = "light-client-verify-execution-header"] unsafe extern "C" fn
+        export_light_client_verify_execution_header(arg0 : * mut u8, arg1 : usize,) {
+        $($path_to_types)*:: _export_light_client_verify_execution_header_cabi::<$ty >
+        (arg0, arg1) } #[export_name = "light-client-verify-transaction"] unsafe extern
+        "C" fn export_light_client_verify_transaction(arg0 : * mut u8, arg1 : usize,) {
+        $($path_to_types)*:: _export_light_client_verify_transaction_cabi::<$ty > (arg0,
+        arg1) } #[export_name = "light-client-get-state"] unsafe extern "C" fn
+        export_light_client_get_state(arg0 : * mut u8, arg1 : usize,) {
+        $($path_to_types)*:: _export_light_client_get_state_cabi::<$ty > (arg0, arg1) }
+        #[export_name = "light-client-get-header-at-slot"] unsafe extern "C" fn
+        export_light_client_get_header_at_slot(arg0 : * mut u8, arg1 : usize,) {
+        $($path_to_types)*:: _export_light_client_get_header_at_slot_cabi::<$ty > (arg0,
+        arg1) } #[export_name = "light-client-get-header-at-block-number"] unsafe extern
+        "C" fn export_light_client_get_header_at_block_number(arg0 : * mut u8, arg1 :
+        usize,) { $($path_to_types)*::
+        _export_light_client_get_header_at_block_number_cabi::<$ty > (arg0, arg1) }
+        #[export_name = "light-client-audit"] unsafe extern "C" fn
+        export_light_client_audit(arg0 : * mut u8, arg1 : usize,) { $($path_to_types)*::
+        _export_light_client_audit_cabi::<$ty > (arg0, arg1) } #[export_name =
+        "light-client-submit-misbehaviour"] unsafe extern "C" fn
+        export_light_client_submit_misbehaviour(arg0 : * mut u8, arg1 : usize,) {
+        $($path_to_types)*:: _export_light_client_submit_misbehaviour_cabi::<$ty > (arg0,
+        arg1) } #[export_name = "light-client-unfreeze"] unsafe extern "C" fn
+        export_light_client_unfreeze(arg0 : * mut u8, arg1 : usize,) {
+        $($path_to_types)*:: _export_light_client_unfreeze_cabi::<$ty > (arg0, arg1) }
+        #[export_name = "light-client-add-member"] unsafe extern "C" fn
+        export_light_client_add_member(arg0 : * mut u8, arg1 : usize,) {
+        $($path_to_types)*:: _export_light_client_add_member_cabi::<$ty > (arg0, arg1) }
+        #[export_name = "light-client-remove-member"] unsafe extern "C" fn
+        export_light_client_remove_member(arg0 : * mut u8, arg1 : usize,) {
+        $($path_to_types)*:: _export_light_client_remove_member_cabi::<$ty > (arg0, arg1)
+        } #[export_name = "light-client-get-acl"] unsafe extern "C" fn
+        export_light_client_get_acl(arg0 : * mut u8, arg1 : usize,) {
+        $($path_to_types)*:: _export_light_client_get_acl_cabi::<$ty > (arg0, arg1) }
+        #[export_name = "light-client-claim-owner"] unsafe extern "C" fn
+        export_light_client_claim_owner(arg0 : * mut u8, arg1 : usize,) {
+        $($path_to_types)*:: _export_light_client_claim_owner_cabi::<$ty > (arg0, arg1)
+        } };
     };
 }
+#[allow(dead_code)]
+pub mod exports {
+    #[allow(dead_code)]
+    pub mod component {
+        #[allow(dead_code)]
+        pub mod evm_light_client {
+            /// Typed entry points of the light client for hosts and components that call it directly.
+            /// The routes of the world take the same parameters as JSON and send their results through the notifier.
+            #[allow(dead_code, clippy::all)]
+            pub mod light_client {
+                #[used]
+                #[doc(hidden)]
+                static __FORCE_SECTION_REF: fn() = super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                /// network name and the beacon endpoints, tried in order
+                #[derive(Clone)]
+                pub struct Network {
+                    pub name: _rt::String,
+                    pub beacon_endpoints: _rt::Vec<_rt::String>,
+                }
+                impl ::core::fmt::Debug for Network {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("Network")
+                            .field("name", &self.name)
+                            .field("beacon-endpoints", &self.beacon_endpoints)
+                            .finish()
+                    }
+                }
+                #[derive(Clone)]
+                pub struct InitParams {
+                    pub network: Network,
+                    pub trusted_block_root: Option<_rt::String>,
+                    /// the trusted block root is the root of the header at this slot
+                    pub untrusted_slot: Option<u64>,
+                }
+                impl ::core::fmt::Debug for InitParams {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("InitParams")
+                            .field("network", &self.network)
+                            .field("trusted-block-root", &self.trusted_block_root)
+                            .field("untrusted-slot", &self.untrusted_slot)
+                            .finish()
+                    }
+                }
+                #[derive(Clone, Copy)]
+                pub enum UpdateTarget {
+                    Latest,
+                    BlockNumber(u64),
+                    Period(u64),
+                    Slot(u64),
+                }
+                impl ::core::fmt::Debug for UpdateTarget {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            UpdateTarget::Latest => {
+                                f.debug_tuple("UpdateTarget::Latest").finish()
+                            }
+                            UpdateTarget::BlockNumber(e) => {
+                                f.debug_tuple("UpdateTarget::BlockNumber").field(e).finish()
+                            }
+                            UpdateTarget::Period(e) => {
+                                f.debug_tuple("UpdateTarget::Period").field(e).finish()
+                            }
+                            UpdateTarget::Slot(e) => {
+                                f.debug_tuple("UpdateTarget::Slot").field(e).finish()
+                            }
+                        }
+                    }
+                }
+                #[repr(u8)]
+                #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+                pub enum Anchor {
+                    Finalized,
+                    Optimistic,
+                }
+                impl ::core::fmt::Debug for Anchor {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            Anchor::Finalized => f.debug_tuple("Anchor::Finalized").finish(),
+                            Anchor::Optimistic => {
+                                f.debug_tuple("Anchor::Optimistic").finish()
+                            }
+                        }
+                    }
+                }
+                impl Anchor {
+                    #[doc(hidden)]
+                    pub unsafe fn _lift(val: u8) -> Anchor {
+                        if !cfg!(debug_assertions) {
+                            return ::core::mem::transmute(val);
+                        }
+                        match val {
+                            0 => Anchor::Finalized,
+                            1 => Anchor::Optimistic,
+                            _ => panic!("invalid enum discriminant"),
+                        }
+                    }
+                }
+                /// parameters of the verified reads, which only read the stored light client state
+                #[derive(Clone)]
+                pub struct ReadParams {
+                    pub network_name: _rt::String,
+                    pub block_number: Option<u64>,
+                    pub anchor: Option<Anchor>,
+                    pub execution_endpoint: Option<_rt::String>,
+                }
+                impl ::core::fmt::Debug for ReadParams {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("ReadParams")
+                            .field("network-name", &self.network_name)
+                            .field("block-number", &self.block_number)
+                            .field("anchor", &self.anchor)
+                            .field("execution-endpoint", &self.execution_endpoint)
+                            .finish()
+                    }
+                }
+                #[derive(Clone)]
+                pub struct StateSummary {
+                    pub finalized_slot: u64,
+                    pub finalized_block_number: u64,
+                    pub finalized_block_hash: _rt::String,
+                    pub finalized_state_root: _rt::String,
+                    pub optimistic_slot: Option<u64>,
+                }
+                impl ::core::fmt::Debug for StateSummary {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("StateSummary")
+                            .field("finalized-slot", &self.finalized_slot)
+                            .field("finalized-block-number", &self.finalized_block_number)
+                            .field("finalized-block-hash", &self.finalized_block_hash)
+                            .field("finalized-state-root", &self.finalized_state_root)
+                            .field("optimistic-slot", &self.optimistic_slot)
+                            .finish()
+                    }
+                }
+                /// the bootstrap, state and genesis to persist with `light-client-persist`
+                #[derive(Clone)]
+                pub struct PersistInfo {
+                    pub bootstrap_info: _rt::String,
+                    pub state_info: _rt::String,
+                    pub genesis_info: _rt::String,
+                    pub state: StateSummary,
+                }
+                impl ::core::fmt::Debug for PersistInfo {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("PersistInfo")
+                            .field("bootstrap-info", &self.bootstrap_info)
+                            .field("state-info", &self.state_info)
+                            .field("genesis-info", &self.genesis_info)
+                            .field("state", &self.state)
+                            .finish()
+                    }
+                }
+                #[derive(Clone)]
+                pub struct UpdateResult {
+                    pub state: StateSummary,
+                    /// the new state to persist with `light-client-persist`, none if the state is up to date
+                    pub state_info: Option<_rt::String>,
+                    /// the consensus updates that led to `state-info`, archived by `light-client-persist`
+                    pub updates_info: Option<_rt::String>,
+                }
+                impl ::core::fmt::Debug for UpdateResult {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("UpdateResult")
+                            .field("state", &self.state)
+                            .field("state-info", &self.state_info)
+                            .field("updates-info", &self.updates_info)
+                            .finish()
+                    }
+                }
+                #[derive(Clone)]
+                pub struct VerifiedAccount {
+                    pub address: _rt::String,
+                    pub exists: bool,
+                    pub nonce: u64,
+                    pub balance: _rt::String,
+                    pub storage_root: _rt::String,
+                    pub code_hash: _rt::String,
+                    pub block_number: u64,
+                    pub state_root: _rt::String,
+                }
+                impl ::core::fmt::Debug for VerifiedAccount {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("VerifiedAccount")
+                            .field("address", &self.address)
+                            .field("exists", &self.exists)
+                            .field("nonce", &self.nonce)
+                            .field("balance", &self.balance)
+                            .field("storage-root", &self.storage_root)
+                            .field("code-hash", &self.code_hash)
+                            .field("block-number", &self.block_number)
+                            .field("state-root", &self.state_root)
+                            .finish()
+                    }
+                }
+                #[derive(Clone)]
+                pub struct StorageSlot {
+                    pub key: _rt::String,
+                    pub value: _rt::String,
+                }
+                impl ::core::fmt::Debug for StorageSlot {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("StorageSlot")
+                            .field("key", &self.key)
+                            .field("value", &self.value)
+                            .finish()
+                    }
+                }
+                #[derive(Clone)]
+                pub struct VerifiedStorage {
+                    pub account: VerifiedAccount,
+                    pub storage: _rt::Vec<StorageSlot>,
+                }
+                impl ::core::fmt::Debug for VerifiedStorage {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("VerifiedStorage")
+                            .field("account", &self.account)
+                            .field("storage", &self.storage)
+                            .finish()
+                    }
+                }
+                /// `code` is one of the error codes of the route responses, `details` is a JSON object
+                #[derive(Clone)]
+                pub struct ErrorInfo {
+                    pub code: _rt::String,
+                    pub message: _rt::String,
+                    pub details: Option<_rt::String>,
+                }
+                impl ::core::fmt::Debug for ErrorInfo {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("ErrorInfo")
+                            .field("code", &self.code)
+                            .field("message", &self.message)
+                            .field("details", &self.details)
+                            .finish()
+                    }
+                }
+                impl ::core::fmt::Display for ErrorInfo {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        write!(f, "{:?}", self)
+                    }
+                }
+                impl std::error::Error for ErrorInfo {}
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_init_cabi<T: Guest>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                    arg2: *mut u8,
+                    arg3: usize,
+                    arg4: i32,
+                    arg5: *mut u8,
+                    arg6: usize,
+                    arg7: i32,
+                    arg8: i64,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg1;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+                    let base4 = arg2;
+                    let len4 = arg3;
+                    let mut result4 = _rt::Vec::with_capacity(len4);
+                    for i in 0..len4 {
+                        let base = base4.add(i * 8);
+                        let e4 = {
+                            let l1 = *base.add(0).cast::<*mut u8>();
+                            let l2 = *base.add(4).cast::<usize>();
+                            let len3 = l2;
+                            let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+                            _rt::string_lift(bytes3)
+                        };
+                        result4.push(e4);
+                    }
+                    _rt::cabi_dealloc(base4, len4 * 8, 4);
+                    let result6 = T::init(InitParams {
+                        network: Network {
+                            name: _rt::string_lift(bytes0),
+                            beacon_endpoints: result4,
+                        },
+                        trusted_block_root: match arg4 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    let len5 = arg6;
+                                    let bytes5 = _rt::Vec::from_raw_parts(
+                                        arg5.cast(),
+                                        len5,
+                                        len5,
+                                    );
+                                    _rt::string_lift(bytes5)
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        untrusted_slot: match arg7 {
+                            0 => None,
+                            1 => {
+                                let e = arg8 as u64;
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                    });
+                    let ptr7 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result6 {
+                        Ok(e) => {
+                            *ptr7.add(0).cast::<u8>() = (0i32) as u8;
+                            let PersistInfo {
+                                bootstrap_info: bootstrap_info8,
+                                state_info: state_info8,
+                                genesis_info: genesis_info8,
+                                state: state8,
+                            } = e;
+                            let vec9 = (bootstrap_info8.into_bytes()).into_boxed_slice();
+                            let ptr9 = vec9.as_ptr().cast::<u8>();
+                            let len9 = vec9.len();
+                            ::core::mem::forget(vec9);
+                            *ptr7.add(12).cast::<usize>() = len9;
+                            *ptr7.add(8).cast::<*mut u8>() = ptr9.cast_mut();
+                            let vec10 = (state_info8.into_bytes()).into_boxed_slice();
+                            let ptr10 = vec10.as_ptr().cast::<u8>();
+                            let len10 = vec10.len();
+                            ::core::mem::forget(vec10);
+                            *ptr7.add(20).cast::<usize>() = len10;
+                            *ptr7.add(16).cast::<*mut u8>() = ptr10.cast_mut();
+                            let vec11 = (genesis_info8.into_bytes()).into_boxed_slice();
+                            let ptr11 = vec11.as_ptr().cast::<u8>();
+                            let len11 = vec11.len();
+                            ::core::mem::forget(vec11);
+                            *ptr7.add(28).cast::<usize>() = len11;
+                            *ptr7.add(24).cast::<*mut u8>() = ptr11.cast_mut();
+                            let StateSummary {
+                                finalized_slot: finalized_slot101,
+                                finalized_block_number: finalized_block_number101,
+                                finalized_block_hash: finalized_block_hash101,
+                                finalized_state_root: finalized_state_root101,
+                                optimistic_slot: optimistic_slot101,
+                            } = state8;
+                            *ptr7.add(32).cast::<i64>() = _rt::as_i64(finalized_slot101);
+                            *ptr7.add(40).cast::<i64>() = _rt::as_i64(finalized_block_number101);
+                            let vec102 = (finalized_block_hash101.into_bytes()).into_boxed_slice();
+                            let ptr102 = vec102.as_ptr().cast::<u8>();
+                            let len102 = vec102.len();
+                            ::core::mem::forget(vec102);
+                            *ptr7.add(52).cast::<usize>() = len102;
+                            *ptr7.add(48).cast::<*mut u8>() = ptr102.cast_mut();
+                            let vec103 = (finalized_state_root101.into_bytes()).into_boxed_slice();
+                            let ptr103 = vec103.as_ptr().cast::<u8>();
+                            let len103 = vec103.len();
+                            ::core::mem::forget(vec103);
+                            *ptr7.add(60).cast::<usize>() = len103;
+                            *ptr7.add(56).cast::<*mut u8>() = ptr103.cast_mut();
+                            match optimistic_slot101 {
+                                Some(e) => {
+                                    *ptr7.add(64).cast::<u8>() = (1i32) as u8;
+                                    *ptr7.add(72).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                None => {
+                                    *ptr7.add(64).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                        }
+                        Err(e) => {
+                            *ptr7.add(0).cast::<u8>() = (1i32) as u8;
+                            let ErrorInfo {
+                                code: code104,
+                                message: message104,
+                                details: details104,
+                            } = e;
+                            let vec105 = (code104.into_bytes()).into_boxed_slice();
+                            let ptr105 = vec105.as_ptr().cast::<u8>();
+                            let len105 = vec105.len();
+                            ::core::mem::forget(vec105);
+                            *ptr7.add(12).cast::<usize>() = len105;
+                            *ptr7.add(8).cast::<*mut u8>() = ptr105.cast_mut();
+                            let vec106 = (message104.into_bytes()).into_boxed_slice();
+                            let ptr106 = vec106.as_ptr().cast::<u8>();
+                            let len106 = vec106.len();
+                            ::core::mem::forget(vec106);
+                            *ptr7.add(20).cast::<usize>() = len106;
+                            *ptr7.add(16).cast::<*mut u8>() = ptr106.cast_mut();
+                            match details104 {
+                                Some(e) => {
+                                    *ptr7.add(24).cast::<u8>() = (1i32) as u8;
+                                    let vec107 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr107 = vec107.as_ptr().cast::<u8>();
+                                    let len107 = vec107.len();
+                                    ::core::mem::forget(vec107);
+                                    *ptr7.add(32).cast::<usize>() = len107;
+                                    *ptr7.add(28).cast::<*mut u8>() = ptr107.cast_mut();
+                                }
+                                None => {
+                                    *ptr7.add(24).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                        }
+                    };
+                    ptr7
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_init<T: Guest>(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0.add(12).cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                            let l3 = *arg0.add(16).cast::<*mut u8>();
+                            let l4 = *arg0.add(20).cast::<usize>();
+                            _rt::cabi_dealloc(l3, l4, 1);
+                            let l5 = *arg0.add(24).cast::<*mut u8>();
+                            let l6 = *arg0.add(28).cast::<usize>();
+                            _rt::cabi_dealloc(l5, l6, 1);
+                            let l108 = *arg0.add(48).cast::<*mut u8>();
+                            let l109 = *arg0.add(52).cast::<usize>();
+                            _rt::cabi_dealloc(l108, l109, 1);
+                            let l110 = *arg0.add(56).cast::<*mut u8>();
+                            let l111 = *arg0.add(60).cast::<usize>();
+                            _rt::cabi_dealloc(l110, l111, 1);
+                        }
+                        _ => {
+                            let l112 = *arg0.add(8).cast::<*mut u8>();
+                            let l113 = *arg0.add(12).cast::<usize>();
+                            _rt::cabi_dealloc(l112, l113, 1);
+                            let l114 = *arg0.add(16).cast::<*mut u8>();
+                            let l115 = *arg0.add(20).cast::<usize>();
+                            _rt::cabi_dealloc(l114, l115, 1);
+                            let l116 = i32::from(*arg0.add(24).cast::<u8>());
+                            match l116 {
+                                0 => {}
+                                _ => {
+                                    let l117 = *arg0.add(28).cast::<*mut u8>();
+                                    let l118 = *arg0.add(32).cast::<usize>();
+                                    _rt::cabi_dealloc(l117, l118, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_update_cabi<T: Guest>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                    arg2: *mut u8,
+                    arg3: usize,
+                    arg4: *mut u8,
+                    arg5: usize,
+                    arg6: i32,
+                    arg7: i64,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg1;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+                    let base4 = arg2;
+                    let len4 = arg3;
+                    let mut result4 = _rt::Vec::with_capacity(len4);
+                    for i in 0..len4 {
+                        let base = base4.add(i * 8);
+                        let e4 = {
+                            let l1 = *base.add(0).cast::<*mut u8>();
+                            let l2 = *base.add(4).cast::<usize>();
+                            let len3 = l2;
+                            let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+                            _rt::string_lift(bytes3)
+                        };
+                        result4.push(e4);
+                    }
+                    _rt::cabi_dealloc(base4, len4 * 8, 4);
+                    let base8 = arg4;
+                    let len8 = arg5;
+                    let mut result8 = _rt::Vec::with_capacity(len8);
+                    for i in 0..len8 {
+                        let base = base8.add(i * 8);
+                        let e8 = {
+                            let l5 = *base.add(0).cast::<*mut u8>();
+                            let l6 = *base.add(4).cast::<usize>();
+                            let len7 = l6;
+                            let bytes7 = _rt::Vec::from_raw_parts(l5.cast(), len7, len7);
+                            _rt::string_lift(bytes7)
+                        };
+                        result8.push(e8);
+                    }
+                    _rt::cabi_dealloc(base8, len8 * 8, 4);
+                    let v9 = match arg6 {
+                        0 => UpdateTarget::Latest,
+                        1 => {
+                            let e9 = arg7 as u64;
+                            UpdateTarget::BlockNumber(e9)
+                        }
+                        2 => {
+                            let e9 = arg7 as u64;
+                            UpdateTarget::Period(e9)
+                        }
+                        n => {
+                            debug_assert_eq!(n, 3, "invalid enum discriminant");
+                            let e9 = arg7 as u64;
+                            UpdateTarget::Slot(e9)
+                        }
+                    };
+                    let result10 = T::update(
+                        Network {
+                            name: _rt::string_lift(bytes0),
+                            beacon_endpoints: result4,
+                        },
+                        result8,
+                        v9,
+                    );
+                    let ptr11 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result10 {
+                        Ok(e) => {
+                            *ptr11.add(0).cast::<u8>() = (0i32) as u8;
+                            let UpdateResult {
+                                state: state12,
+                                state_info: state_info12,
+                                updates_info: updates_info12,
+                            } = e;
+                            let StateSummary {
+                                finalized_slot: finalized_slot119,
+                                finalized_block_number: finalized_block_number119,
+                                finalized_block_hash: finalized_block_hash119,
+                                finalized_state_root: finalized_state_root119,
+                                optimistic_slot: optimistic_slot119,
+                            } = state12;
+                            *ptr11.add(8).cast::<i64>() = _rt::as_i64(finalized_slot119);
+                            *ptr11.add(16).cast::<i64>() = _rt::as_i64(finalized_block_number119);
+                            let vec120 = (finalized_block_hash119.into_bytes()).into_boxed_slice();
+                            let ptr120 = vec120.as_ptr().cast::<u8>();
+                            let len120 = vec120.len();
+                            ::core::mem::forget(vec120);
+                            *ptr11.add(28).cast::<usize>() = len120;
+                            *ptr11.add(24).cast::<*mut u8>() = ptr120.cast_mut();
+                            let vec121 = (finalized_state_root119.into_bytes()).into_boxed_slice();
+                            let ptr121 = vec121.as_ptr().cast::<u8>();
+                            let len121 = vec121.len();
+                            ::core::mem::forget(vec121);
+                            *ptr11.add(36).cast::<usize>() = len121;
+                            *ptr11.add(32).cast::<*mut u8>() = ptr121.cast_mut();
+                            match optimistic_slot119 {
+                                Some(e) => {
+                                    *ptr11.add(40).cast::<u8>() = (1i32) as u8;
+                                    *ptr11.add(48).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                None => {
+                                    *ptr11.add(40).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match state_info12 {
+                                Some(e) => {
+                                    *ptr11.add(56).cast::<u8>() = (1i32) as u8;
+                                    let vec13 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                                    let len13 = vec13.len();
+                                    ::core::mem::forget(vec13);
+                                    *ptr11.add(64).cast::<usize>() = len13;
+                                    *ptr11.add(60).cast::<*mut u8>() = ptr13.cast_mut();
+                                }
+                                None => {
+                                    *ptr11.add(56).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match updates_info12 {
+                                Some(e) => {
+                                    *ptr11.add(68).cast::<u8>() = (1i32) as u8;
+                                    let vec14 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                                    let len14 = vec14.len();
+                                    ::core::mem::forget(vec14);
+                                    *ptr11.add(76).cast::<usize>() = len14;
+                                    *ptr11.add(72).cast::<*mut u8>() = ptr14.cast_mut();
+                                }
+                                None => {
+                                    *ptr11.add(68).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                        }
+                        Err(e) => {
+                            *ptr11.add(0).cast::<u8>() = (1i32) as u8;
+                            let ErrorInfo {
+                                code: code122,
+                                message: message122,
+                                details: details122,
+                            } = e;
+                            let vec123 = (code122.into_bytes()).into_boxed_slice();
+                            let ptr123 = vec123.as_ptr().cast::<u8>();
+                            let len123 = vec123.len();
+                            ::core::mem::forget(vec123);
+                            *ptr11.add(12).cast::<usize>() = len123;
+                            *ptr11.add(8).cast::<*mut u8>() = ptr123.cast_mut();
+                            let vec124 = (message122.into_bytes()).into_boxed_slice();
+                            let ptr124 = vec124.as_ptr().cast::<u8>();
+                            let len124 = vec124.len();
+                            ::core::mem::forget(vec124);
+                            *ptr11.add(20).cast::<usize>() = len124;
+                            *ptr11.add(16).cast::<*mut u8>() = ptr124.cast_mut();
+                            match details122 {
+                                Some(e) => {
+                                    *ptr11.add(24).cast::<u8>() = (1i32) as u8;
+                                    let vec125 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr125 = vec125.as_ptr().cast::<u8>();
+                                    let len125 = vec125.len();
+                                    ::core::mem::forget(vec125);
+                                    *ptr11.add(32).cast::<usize>() = len125;
+                                    *ptr11.add(28).cast::<*mut u8>() = ptr125.cast_mut();
+                                }
+                                None => {
+                                    *ptr11.add(24).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                        }
+                    };
+                    ptr11
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_update<T: Guest>(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l126 = *arg0.add(24).cast::<*mut u8>();
+                            let l127 = *arg0.add(28).cast::<usize>();
+                            _rt::cabi_dealloc(l126, l127, 1);
+                            let l128 = *arg0.add(32).cast::<*mut u8>();
+                            let l129 = *arg0.add(36).cast::<usize>();
+                            _rt::cabi_dealloc(l128, l129, 1);
+                            let l1 = i32::from(*arg0.add(56).cast::<u8>());
+                            match l1 {
+                                0 => {}
+                                _ => {
+                                    let l2 = *arg0.add(60).cast::<*mut u8>();
+                                    let l3 = *arg0.add(64).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                            }
+                            let l4 = i32::from(*arg0.add(68).cast::<u8>());
+                            match l4 {
+                                0 => {}
+                                _ => {
+                                    let l5 = *arg0.add(72).cast::<*mut u8>();
+                                    let l6 = *arg0.add(76).cast::<usize>();
+                                    _rt::cabi_dealloc(l5, l6, 1);
+                                }
+                            }
+                        }
+                        _ => {
+                            let l130 = *arg0.add(8).cast::<*mut u8>();
+                            let l131 = *arg0.add(12).cast::<usize>();
+                            _rt::cabi_dealloc(l130, l131, 1);
+                            let l132 = *arg0.add(16).cast::<*mut u8>();
+                            let l133 = *arg0.add(20).cast::<usize>();
+                            _rt::cabi_dealloc(l132, l133, 1);
+                            let l134 = i32::from(*arg0.add(24).cast::<u8>());
+                            match l134 {
+                                0 => {}
+                                _ => {
+                                    let l135 = *arg0.add(28).cast::<*mut u8>();
+                                    let l136 = *arg0.add(32).cast::<usize>();
+                                    _rt::cabi_dealloc(l135, l136, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_verify_account_cabi<T: Guest>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                    arg2: i32,
+                    arg3: i64,
+                    arg4: i32,
+                    arg5: i32,
+                    arg6: i32,
+                    arg7: *mut u8,
+                    arg8: usize,
+                    arg9: *mut u8,
+                    arg10: usize,
+                    arg11: i32,
+                    arg12: *mut u8,
+                    arg13: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len191 = arg1;
+                    let bytes191 = _rt::Vec::from_raw_parts(arg0.cast(), len191, len191);
+                    let params0 = ReadParams {
+                        network_name: _rt::string_lift(bytes191),
+                        block_number: match arg2 {
+                            0 => None,
+                            1 => {
+                                let e = arg3 as u64;
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        anchor: match arg4 {
+                            0 => None,
+                            1 => {
+                                let e = Anchor::_lift(arg5 as u8);
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        execution_endpoint: match arg6 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    let len192 = arg8;
+                                    let bytes192 = _rt::Vec::from_raw_parts(
+                                        arg7.cast(),
+                                        len192,
+                                        len192,
+                                    );
+                                    _rt::string_lift(bytes192)
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                    };
+                    let len1 = arg10;
+                    let bytes1 = _rt::Vec::from_raw_parts(arg9.cast(), len1, len1);
+                    let result3 = T::verify_account(
+                        params0,
+                        _rt::string_lift(bytes1),
+                        match arg11 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    let len2 = arg13;
+                                    let bytes2 = _rt::Vec::from_raw_parts(
+                                        arg12.cast(),
+                                        len2,
+                                        len2,
+                                    );
+                                    _rt::string_lift(bytes2)
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                    );
+                    let ptr4 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result3 {
+                        Ok(e) => {
+                            *ptr4.add(0).cast::<u8>() = (0i32) as u8;
+                            let VerifiedAccount {
+                                address: address137,
+                                exists: exists137,
+                                nonce: nonce137,
+                                balance: balance137,
+                                storage_root: storage_root137,
+                                code_hash: code_hash137,
+                                block_number: block_number137,
+                                state_root: state_root137,
+                            } = e;
+                            let vec138 = (address137.into_bytes()).into_boxed_slice();
+                            let ptr138 = vec138.as_ptr().cast::<u8>();
+                            let len138 = vec138.len();
+                            ::core::mem::forget(vec138);
+                            *ptr4.add(12).cast::<usize>() = len138;
+                            *ptr4.add(8).cast::<*mut u8>() = ptr138.cast_mut();
+                            *ptr4.add(16).cast::<u8>() = (match exists137 {
+                                true => 1,
+                                false => 0,
+                            }) as u8;
+                            *ptr4.add(24).cast::<i64>() = _rt::as_i64(nonce137);
+                            let vec139 = (balance137.into_bytes()).into_boxed_slice();
+                            let ptr139 = vec139.as_ptr().cast::<u8>();
+                            let len139 = vec139.len();
+                            ::core::mem::forget(vec139);
+                            *ptr4.add(36).cast::<usize>() = len139;
+                            *ptr4.add(32).cast::<*mut u8>() = ptr139.cast_mut();
+                            let vec140 = (storage_root137.into_bytes()).into_boxed_slice();
+                            let ptr140 = vec140.as_ptr().cast::<u8>();
+                            let len140 = vec140.len();
+                            ::core::mem::forget(vec140);
+                            *ptr4.add(44).cast::<usize>() = len140;
+                            *ptr4.add(40).cast::<*mut u8>() = ptr140.cast_mut();
+                            let vec141 = (code_hash137.into_bytes()).into_boxed_slice();
+                            let ptr141 = vec141.as_ptr().cast::<u8>();
+                            let len141 = vec141.len();
+                            ::core::mem::forget(vec141);
+                            *ptr4.add(52).cast::<usize>() = len141;
+                            *ptr4.add(48).cast::<*mut u8>() = ptr141.cast_mut();
+                            *ptr4.add(56).cast::<i64>() = _rt::as_i64(block_number137);
+                            let vec142 = (state_root137.into_bytes()).into_boxed_slice();
+                            let ptr142 = vec142.as_ptr().cast::<u8>();
+                            let len142 = vec142.len();
+                            ::core::mem::forget(vec142);
+                            *ptr4.add(68).cast::<usize>() = len142;
+                            *ptr4.add(64).cast::<*mut u8>() = ptr142.cast_mut();
+                        }
+                        Err(e) => {
+                            *ptr4.add(0).cast::<u8>() = (1i32) as u8;
+                            let ErrorInfo {
+                                code: code143,
+                                message: message143,
+                                details: details143,
+                            } = e;
+                            let vec144 = (code143.into_bytes()).into_boxed_slice();
+                            let ptr144 = vec144.as_ptr().cast::<u8>();
+                            let len144 = vec144.len();
+                            ::core::mem::forget(vec144);
+                            *ptr4.add(12).cast::<usize>() = len144;
+                            *ptr4.add(8).cast::<*mut u8>() = ptr144.cast_mut();
+                            let vec145 = (message143.into_bytes()).into_boxed_slice();
+                            let ptr145 = vec145.as_ptr().cast::<u8>();
+                            let len145 = vec145.len();
+                            ::core::mem::forget(vec145);
+                            *ptr4.add(20).cast::<usize>() = len145;
+                            *ptr4.add(16).cast::<*mut u8>() = ptr145.cast_mut();
+                            match details143 {
+                                Some(e) => {
+                                    *ptr4.add(24).cast::<u8>() = (1i32) as u8;
+                                    let vec146 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr146 = vec146.as_ptr().cast::<u8>();
+                                    let len146 = vec146.len();
+                                    ::core::mem::forget(vec146);
+                                    *ptr4.add(32).cast::<usize>() = len146;
+                                    *ptr4.add(28).cast::<*mut u8>() = ptr146.cast_mut();
+                                }
+                                None => {
+                                    *ptr4.add(24).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                        }
+                    };
+                    ptr4
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_verify_account<T: Guest>(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l147 = *arg0.add(8).cast::<*mut u8>();
+                            let l148 = *arg0.add(12).cast::<usize>();
+                            _rt::cabi_dealloc(l147, l148, 1);
+                            let l149 = *arg0.add(32).cast::<*mut u8>();
+                            let l150 = *arg0.add(36).cast::<usize>();
+                            _rt::cabi_dealloc(l149, l150, 1);
+                            let l151 = *arg0.add(40).cast::<*mut u8>();
+                            let l152 = *arg0.add(44).cast::<usize>();
+                            _rt::cabi_dealloc(l151, l152, 1);
+                            let l153 = *arg0.add(48).cast::<*mut u8>();
+                            let l154 = *arg0.add(52).cast::<usize>();
+                            _rt::cabi_dealloc(l153, l154, 1);
+                            let l155 = *arg0.add(64).cast::<*mut u8>();
+                            let l156 = *arg0.add(68).cast::<usize>();
+                            _rt::cabi_dealloc(l155, l156, 1);
+                        }
+                        _ => {
+                            let l157 = *arg0.add(8).cast::<*mut u8>();
+                            let l158 = *arg0.add(12).cast::<usize>();
+                            _rt::cabi_dealloc(l157, l158, 1);
+                            let l159 = *arg0.add(16).cast::<*mut u8>();
+                            let l160 = *arg0.add(20).cast::<usize>();
+                            _rt::cabi_dealloc(l159, l160, 1);
+                            let l161 = i32::from(*arg0.add(24).cast::<u8>());
+                            match l161 {
+                                0 => {}
+                                _ => {
+                                    let l162 = *arg0.add(28).cast::<*mut u8>();
+                                    let l163 = *arg0.add(32).cast::<usize>();
+                                    _rt::cabi_dealloc(l162, l163, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_verify_storage_cabi<T: Guest>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                    arg2: i32,
+                    arg3: i64,
+                    arg4: i32,
+                    arg5: i32,
+                    arg6: i32,
+                    arg7: *mut u8,
+                    arg8: usize,
+                    arg9: *mut u8,
+                    arg10: usize,
+                    arg11: *mut u8,
+                    arg12: usize,
+                    arg13: i32,
+                    arg14: *mut u8,
+                    arg15: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len193 = arg1;
+                    let bytes193 = _rt::Vec::from_raw_parts(arg0.cast(), len193, len193);
+                    let params0 = ReadParams {
+                        network_name: _rt::string_lift(bytes193),
+                        block_number: match arg2 {
+                            0 => None,
+                            1 => {
+                                let e = arg3 as u64;
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        anchor: match arg4 {
+                            0 => None,
+                            1 => {
+                                let e = Anchor::_lift(arg5 as u8);
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        execution_endpoint: match arg6 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    let len194 = arg8;
+                                    let bytes194 = _rt::Vec::from_raw_parts(
+                                        arg7.cast(),
+                                        len194,
+                                        len194,
+                                    );
+                                    _rt::string_lift(bytes194)
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                    };
+                    let len1 = arg10;
+                    let bytes1 = _rt::Vec::from_raw_parts(arg9.cast(), len1, len1);
+                    let base5 = arg11;
+                    let len5 = arg12;
+                    let mut result5 = _rt::Vec::with_capacity(len5);
+                    for i in 0..len5 {
+                        let base = base5.add(i * 8);
+                        let e5 = {
+                            let l2 = *base.add(0).cast::<*mut u8>();
+                            let l3 = *base.add(4).cast::<usize>();
+                            let len4 = l3;
+                            let bytes4 = _rt::Vec::from_raw_parts(l2.cast(), len4, len4);
+                            _rt::string_lift(bytes4)
+                        };
+                        result5.push(e5);
+                    }
+                    _rt::cabi_dealloc(base5, len5 * 8, 4);
+                    let result7 = T::verify_storage(
+                        params0,
+                        _rt::string_lift(bytes1),
+                        result5,
+                        match arg13 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    let len6 = arg15;
+                                    let bytes6 = _rt::Vec::from_raw_parts(
+                                        arg14.cast(),
+                                        len6,
+                                        len6,
+                                    );
+                                    _rt::string_lift(bytes6)
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                    );
+                    let ptr8 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result7 {
+                        Ok(e) => {
+                            *ptr8.add(0).cast::<u8>() = (0i32) as u8;
+                            let VerifiedStorage { account: account9, storage: storage9 } = e;
+                            let VerifiedAccount {
+                                address: address164,
+                                exists: exists164,
+                                nonce: nonce164,
+                                balance: balance164,
+                                storage_root: storage_root164,
+                                code_hash: code_hash164,
+                                block_number: block_number164,
+                                state_root: state_root164,
+                            } = account9;
+                            let vec165 = (address164.into_bytes()).into_boxed_slice();
+                            let ptr165 = vec165.as_ptr().cast::<u8>();
+                            let len165 = vec165.len();
+                            ::core::mem::forget(vec165);
+                            *ptr8.add(12).cast::<usize>() = len165;
+                            *ptr8.add(8).cast::<*mut u8>() = ptr165.cast_mut();
+                            *ptr8.add(16).cast::<u8>() = (match exists164 {
+                                true => 1,
+                                false => 0,
+                            }) as u8;
+                            *ptr8.add(24).cast::<i64>() = _rt::as_i64(nonce164);
+                            let vec166 = (balance164.into_bytes()).into_boxed_slice();
+                            let ptr166 = vec166.as_ptr().cast::<u8>();
+                            let len166 = vec166.len();
+                            ::core::mem::forget(vec166);
+                            *ptr8.add(36).cast::<usize>() = len166;
+                            *ptr8.add(32).cast::<*mut u8>() = ptr166.cast_mut();
+                            let vec167 = (storage_root164.into_bytes()).into_boxed_slice();
+                            let ptr167 = vec167.as_ptr().cast::<u8>();
+                            let len167 = vec167.len();
+                            ::core::mem::forget(vec167);
+                            *ptr8.add(44).cast::<usize>() = len167;
+                            *ptr8.add(40).cast::<*mut u8>() = ptr167.cast_mut();
+                            let vec168 = (code_hash164.into_bytes()).into_boxed_slice();
+                            let ptr168 = vec168.as_ptr().cast::<u8>();
+                            let len168 = vec168.len();
+                            ::core::mem::forget(vec168);
+                            *ptr8.add(52).cast::<usize>() = len168;
+                            *ptr8.add(48).cast::<*mut u8>() = ptr168.cast_mut();
+                            *ptr8.add(56).cast::<i64>() = _rt::as_i64(block_number164);
+                            let vec169 = (state_root164.into_bytes()).into_boxed_slice();
+                            let ptr169 = vec169.as_ptr().cast::<u8>();
+                            let len169 = vec169.len();
+                            ::core::mem::forget(vec169);
+                            *ptr8.add(68).cast::<usize>() = len169;
+                            *ptr8.add(64).cast::<*mut u8>() = ptr169.cast_mut();
+                            let vec12 = storage9;
+                            let len12 = vec12.len();
+                            let layout12 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec12.len() * 16,
+                                4,
+                            );
+                            let result12 = if layout12.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout12).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout12);
+                                }
+                                ptr
+                            } else {
+                                ::core::ptr::null_mut()
+                            };
+                            for (i, e) in vec12.into_iter().enumerate() {
+                                let base = result12.add(i * 16);
+                                {
+                                    let StorageSlot { key: key10, value: value10 } = e;
+                                    let vec10 = (key10.into_bytes()).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *base.add(4).cast::<usize>() = len10;
+                                    *base.add(0).cast::<*mut u8>() = ptr10.cast_mut();
+                                    let vec11 = (value10.into_bytes()).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *base.add(12).cast::<usize>() = len11;
+                                    *base.add(8).cast::<*mut u8>() = ptr11.cast_mut();
+                                }
+                            }
+                            *ptr8.add(76).cast::<usize>() = len12;
+                            *ptr8.add(72).cast::<*mut u8>() = result12;
+                        }
+                        Err(e) => {
+                            *ptr8.add(0).cast::<u8>() = (1i32) as u8;
+                            let ErrorInfo {
+                                code: code170,
+                                message: message170,
+                                details: details170,
+                            } = e;
+                            let vec171 = (code170.into_bytes()).into_boxed_slice();
+                            let ptr171 = vec171.as_ptr().cast::<u8>();
+                            let len171 = vec171.len();
+                            ::core::mem::forget(vec171);
+                            *ptr8.add(12).cast::<usize>() = len171;
+                            *ptr8.add(8).cast::<*mut u8>() = ptr171.cast_mut();
+                            let vec172 = (message170.into_bytes()).into_boxed_slice();
+                            let ptr172 = vec172.as_ptr().cast::<u8>();
+                            let len172 = vec172.len();
+                            ::core::mem::forget(vec172);
+                            *ptr8.add(20).cast::<usize>() = len172;
+                            *ptr8.add(16).cast::<*mut u8>() = ptr172.cast_mut();
+                            match details170 {
+                                Some(e) => {
+                                    *ptr8.add(24).cast::<u8>() = (1i32) as u8;
+                                    let vec173 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr173 = vec173.as_ptr().cast::<u8>();
+                                    let len173 = vec173.len();
+                                    ::core::mem::forget(vec173);
+                                    *ptr8.add(32).cast::<usize>() = len173;
+                                    *ptr8.add(28).cast::<*mut u8>() = ptr173.cast_mut();
+                                }
+                                None => {
+                                    *ptr8.add(24).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                        }
+                    };
+                    ptr8
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_verify_storage<T: Guest>(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l174 = *arg0.add(8).cast::<*mut u8>();
+                            let l175 = *arg0.add(12).cast::<usize>();
+                            _rt::cabi_dealloc(l174, l175, 1);
+                            let l176 = *arg0.add(32).cast::<*mut u8>();
+                            let l177 = *arg0.add(36).cast::<usize>();
+                            _rt::cabi_dealloc(l176, l177, 1);
+                            let l178 = *arg0.add(40).cast::<*mut u8>();
+                            let l179 = *arg0.add(44).cast::<usize>();
+                            _rt::cabi_dealloc(l178, l179, 1);
+                            let l180 = *arg0.add(48).cast::<*mut u8>();
+                            let l181 = *arg0.add(52).cast::<usize>();
+                            _rt::cabi_dealloc(l180, l181, 1);
+                            let l182 = *arg0.add(64).cast::<*mut u8>();
+                            let l183 = *arg0.add(68).cast::<usize>();
+                            _rt::cabi_dealloc(l182, l183, 1);
+                            let l1 = *arg0.add(72).cast::<*mut u8>();
+                            let l2 = *arg0.add(76).cast::<usize>();
+                            let base7 = l1;
+                            let len7 = l2;
+                            for i in 0..len7 {
+                                let base = base7.add(i * 16);
+                                {
+                                    let l3 = *base.add(0).cast::<*mut u8>();
+                                    let l4 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l3, l4, 1);
+                                    let l5 = *base.add(8).cast::<*mut u8>();
+                                    let l6 = *base.add(12).cast::<usize>();
+                                    _rt::cabi_dealloc(l5, l6, 1);
+                                }
+                            }
+                            _rt::cabi_dealloc(base7, len7 * 16, 4);
+                        }
+                        _ => {
+                            let l184 = *arg0.add(8).cast::<*mut u8>();
+                            let l185 = *arg0.add(12).cast::<usize>();
+                            _rt::cabi_dealloc(l184, l185, 1);
+                            let l186 = *arg0.add(16).cast::<*mut u8>();
+                            let l187 = *arg0.add(20).cast::<usize>();
+                            _rt::cabi_dealloc(l186, l187, 1);
+                            let l188 = i32::from(*arg0.add(24).cast::<u8>());
+                            match l188 {
+                                0 => {}
+                                _ => {
+                                    let l189 = *arg0.add(28).cast::<*mut u8>();
+                                    let l190 = *arg0.add(32).cast::<usize>();
+                                    _rt::cabi_dealloc(l189, l190, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                pub trait Guest {
+                    fn init(params: InitParams) -> Result<PersistInfo, ErrorInfo>;
+                    fn update(
+                        network: Network,
+                        secondary_beacon_endpoints: _rt::Vec<_rt::String>,
+                        target: UpdateTarget,
+                    ) -> Result<UpdateResult, ErrorInfo>;
+                    fn verify_account(
+                        params: ReadParams,
+                        address: _rt::String,
+                        account_proof: Option<_rt::String>,
+                    ) -> Result<VerifiedAccount, ErrorInfo>;
+                    fn verify_storage(
+                        params: ReadParams,
+                        address: _rt::String,
+                        slots: _rt::Vec<_rt::String>,
+                        account_proof: Option<_rt::String>,
+                    ) -> Result<VerifiedStorage, ErrorInfo>;
+                }
+                #[doc(hidden)]
+                macro_rules! __export_component_evm_light_client_light_client_cabi {
+                    ($ty:ident with_types_in $($path_to_types:tt)*) => {
+                        const _ : () = { #[export_name =
+                        "component:evm-light-client/light-client#init"] unsafe extern "C" fn
+                        export_init(arg0 : * mut u8, arg1 : usize, arg2 : * mut u8, arg3 :
+                        usize, arg4 : i32, arg5 : * mut u8, arg6 : usize, arg7 : i32, arg8 :
+                        i64,) -> * mut u8 { $($path_to_types)*:: _export_init_cabi::<$ty >
+                        (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8) }
+                        #[export_name =
+                        "cabi_post_component:evm-light-client/light-client#init"] unsafe
+                        extern "C" fn _post_return_init(arg0 : * mut u8,) {
+                        $($path_to_types)*:: __post_return_init::<$ty > (arg0) }
+                        #[export_name = "component:evm-light-client/light-client#update"]
+                        unsafe extern "C" fn export_update(arg0 : * mut u8, arg1 : usize,
+                        arg2 : * mut u8, arg3 : usize, arg4 : * mut u8, arg5 : usize, arg6 :
+                        i32, arg7 : i64,) -> * mut u8 { $($path_to_types)*::
+                        _export_update_cabi::<$ty > (arg0, arg1, arg2, arg3, arg4, arg5,
+                        arg6, arg7) } #[export_name =
+                        "cabi_post_component:evm-light-client/light-client#update"] unsafe
+                        extern "C" fn _post_return_update(arg0 : * mut u8,) {
+                        $($path_to_types)*:: __post_return_update::<$ty > (arg0) }
+                        #[export_name =
+                        "component:evm-light-client/light-client#verify-account"] unsafe
+                        extern "C" fn export_verify_account(arg0 : * mut u8, arg1 : usize,
+                        arg2 : i32, arg3 : i64, arg4 : i32, arg5 : i32, arg6 : i32, arg7 : *
+                        mut u8, arg8 : usize, arg9 : * mut u8, arg10 : usize, arg11 : i32,
+                        arg12 : * mut u8, arg13 : usize,) -> * mut u8 { $($path_to_types)*::
+                        _export_verify_account_cabi::<$ty > (arg0, arg1, arg2, arg3, arg4,
+                        arg5, arg6, arg7, arg8, arg9, arg10, arg11, arg12, arg13) }
+                        #[export_name =
+                        "cabi_post_component:evm-light-client/light-client#verify-account"]
+                        unsafe extern "C" fn _post_return_verify_account(arg0 : * mut u8,) {
+                        $($path_to_types)*:: __post_return_verify_account::<$ty > (arg0) }
+                        #[export_name =
+                        "component:evm-light-client/light-client#verify-storage"] unsafe
+                        extern "C" fn export_verify_storage(arg0 : * mut u8, arg1 : usize,
+                        arg2 : i32, arg3 : i64, arg4 : i32, arg5 : i32, arg6 : i32, arg7 : *
+                        mut u8, arg8 : usize, arg9 : * mut u8, arg10 : usize, arg11 : * mut
+                        u8, arg12 : usize, arg13 : i32, arg14 : * mut u8, arg15 : usize,) ->
+                        * mut u8 { $($path_to_types)*:: _export_verify_storage_cabi::<$ty >
+                        (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10,
+                        arg11, arg12, arg13, arg14, arg15) } #[export_name =
+                        "cabi_post_component:evm-light-client/light-client#verify-storage"]
+                        unsafe extern "C" fn _post_return_verify_storage(arg0 : * mut u8,) {
+                        $($path_to_types)*:: __post_return_verify_storage::<$ty > (arg0) } };
+                    };
+                }
+                #[doc(hidden)]
+                pub(crate) use __export_component_evm_light_client_light_client_cabi;
+                #[repr(align(8))]
+                struct _RetArea([::core::mem::MaybeUninit<u8>; 80]);
+                static mut _RET_AREA: _RetArea = _RetArea(
+                    [::core::mem::MaybeUninit::uninit(); 80],
+                );
+            }
+        }
+    }
+}
 #[doc(hidden)]
 pub(crate) use __export_world_evm_light_client_cabi;
 #[rustfmt::skip]
@@ -146,6 +1657,44 @@ mod _rt {
         }
     }
     pub use alloc_crate::string::String;
+    pub unsafe fn cabi_dealloc(ptr: *mut u8, size: usize, align: usize) {
+        if size == 0 {
+            return;
+        }
+        let layout = alloc::Layout::from_size_align_unchecked(size, align);
+        alloc::dealloc(ptr, layout);
+    }
+    pub unsafe fn invalid_enum_discriminant<T>() -> T {
+        if cfg!(debug_assertions) {
+            panic!("invalid enum discriminant")
+        } else {
+            core::hint::unreachable_unchecked()
+        }
+    }
+    pub fn as_i64<T: AsI64>(t: T) -> i64 {
+        t.as_i64()
+    }
+    pub trait AsI64 {
+        fn as_i64(self) -> i64;
+    }
+    impl<'a, T: Copy + AsI64> AsI64 for &'a T {
+        fn as_i64(self) -> i64 {
+            (*self).as_i64()
+        }
+    }
+    impl AsI64 for i64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+    }
+    impl AsI64 for u64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+    }
+    pub use alloc_crate::alloc;
     extern crate alloc as alloc_crate;
 }
 /// Generates `#[no_mangle]` functions to export the specified type as the
@@ -172,7 +1721,10 @@ macro_rules! __export_evm_light_client_impl {
     };
     ($ty:ident with_types_in $($path_to_types_root:tt)*) => {
         $($path_to_types_root)*:: __export_world_evm_light_client_cabi!($ty with_types_in
-        $($path_to_types_root)*);
+        $($path_to_types_root)*); $($path_to_types_root)*::
+        exports::component::evm_light_client::light_client::__export_component_evm_light_client_light_client_cabi!($ty
+        with_types_in $($path_to_types_root)*::
+        exports::component::evm_light_client::light_client);
     };
 }
 #[doc(inline)]
@@ -180,16 +1732,47 @@ pub(crate) use __export_evm_light_client_impl as export;
 #[cfg(target_arch = "wasm32")]
 #[link_section = "component-type:wit-bindgen:0.36.0:component:evm-light-client:evm-light-client:encoded world"]
 #[doc(hidden)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 483] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xdc\x02\x01A\x02\x01\
-A\x0b\x01@\0\x01\0\x04\0\x0fregister-routes\x01\0\x01@\x01\x03cmds\x01\0\x04\0\x11\
-light-client-init\x01\x01\x04\0\x13light-client-update\x01\x01\x04\0$light-clien\
-t-update-for-block-number\x01\x01\x04\0\x1elight-client-update-for-period\x01\x01\
-\x04\0\x1clight-client-update-for-slot\x01\x01\x04\0#light-client-fetch-header-f\
-rom-slot\x01\x01\x04\0\"light-client-fetch-block-from-slot\x01\x01\x04\0\x14ligh\
-t-client-persist\x01\x01\x04\0+component:evm-light-client/evm-light-client\x04\0\
-\x0b\x16\x01\0\x10evm-light-client\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\
-\x0dwit-component\x070.220.1\x10wit-bindgen-rust\x060.36.0";
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 2074] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\x93\x0f\x01A\x02\x01\
+A\x1e\x01@\0\x01\0\x04\0\x0fregister-routes\x01\0\x01@\x01\x03cmds\x01\0\x04\0\x11\
+light-client-init\x01\x01\x04\0\x13light-client-update\x01\x01\x04\0\x1elight-cl\
+ient-update-and-commit\x01\x01\x04\0\x1elight-client-update-optimistic\x01\x01\x04\
+\0$light-client-update-for-block-number\x01\x01\x04\0\x1elight-client-update-for\
+-period\x01\x01\x04\0\x1clight-client-update-for-slot\x01\x01\x04\0#light-client\
+-fetch-header-from-slot\x01\x01\x04\0\"light-client-fetch-block-from-slot\x01\x01\
+\x04\0\x14light-client-persist\x01\x01\x04\0\x1blight-client-verify-account\x01\x01\
+\x04\0\x1blight-client-verify-storage\x01\x01\x04\0\x17light-client-verify-log\x01\
+\x01\x04\0$light-client-verify-execution-header\x01\x01\x04\0\x1flight-client-ve\
+rify-transaction\x01\x01\x04\0\x16light-client-get-state\x01\x01\x04\0\x1flight-\
+client-get-header-at-slot\x01\x01\x04\0'light-client-get-header-at-block-number\x01\
+\x01\x04\0\x12light-client-audit\x01\x01\x04\0\x20light-client-submit-misbehavio\
+ur\x01\x01\x04\0\x15light-client-unfreeze\x01\x01\x04\0\x17light-client-add-memb\
+er\x01\x01\x04\0\x1alight-client-remove-member\x01\x01\x04\0\x14light-client-get\
+-acl\x01\x01\x04\0\x18light-client-claim-owner\x01\x01\x01B)\x01ps\x01r\x02\x04n\
+ames\x10beacon-endpoints\0\x04\0\x07network\x03\0\x01\x01ks\x01kw\x01r\x03\x07ne\
+twork\x02\x12trusted-block-root\x03\x0euntrusted-slot\x04\x04\0\x0binit-params\x03\
+\0\x05\x01q\x04\x06latest\0\0\x0cblock-number\x01w\0\x06period\x01w\0\x04slot\x01\
+w\0\x04\0\x0dupdate-target\x03\0\x07\x01m\x02\x09finalized\x0aoptimistic\x04\0\x06\
+anchor\x03\0\x09\x01k\x0a\x01r\x04\x0cnetwork-names\x0cblock-number\x04\x06ancho\
+r\x0b\x12execution-endpoint\x03\x04\0\x0bread-params\x03\0\x0c\x01r\x05\x0efinal\
+ized-slotw\x16finalized-block-numberw\x14finalized-block-hashs\x14finalized-stat\
+e-roots\x0foptimistic-slot\x04\x04\0\x0dstate-summary\x03\0\x0e\x01r\x04\x0eboot\
+strap-infos\x0astate-infos\x0cgenesis-infos\x05state\x0f\x04\0\x0cpersist-info\x03\
+\0\x10\x01r\x03\x05state\x0f\x0astate-info\x03\x0cupdates-info\x03\x04\0\x0dupda\
+te-result\x03\0\x12\x01r\x08\x07addresss\x06exists\x7f\x05noncew\x07balances\x0c\
+storage-roots\x09code-hashs\x0cblock-numberw\x0astate-roots\x04\0\x10verified-ac\
+count\x03\0\x14\x01r\x02\x03keys\x05values\x04\0\x0cstorage-slot\x03\0\x16\x01p\x17\
+\x01r\x02\x07account\x15\x07storage\x18\x04\0\x10verified-storage\x03\0\x19\x01r\
+\x03\x04codes\x07messages\x07details\x03\x04\0\x0aerror-info\x03\0\x1b\x01j\x01\x11\
+\x01\x1c\x01@\x01\x06params\x06\0\x1d\x04\0\x04init\x01\x1e\x01j\x01\x13\x01\x1c\
+\x01@\x03\x07network\x02\x1asecondary-beacon-endpoints\0\x06target\x08\0\x1f\x04\
+\0\x06update\x01\x20\x01j\x01\x15\x01\x1c\x01@\x03\x06params\x0d\x07addresss\x0d\
+account-proof\x03\0!\x04\0\x0everify-account\x01\"\x01j\x01\x1a\x01\x1c\x01@\x04\
+\x06params\x0d\x07addresss\x05slots\0\x0daccount-proof\x03\0#\x04\0\x0everify-st\
+orage\x01$\x04\0'component:evm-light-client/light-client\x05\x02\x04\0+component\
+:evm-light-client/evm-light-client\x04\0\x0b\x16\x01\0\x10evm-light-client\x03\0\
+\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-component\x070.220.1\x10wit-bi\
+ndgen-rust\x060.36.0";
 #[inline(never)]
 #[doc(hidden)]
 pub fn __link_custom_section_describing_imports() {
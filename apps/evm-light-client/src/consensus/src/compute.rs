@@ -1,12 +1,15 @@
 use super::{
     beacon::{
-        BeaconBlockHeader, Domain, DomainType, Epoch, ForkData, Root, SigningData, Slot, Version,
+        BeaconBlockHeader, Domain, DomainType, Epoch, ForkData, ForkDigest, Root, SigningData, Slot,
+        Version,
     },
+    config::BlobParameters,
     context::ChainContext,
     errors::Error,
     sync_protocol::SyncCommitteePeriod,
     types::{H256, U64},
 };
+use sha2::{Digest, Sha256};
 
 /// https://github.com/ethereum/consensus-specs/blob/dev/specs/bellatrix/beacon-chain.md#compute_timestamp_at_slot
 pub fn compute_timestamp_at_slot<C: ChainContext>(ctx: &C, slot: Slot) -> U64 {
@@ -58,6 +61,23 @@ pub fn compute_fork_data_root(
     })
 }
 
+/// `blob_parameters` are the ones active at the epoch of the digest since fulu, and `None` before
+/// https://github.com/ethereum/consensus-specs/blob/dev/specs/fulu/beacon-chain.md#modified-compute_fork_digest
+pub fn compute_fork_digest(
+    current_version: Version,
+    genesis_validators_root: Root,
+    blob_parameters: Option<&BlobParameters>,
+) -> Result<ForkDigest, Error> {
+    let mut digest = compute_fork_data_root(current_version, genesis_validators_root)?;
+    if let Some(blob_parameters) = blob_parameters {
+        let mut preimage = blob_parameters.epoch.0.to_le_bytes().to_vec();
+        preimage.extend_from_slice(&blob_parameters.max_blobs_per_block.0.to_le_bytes());
+        let mask = Sha256::digest(&preimage);
+        digest.0.iter_mut().zip(mask.iter()).for_each(|(b, m)| *b ^= m);
+    }
+    Ok(ForkDigest(digest.0[..4].try_into().unwrap()))
+}
+
 /// https://github.com/ethereum/consensus-specs/blob/dev/specs/phase0/beacon-chain.md#compute_domain
 pub fn compute_domain<C: ChainContext>(
     ctx: &C,
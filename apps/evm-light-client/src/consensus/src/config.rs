@@ -1,4 +1,4 @@
-use super::{fork::ForkParameters, preset::Preset, types::U64};
+use super::{beacon::Epoch, fork::ForkParameters, preset::Preset, types::U64};
 
 pub mod holesky;
 pub mod mainnet;
@@ -10,4 +10,31 @@ pub struct Config {
     pub preset: Preset,
     pub fork_parameters: ForkParameters,
     pub min_genesis_time: U64,
+    /// Blob parameters in order of ascending epoch, whose first element is the electra one
+    /// that applies until the first blob parameter only fork
+    pub blob_schedule: Vec<BlobParameters>,
+}
+
+impl Config {
+    /// https://github.com/ethereum/consensus-specs/blob/dev/specs/fulu/beacon-chain.md#new-get_blob_parameters
+    pub fn blob_parameters(&self, epoch: Epoch) -> Option<&BlobParameters> {
+        self.blob_schedule.iter().rev().find(|p| epoch >= p.epoch)
+    }
+}
+
+/// An entry of the blob schedule, which is mixed into the fork digest since fulu (EIP-7892)
+/// https://github.com/ethereum/consensus-specs/blob/dev/specs/fulu/beacon-chain.md#blobparameters
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BlobParameters {
+    pub epoch: Epoch,
+    pub max_blobs_per_block: U64,
+}
+
+impl BlobParameters {
+    pub const fn new(epoch: Epoch, max_blobs_per_block: U64) -> Self {
+        Self {
+            epoch,
+            max_blobs_per_block,
+        }
+    }
 }
@@ -1,8 +1,8 @@
 use crate::consensus::src::{
-    config::Config,
+    config::{BlobParameters, Config},
     beacon::Version,
     fork::{
-        altair::ALTAIR_FORK_SPEC, bellatrix::BELLATRIX_FORK_SPEC, capella::CAPELLA_FORK_SPEC, deneb::DENEB_FORK_SPEC, electra::ELECTRA_FORK_SPEC, ForkParameter, ForkParameters
+        altair::ALTAIR_FORK_SPEC, bellatrix::BELLATRIX_FORK_SPEC, capella::CAPELLA_FORK_SPEC, deneb::DENEB_FORK_SPEC, electra::ELECTRA_FORK_SPEC, fulu::FULU_FORK_SPEC, ForkParameter, ForkParameters
     },
     preset,
     types::U64,
@@ -12,18 +12,23 @@ pub fn get_config() -> Config {
     Config {
         preset: preset::mainnet::PRESET,
         fork_parameters: ForkParameters::new(
-            Version([0, 0, 16, 32]),
+            Version([1, 1, 112, 0]),
             vec![
-                ForkParameter::new(Version([1, 0, 16, 32]), U64(36660), ALTAIR_FORK_SPEC),
-                ForkParameter::new(Version([2, 0, 16, 32]), U64(112260), BELLATRIX_FORK_SPEC),
-                ForkParameter::new(Version([3, 0, 16, 32]), U64(162304), CAPELLA_FORK_SPEC),
-                ForkParameter::new(Version([4, 0, 16, 32]), U64(231680), DENEB_FORK_SPEC),
-                ForkParameter::new(Version([5, 0, 16, 32]), U64(u64::MAX), ELECTRA_FORK_SPEC),
-                ForkParameter::new(Version([6, 0, 16, 32]), U64(u64::MAX), Default::default()),
+                ForkParameter::new(Version([2, 1, 112, 0]), U64(0), ALTAIR_FORK_SPEC),
+                ForkParameter::new(Version([3, 1, 112, 0]), U64(0), BELLATRIX_FORK_SPEC),
+                ForkParameter::new(Version([4, 1, 112, 0]), U64(256), CAPELLA_FORK_SPEC),
+                ForkParameter::new(Version([5, 1, 112, 0]), U64(29696), DENEB_FORK_SPEC),
+                ForkParameter::new(Version([6, 1, 112, 0]), U64(115968), ELECTRA_FORK_SPEC),
+                ForkParameter::new(Version([7, 1, 112, 0]), U64(165120), FULU_FORK_SPEC),
             ],
         )
         .unwrap(),
-        min_genesis_time: U64(1614588812),
+        min_genesis_time: U64(1695902100),
+        blob_schedule: vec![
+            BlobParameters::new(U64(115968), U64(9)),
+            BlobParameters::new(U64(166400), U64(15)),
+            BlobParameters::new(U64(167936), U64(21)),
+        ],
     }
 }
 
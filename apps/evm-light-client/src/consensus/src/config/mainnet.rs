@@ -1,8 +1,8 @@
 use crate::consensus::src::{
-    config::Config,
+    config::{BlobParameters, Config},
     beacon::Version,
     fork::{
-        altair::ALTAIR_FORK_SPEC, bellatrix::BELLATRIX_FORK_SPEC, capella::CAPELLA_FORK_SPEC, deneb::DENEB_FORK_SPEC, electra::ELECTRA_FORK_SPEC, ForkParameter, ForkParameters
+        altair::ALTAIR_FORK_SPEC, bellatrix::BELLATRIX_FORK_SPEC, capella::CAPELLA_FORK_SPEC, deneb::DENEB_FORK_SPEC, electra::ELECTRA_FORK_SPEC, fulu::FULU_FORK_SPEC, ForkParameter, ForkParameters
     },
     preset,
     types::U64,
@@ -18,12 +18,17 @@ pub fn get_config() -> Config {
                 ForkParameter::new(Version([2, 0, 0, 0]), U64(144896), BELLATRIX_FORK_SPEC),
                 ForkParameter::new(Version([3, 0, 0, 0]), U64(194048), CAPELLA_FORK_SPEC),
                 ForkParameter::new(Version([4, 0, 0, 0]), U64(269568), DENEB_FORK_SPEC),
-                ForkParameter::new(Version([5, 0, 0, 0]), U64(u64::MAX), ELECTRA_FORK_SPEC),
-                ForkParameter::new(Version([6, 0, 0, 0]), U64(u64::MAX), Default::default()),
+                ForkParameter::new(Version([5, 0, 0, 0]), U64(364032), ELECTRA_FORK_SPEC),
+                ForkParameter::new(Version([6, 0, 0, 0]), U64(411392), FULU_FORK_SPEC),
             ],
         )
         .unwrap(),
         min_genesis_time: U64(1606824000),
+        blob_schedule: vec![
+            BlobParameters::new(U64(364032), U64(9)),
+            BlobParameters::new(U64(412672), U64(15)),
+            BlobParameters::new(U64(419072), U64(21)),
+        ],
     }
 }
 
@@ -35,4 +40,33 @@ mod tests {
     fn test_config_validation() {
         let _ = get_config();
     }
+
+    #[test]
+    fn test_fork_digests() {
+        use crate::consensus::src::{compute::compute_fork_digest, fork::{CAPELLA_INDEX, DENEB_INDEX, FULU_INDEX}, types::H256};
+
+        let config = get_config();
+        let genesis_validators_root = H256(hex_literal::hex!(
+            "4b363db94e286120d76eb905340fdd4e54bfe9f06bf33ff6cf5ad27f511bfe95"
+        ));
+        let digest = |index: usize, epoch: Option<u64>| {
+            let fork = &config.fork_parameters.forks()[index];
+            compute_fork_digest(
+                fork.version.clone(),
+                genesis_validators_root,
+                epoch.and_then(|epoch| config.blob_parameters(U64(epoch))),
+            )
+            .unwrap()
+            .0
+        };
+        assert_eq!(digest(CAPELLA_INDEX, None), hex_literal::hex!("bba4da96"));
+        assert_eq!(digest(DENEB_INDEX, None), hex_literal::hex!("6a95a1a9"));
+
+        // every blob parameter only fork changes the fulu digest
+        let fulu = [411392, 412672, 419072].map(|epoch| digest(FULU_INDEX, Some(epoch)));
+        assert_ne!(fulu[0], fulu[1]);
+        assert_ne!(fulu[1], fulu[2]);
+        assert_eq!(fulu[2], digest(FULU_INDEX, Some(500000)));
+        assert_eq!(config.blob_parameters(U64(411392)).unwrap().max_blobs_per_block, U64(9));
+    }
 }
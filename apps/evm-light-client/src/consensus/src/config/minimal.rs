@@ -1,8 +1,8 @@
 use crate::consensus::src::{
-    config::Config,
+    config::{BlobParameters, Config},
     beacon::Version,
     fork::{
-        altair::ALTAIR_FORK_SPEC, bellatrix::BELLATRIX_FORK_SPEC, capella::CAPELLA_FORK_SPEC, deneb::DENEB_FORK_SPEC, electra::ELECTRA_FORK_SPEC, ForkParameter, ForkParameters
+        altair::ALTAIR_FORK_SPEC, bellatrix::BELLATRIX_FORK_SPEC, capella::CAPELLA_FORK_SPEC, deneb::DENEB_FORK_SPEC, electra::ELECTRA_FORK_SPEC, fulu::FULU_FORK_SPEC, ForkParameter, ForkParameters
     },
     preset,
     types::U64,
@@ -19,10 +19,14 @@ pub fn get_config() -> Config {
                 ForkParameter::new(Version([3, 0, 0, 1]), U64(0), CAPELLA_FORK_SPEC),
                 ForkParameter::new(Version([4, 0, 0, 1]), U64(0), DENEB_FORK_SPEC),
                 ForkParameter::new(Version([5, 0, 0, 1]), U64(0), ELECTRA_FORK_SPEC),
+                ForkParameter::new(Version([6, 0, 0, 1]), U64(0), FULU_FORK_SPEC),
             ],
         )
         .unwrap(),
         min_genesis_time: U64(1578009600),
+        blob_schedule: vec![
+            BlobParameters::new(U64(0), U64(9)),
+        ],
     }
 }
 
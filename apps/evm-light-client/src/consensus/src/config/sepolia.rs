@@ -1,8 +1,8 @@
 use crate::consensus::src::{
-    config::Config,
+    config::{BlobParameters, Config},
     beacon::Version,
     fork::{
-        altair::ALTAIR_FORK_SPEC, bellatrix::BELLATRIX_FORK_SPEC, capella::CAPELLA_FORK_SPEC, deneb::DENEB_FORK_SPEC, electra::ELECTRA_FORK_SPEC, ForkParameter, ForkParameters
+        altair::ALTAIR_FORK_SPEC, bellatrix::BELLATRIX_FORK_SPEC, capella::CAPELLA_FORK_SPEC, deneb::DENEB_FORK_SPEC, electra::ELECTRA_FORK_SPEC, fulu::FULU_FORK_SPEC, ForkParameter, ForkParameters
     },
     preset,
     types::U64,
@@ -19,11 +19,16 @@ pub fn get_config() -> Config {
                 ForkParameter::new(Version([144, 0, 0, 114]), U64(56832), CAPELLA_FORK_SPEC),
                 ForkParameter::new(Version([144, 0, 0, 115]), U64(132608), DENEB_FORK_SPEC),
                 ForkParameter::new(Version([144, 0, 0, 116]), U64(222464), ELECTRA_FORK_SPEC),
-                ForkParameter::new(Version([144, 0, 0, 117]), U64(u64::MAX), Default::default()),
+                ForkParameter::new(Version([144, 0, 0, 117]), U64(272640), FULU_FORK_SPEC),
             ],
         )
         .unwrap(),
         min_genesis_time: U64(1655647200),
+        blob_schedule: vec![
+            BlobParameters::new(U64(222464), U64(9)),
+            BlobParameters::new(U64(274176), U64(15)),
+            BlobParameters::new(U64(275712), U64(21)),
+        ],
     }
 }
 
@@ -32,6 +32,8 @@ pub enum Error {
     NotSupportedExecutionPayload(Version),
     /// the genesis does not support light client protocol
     NotSupportedLightClient,
+    /// transaction index out of range: `index={0} length={1}`
+    TransactionIndexOutOfRange(u64, u64),
     /// other error: `{description}`
     Other { description: String },
 }
@@ -44,6 +46,8 @@ pub enum MerkleError {
     TooLongMerkleBranchLength(u32, H256, Vec<H256>, u32, Root),
     /// invalid merkle branch length error: depth={0:?} leaf={1:?} branch={2:?} subtree_index={3:?} root={4:?}
     InvalidMerkleBranchLength(u32, H256, Vec<H256>, u32, Root),
+    /// non-zero padding in normalized merkle branch error: depth={0:?} leaf={1:?} branch={2:?} subtree_index={3:?} root={4:?}
+    NonZeroNormalizedMerkleBranchPadding(u32, H256, Vec<H256>, u32, Root),
     /// invalid general index error: gindex={0:?}
     InvalidGeneralIndex(i64),
 }
@@ -73,3 +77,88 @@ impl From<hex::FromHexError> for Error {
         Self::FromHexError(value)
     }
 }
+
+impl Error {
+    /// stable code of the error kind
+    pub fn code(&self) -> &'static str {
+        match self {
+            Error::BLSAmclError(_) => "CONSENSUS_BLS_ERROR",
+            Error::MerkleizationError(_) => "CONSENSUS_MERKLEIZATION_ERROR",
+            Error::SSZDeserializeError(_) => "CONSENSUS_SSZ_DECODE_ERROR",
+            Error::FromHexError(_) => "CONSENSUS_INVALID_HEX",
+            Error::InvalidBLSSignatureLenght(..) => "CONSENSUS_INVALID_BLS_SIGNATURE_LENGTH",
+            Error::InvalidBLSPublicKeyLength(..) => "CONSENSUS_INVALID_BLS_PUBLIC_KEY_LENGTH",
+            Error::BLSAggregatePublicKeyMismatch(..) => "CONSENSUS_BLS_AGGREGATE_PUBLIC_KEY_MISMATCH",
+            Error::InvalidAddressLength(..) => "CONSENSUS_INVALID_ADDRESS_LENGTH",
+            Error::InvalidForkParamersOrder(_) => "CONSENSUS_INVALID_FORK_PARAMETERS_ORDER",
+            Error::UnknownFork(..) => "CONSENSUS_UNKNOWN_FORK",
+            Error::NotSupportedExecutionPayload(_) => "CONSENSUS_EXECUTION_PAYLOAD_NOT_SUPPORTED",
+            Error::NotSupportedLightClient => "CONSENSUS_LIGHT_CLIENT_NOT_SUPPORTED",
+            Error::TransactionIndexOutOfRange(..) => "CONSENSUS_TRANSACTION_INDEX_OUT_OF_RANGE",
+            Error::Other { .. } => "CONSENSUS_ERROR",
+        }
+    }
+
+    /// structured fields of the error
+    pub fn details(&self) -> serde_json::Value {
+        use serde_json::json;
+        match self {
+            Error::InvalidBLSSignatureLenght(expected, actual)
+            | Error::InvalidBLSPublicKeyLength(expected, actual)
+            | Error::InvalidAddressLength(expected, actual) => {
+                json!({"expected": expected, "actual": actual})
+            }
+            Error::BLSAggregatePublicKeyMismatch(expected, actual) => {
+                json!({"expected": expected, "actual": actual})
+            }
+            Error::InvalidForkParamersOrder(fork_parameters) => {
+                json!({"fork_parameters": fork_parameters})
+            }
+            Error::UnknownFork(epoch, fork, index) => {
+                json!({"epoch": epoch, "fork": fork, "index": index})
+            }
+            Error::NotSupportedExecutionPayload(version) => json!({"version": version}),
+            Error::TransactionIndexOutOfRange(index, length) => {
+                json!({"index": index, "length": length})
+            }
+            _ => serde_json::Value::Null,
+        }
+    }
+}
+
+impl MerkleError {
+    /// stable code of the error kind
+    pub fn code(&self) -> &'static str {
+        match self {
+            MerkleError::InvalidMerkleBranch(..) => "MERKLE_INVALID_BRANCH",
+            MerkleError::TooLongMerkleBranchLength(..) => "MERKLE_TOO_LONG_BRANCH",
+            MerkleError::InvalidMerkleBranchLength(..) => "MERKLE_INVALID_BRANCH_LENGTH",
+            MerkleError::NonZeroNormalizedMerkleBranchPadding(..) => "MERKLE_NON_ZERO_BRANCH_PADDING",
+            MerkleError::InvalidGeneralIndex(_) => "MERKLE_INVALID_GENERAL_INDEX",
+        }
+    }
+
+    /// structured fields of the error
+    pub fn details(&self) -> serde_json::Value {
+        use serde_json::json;
+        match self {
+            MerkleError::InvalidMerkleBranch(leaf, branch, subtree_index, expected, actual) => json!({
+                "leaf": leaf,
+                "branch": branch,
+                "subtree_index": subtree_index,
+                "expected_root": expected,
+                "actual_root": actual,
+            }),
+            MerkleError::TooLongMerkleBranchLength(depth, leaf, branch, subtree_index, root)
+            | MerkleError::InvalidMerkleBranchLength(depth, leaf, branch, subtree_index, root)
+            | MerkleError::NonZeroNormalizedMerkleBranchPadding(depth, leaf, branch, subtree_index, root) => json!({
+                "depth": depth,
+                "leaf": leaf,
+                "branch": branch,
+                "subtree_index": subtree_index,
+                "root": root,
+            }),
+            MerkleError::InvalidGeneralIndex(gindex) => json!({"gindex": gindex}),
+        }
+    }
+}
@@ -3,6 +3,8 @@ pub mod bellatrix;
 pub mod capella;
 pub mod deneb;
 pub mod electra;
+pub mod fulu;
+pub mod versioned;
 
 use super::beacon::{Epoch, Slot, Version};
 use super::errors::Error;
@@ -22,6 +24,7 @@ pub const BELLATRIX_INDEX: usize = 1;
 pub const CAPELLA_INDEX: usize = 2;
 pub const DENEB_INDEX: usize = 3;
 pub const ELECTRA_INDEX: usize = 4;
+pub const FULU_INDEX: usize = 5;
 
 /// Fork parameters for the beacon chain
 #[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -141,6 +144,7 @@ mod tests {
     use capella::CAPELLA_FORK_SPEC;
     use deneb::DENEB_FORK_SPEC;
     use electra::ELECTRA_FORK_SPEC;
+    use fulu::FULU_FORK_SPEC;
 
     use super::*;
 
@@ -154,11 +158,12 @@ mod tests {
                 ForkParameter::new(Version([3, 0, 0, 1]), U64(0), CAPELLA_FORK_SPEC),
                 ForkParameter::new(Version([4, 0, 0, 1]), U64(0), DENEB_FORK_SPEC),
                 ForkParameter::new(Version([5, 0, 0, 1]), U64(0), ELECTRA_FORK_SPEC),
+                ForkParameter::new(Version([6, 0, 0, 1]), U64(0), FULU_FORK_SPEC),
             ],
         );
         assert!(res.is_ok());
         let params = res.unwrap();
-        assert_eq!(params.compute_fork_version(0.into()), Version([4, 0, 0, 1]));
+        assert_eq!(params.compute_fork_version(0.into()), Version([6, 0, 0, 1]));
 
         let res = ForkParameters::new(Version([0, 0, 0, 1]), vec![]);
         assert!(res.is_err());
@@ -182,6 +187,7 @@ mod tests {
                 ForkParameter::new(Version([3, 0, 0, 1]), U64(2), CAPELLA_FORK_SPEC),
                 ForkParameter::new(Version([4, 0, 0, 1]), U64(3), DENEB_FORK_SPEC),
                 ForkParameter::new(Version([5, 0, 0, 1]), U64(4), ELECTRA_FORK_SPEC),
+                ForkParameter::new(Version([6, 0, 0, 1]), U64(5), FULU_FORK_SPEC),
             ],
         );
         assert!(res.is_ok());
@@ -199,6 +205,10 @@ mod tests {
         assert!(params.is_fork(3.into(), DENEB_INDEX));
         assert!(params.is_fork(4.into(), DENEB_INDEX));
         assert!(params.is_fork(5.into(), ELECTRA_INDEX));
+        assert!(!params.is_fork(4.into(), FULU_INDEX));
+        assert!(params.is_fork(5.into(), FULU_INDEX));
+        assert_eq!(params.compute_fork_version(5.into()), Version([6, 0, 0, 1]));
+        assert_eq!(params.compute_fork_spec(5.into()), FULU_FORK_SPEC);
 
         let res = ForkParameters::new(
             Version([0, 0, 0, 1]),
@@ -310,7 +310,38 @@ pub struct LightClientHeader<const BYTES_PER_LOGS_BLOOM: usize, const MAX_EXTRA_
 
 pub mod prover {
     use super::*;
-    use crate::consensus::src::{errors::Error, merkle::{get_subtree_index, MerkleTree}};
+    use crate::consensus::src::{
+        errors::Error,
+        merkle::{
+            byte_list_root, get_chunks_depth, get_subtree_index, length_chunk, merkleize_with_proof,
+            mix_in_length, MerkleTree,
+        },
+    };
+
+    /// generate a merkle proof of the transaction at `index` against `transactions_root` of an execution payload
+    /// the last element of the branch is the length of the transactions, which is mixed into the root
+    pub fn gen_transaction_proof<
+        const MAX_BYTES_PER_TRANSACTION: usize,
+        const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+    >(
+        transactions: &List<ByteList<MAX_BYTES_PER_TRANSACTION>, MAX_TRANSACTIONS_PER_PAYLOAD>,
+        index: usize,
+    ) -> Result<(Root, Vec<H256>), Error> {
+        if index >= transactions.len() {
+            return Err(Error::TransactionIndexOutOfRange(index as u64, transactions.len() as u64));
+        }
+        let leaves: Vec<H256> = transactions
+            .iter()
+            .map(|tx| byte_list_root(tx.as_ref(), MAX_BYTES_PER_TRANSACTION))
+            .collect();
+        let (root, mut branch) = merkleize_with_proof(
+            &leaves,
+            get_chunks_depth(MAX_TRANSACTIONS_PER_PAYLOAD),
+            Some(index),
+        );
+        branch.push(length_chunk(leaves.len() as u64));
+        Ok((mix_in_length(root, leaves.len() as u64), branch))
+    }
 
     pub fn gen_execution_payload_field_proof<
         const BYTES_PER_LOGS_BLOOM: usize,
@@ -0,0 +1,129 @@
+use crate::consensus::src::{
+    beacon::{BlockNumber, Root},
+    errors::Error,
+    fork::{capella, deneb, electra},
+    types::{H256, U64},
+};
+
+/// ExecutionPayloadHeader is an execution payload header of any fork that carries one in its `LightClientHeader`
+///
+/// The variant is (de)serialized as `{"version": "<fork>", "data": <header>}`, matching the beacon API.
+/// Fulu did not modify the execution payload header, so a `fulu` version is decoded as electra.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "version", content = "data", rename_all = "lowercase")]
+pub enum ExecutionPayloadHeader<const BYTES_PER_LOGS_BLOOM: usize, const MAX_EXTRA_DATA_BYTES: usize>
+{
+    Capella(capella::ExecutionPayloadHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>),
+    Deneb(deneb::ExecutionPayloadHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>),
+    #[serde(alias = "fulu")]
+    Electra(electra::ExecutionPayloadHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>),
+}
+
+impl<const BYTES_PER_LOGS_BLOOM: usize, const MAX_EXTRA_DATA_BYTES: usize> Default
+    for ExecutionPayloadHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
+{
+    fn default() -> Self {
+        Self::Deneb(Default::default())
+    }
+}
+
+impl<const BYTES_PER_LOGS_BLOOM: usize, const MAX_EXTRA_DATA_BYTES: usize>
+    ExecutionPayloadHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
+{
+    pub fn parent_hash(&self) -> H256 {
+        match self {
+            Self::Capella(header) => header.parent_hash,
+            Self::Deneb(header) | Self::Electra(header) => header.parent_hash,
+        }
+    }
+
+    pub fn state_root(&self) -> H256 {
+        match self {
+            Self::Capella(header) => header.state_root,
+            Self::Deneb(header) | Self::Electra(header) => header.state_root,
+        }
+    }
+
+    pub fn receipts_root(&self) -> H256 {
+        match self {
+            Self::Capella(header) => header.receipts_root,
+            Self::Deneb(header) | Self::Electra(header) => header.receipts_root,
+        }
+    }
+
+    pub fn block_number(&self) -> BlockNumber {
+        match self {
+            Self::Capella(header) => header.block_number,
+            Self::Deneb(header) | Self::Electra(header) => header.block_number,
+        }
+    }
+
+    pub fn timestamp(&self) -> U64 {
+        match self {
+            Self::Capella(header) => header.timestamp,
+            Self::Deneb(header) | Self::Electra(header) => header.timestamp,
+        }
+    }
+
+    pub fn block_hash(&self) -> H256 {
+        match self {
+            Self::Capella(header) => header.block_hash,
+            Self::Deneb(header) | Self::Electra(header) => header.block_hash,
+        }
+    }
+
+    pub fn transactions_root(&self) -> Root {
+        match self {
+            Self::Capella(header) => header.transactions_root,
+            Self::Deneb(header) | Self::Electra(header) => header.transactions_root,
+        }
+    }
+
+    pub fn withdrawals_root(&self) -> Root {
+        match self {
+            Self::Capella(header) => header.withdrawals_root,
+            Self::Deneb(header) | Self::Electra(header) => header.withdrawals_root,
+        }
+    }
+
+    /// generate a merkle proof of the field at `leaf_index` within the fork's `ExecutionPayloadHeader`
+    pub fn gen_execution_payload_field_proof(
+        &self,
+        leaf_index: usize,
+    ) -> Result<(Root, Vec<H256>), Error> {
+        match self {
+            Self::Capella(header) => {
+                capella::prover::gen_execution_payload_field_proof(header, leaf_index)
+            }
+            Self::Deneb(header) | Self::Electra(header) => {
+                deneb::prover::gen_execution_payload_field_proof(header, leaf_index)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_versioned_execution_payload_header_serde() {
+        let header = ExecutionPayloadHeader::<256, 32>::Electra(Default::default());
+        let value = serde_json::to_value(&header).unwrap();
+        assert_eq!(value["version"], "electra");
+        assert_eq!(
+            serde_json::from_value::<ExecutionPayloadHeader<256, 32>>(value.clone()).unwrap(),
+            header
+        );
+
+        let mut value = value;
+        value["version"] = "fulu".into();
+        assert_eq!(
+            serde_json::from_value::<ExecutionPayloadHeader<256, 32>>(value.clone()).unwrap(),
+            header
+        );
+
+        value["version"] = "bellatrix".into();
+        assert!(serde_json::from_value::<ExecutionPayloadHeader<256, 32>>(value).is_err());
+    }
+}
@@ -6,6 +6,11 @@ use klave;
 /// MerkleTree is a merkle tree implementation using sha256 as a hashing algorithm.
 pub type MerkleTree = rs_merkle::MerkleTree<rs_merkle::algorithms::Sha256>;
 
+/// Check if ``leaf`` at ``gindex`` verifies against the Merkle ``root`` and a normalized ``branch``.
+///
+/// A normalized branch may be longer than the depth of ``gindex`` (e.g. a deneb branch upgraded to the electra format),
+/// in which case the extra leading elements must be zero.
+/// https://github.com/ethereum/consensus-specs/blob/dev/specs/electra/light-client/sync-protocol.md#is_valid_normalized_merkle_branch
 pub fn is_valid_normalized_merkle_branch(
     leaf: H256,
     branch: &[H256],
@@ -17,7 +22,17 @@ pub fn is_valid_normalized_merkle_branch(
     }
     let depth = get_depth(gindex);
     let subtree_index = get_subtree_index(gindex);
-    is_valid_merkle_branch(leaf, branch, depth, subtree_index, root)
+    let num_extra = branch.len().saturating_sub(depth as usize);
+    if branch[..num_extra].iter().any(|b| !b.is_zero()) {
+        return Err(MerkleError::NonZeroNormalizedMerkleBranchPadding(
+            depth,
+            leaf,
+            branch.to_vec(),
+            subtree_index,
+            root,
+        ));
+    }
+    is_valid_merkle_branch(leaf, &branch[num_extra..], depth, subtree_index, root)
 }
 
 /// Check if ``leaf`` at ``index`` verifies against the Merkle ``root`` and ``branch``.
@@ -79,8 +94,123 @@ pub const fn get_subtree_index(gindex: u32) -> u32 {
     gindex % 2u32.pow(get_depth(gindex))
 }
 
+/// Merkleize ``chunks`` as the leaves of a tree of ``depth``, virtually padded with zero chunks.
+/// Returns the root and the branch of the leaf at ``index``, if any.
+/// https://github.com/ethereum/consensus-specs/blob/dev/ssz/simple-serialize.md#merkleization
+pub fn merkleize_with_proof(chunks: &[H256], depth: u32, index: Option<usize>) -> (Root, Vec<H256>) {
+    let mut zero = H256::default();
+    let mut layer = chunks.to_vec();
+    let mut index = index;
+    let mut branch = vec![];
+    for _ in 0..depth {
+        if let Some(i) = index {
+            branch.push(layer.get(i ^ 1).copied().unwrap_or(zero));
+            index = Some(i / 2);
+        }
+        layer = layer
+            .chunks(2)
+            .map(|pair| hash([pair[0].as_bytes(), pair.get(1).unwrap_or(&zero).as_bytes()].concat()))
+            .collect();
+        zero = hash([zero.as_bytes(), zero.as_bytes()].concat());
+    }
+    (layer.first().copied().unwrap_or(zero), branch)
+}
+
+/// https://github.com/ethereum/consensus-specs/blob/dev/ssz/simple-serialize.md#merkleization
+pub fn mix_in_length(root: Root, length: u64) -> Root {
+    hash([root.as_bytes(), length_chunk(length).as_bytes()].concat())
+}
+
+/// the chunk mixed into the root of a list, i.e. its length as a little-endian uint256
+pub fn length_chunk(length: u64) -> H256 {
+    let mut chunk = H256::default();
+    chunk.0[..8].copy_from_slice(&length.to_le_bytes());
+    chunk
+}
+
+/// depth of the tree whose leaves are ``limit`` chunks
+pub fn get_chunks_depth(limit: usize) -> u32 {
+    limit.max(1).next_power_of_two().trailing_zeros()
+}
+
+/// hash tree root of a ``ByteList`` of at most ``max_bytes`` bytes
+pub fn byte_list_root(bytes: &[u8], max_bytes: usize) -> Root {
+    let chunks: Vec<H256> = bytes
+        .chunks(32)
+        .map(|chunk| {
+            let mut padded = H256::default();
+            padded.0[..chunk.len()].copy_from_slice(chunk);
+            padded
+        })
+        .collect();
+    let (root, _) = merkleize_with_proof(&chunks, get_chunks_depth((max_bytes + 31) / 32), None);
+    mix_in_length(root, bytes.len() as u64)
+}
+
 fn hash(bz: Vec<u8>) -> H256 {
     let mut output = H256::default();
     output.0.copy_from_slice(Sha256::digest(bz).as_slice());
     output
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consensus::src::{compute::hash_tree_root, types::ByteList};
+
+    #[test]
+    fn test_normalized_merkle_branch() {
+        let leaves: Vec<[u8; 32]> = (0..8u8).map(|i| [i; 32]).collect();
+        let tree = MerkleTree::from_leaves(&leaves);
+        let root = H256(tree.root().unwrap());
+        let leaf_index = 5;
+        let gindex = 8 + leaf_index as u32;
+        let branch: Vec<H256> = tree
+            .proof(&[leaf_index])
+            .proof_hashes()
+            .iter()
+            .map(|h| H256::from_slice(h))
+            .collect();
+        let leaf = H256(leaves[leaf_index]);
+
+        assert!(is_valid_normalized_merkle_branch(leaf, &branch, gindex, root).is_ok());
+
+        // a branch padded with leading zeros is accepted
+        let padded = [vec![H256::default()], branch.clone()].concat();
+        assert!(is_valid_normalized_merkle_branch(leaf, &padded, gindex, root).is_ok());
+
+        // a branch padded with non-zero elements is rejected
+        let padded = [vec![H256([1; 32])], branch.clone()].concat();
+        assert!(matches!(
+            is_valid_normalized_merkle_branch(leaf, &padded, gindex, root),
+            Err(MerkleError::NonZeroNormalizedMerkleBranchPadding(..))
+        ));
+
+        // a branch shorter than the depth is rejected
+        assert!(matches!(
+            is_valid_normalized_merkle_branch(leaf, &branch[1..], gindex, root),
+            Err(MerkleError::InvalidMerkleBranchLength(..))
+        ));
+    }
+
+    #[test]
+    fn test_merkleize_with_proof() {
+        let leaves: Vec<[u8; 32]> = (0..8u8).map(|i| [i; 32]).collect();
+        let tree = MerkleTree::from_leaves(&leaves);
+        let chunks: Vec<H256> = leaves.iter().map(|leaf| H256(*leaf)).collect();
+        let (root, branch) = merkleize_with_proof(&chunks, 3, Some(5));
+        assert_eq!(root, H256(tree.root().unwrap()));
+        assert!(is_valid_merkle_branch(chunks[5], &branch, 3, 5, root).is_ok());
+
+        // the missing leaves of a deeper tree are zero chunks
+        let (deep_root, branch) = merkleize_with_proof(&chunks[..5], 5, Some(4));
+        let padded: Vec<[u8; 32]> = (0..32)
+            .map(|i| if i < 5 { leaves[i] } else { [0; 32] })
+            .collect();
+        assert_eq!(deep_root, H256(MerkleTree::from_leaves(&padded).root().unwrap()));
+        assert!(is_valid_merkle_branch(chunks[4], &branch, 5, 4, deep_root).is_ok());
+
+        let byte_list = ByteList::<100>::try_from([7u8; 40].as_slice()).unwrap();
+        assert_eq!(byte_list_root(&[7u8; 40], 100), hash_tree_root(byte_list).unwrap());
+    }
+}
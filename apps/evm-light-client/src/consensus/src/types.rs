@@ -12,6 +12,9 @@ use ssz_rs_derive::SimpleSerialize;
     Debug,
     PartialEq,
     Eq,
+    PartialOrd,
+    Ord,
+    Hash,
     Default,
     SimpleSerialize,
     serde::Serialize,
@@ -0,0 +1 @@
+pub mod src;
\ No newline at end of file
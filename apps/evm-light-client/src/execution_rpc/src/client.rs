@@ -0,0 +1,166 @@
+use super::errors::Error;
+use super::types::{AccountProof, Block, BlockId, Quantity, TransactionReceipt};
+use crate::consensus::src::types::{Address, H256, U64};
+use crate::http::{request_format, JsonRpcRequest, JsonRpcResponse};
+use crate::klave_client::src::client::{Client as KlaveClient, HttpStatusError};
+use log::debug;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::json;
+use std::collections::BTreeMap;
+use url::Url;
+
+type Result<T> = core::result::Result<T, Error>;
+
+/// RPCClient is a client of the execution layer JSON-RPC API
+pub struct RPCClient {
+    http_client: KlaveClient,
+    endpoint: String,
+}
+
+impl RPCClient {
+    pub fn new(endpoint: impl Into<String>) -> Result<Self> {
+        let endpoint = endpoint.into();
+        let url = Url::parse(&endpoint).map_err(|e| Error::InvalidEndpoint(format!("{}: {}", e, endpoint)))?;
+        if url.scheme() != "http" && url.scheme() != "https" {
+            return Err(Error::InvalidEndpoint(format!("invalid scheme {}: {}", url.scheme(), endpoint)));
+        }
+        if url.host().is_none() {
+            return Err(Error::InvalidEndpoint(format!("missing host: {}", endpoint)));
+        }
+        // the path is kept as is, since providers commonly put an API key in it
+        Ok(Self {
+            http_client: KlaveClient::new(),
+            endpoint: url.to_string(),
+        })
+    }
+
+    // Execution API
+
+    pub fn chain_id(&self) -> Result<U64> {
+        Ok(self.call::<Quantity>("eth_chainId", json!([]))?.into())
+    }
+
+    /// returns `None` if the block is unknown to the node
+    pub fn get_block_by_number(&self, block: BlockId) -> Result<Option<Block>> {
+        self.call("eth_getBlockByNumber", json!([block, false]))
+    }
+
+    pub fn get_proof(
+        &self,
+        address: &Address,
+        storage_keys: &[H256],
+        block: BlockId,
+    ) -> Result<AccountProof> {
+        self.call("eth_getProof", json!([address, storage_keys, block]))
+    }
+
+    /// returns `None` if the block is unknown to the node
+    pub fn get_block_receipts(&self, block: BlockId) -> Result<Option<Vec<TransactionReceipt>>> {
+        self.call("eth_getBlockReceipts", json!([block]))
+    }
+
+    /// Sends a single JSON-RPC request and decodes its result
+    pub fn call<T: DeserializeOwned>(&self, method: &str, params: serde_json::Value) -> Result<T> {
+        let request = JsonRpcRequest::new(1, method, params);
+        let response: JsonRpcResponse<serde_json::Value> = self.send(&request)?;
+        if response.id != Some(request.id) {
+            return Err(Error::ResponseIdMismatch(request.id, response.id));
+        }
+        decode_result(response)
+    }
+
+    /// Sends the calls as a single JSON-RPC batch.
+    /// The outer result fails if the batch itself fails; each call has its own result, in the order of `calls`.
+    pub fn batch<T: DeserializeOwned>(
+        &self,
+        calls: Vec<(&str, serde_json::Value)>,
+    ) -> Result<Vec<Result<T>>> {
+        if calls.is_empty() {
+            return Ok(vec![]);
+        }
+        let requests: Vec<JsonRpcRequest> = calls
+            .into_iter()
+            .enumerate()
+            .map(|(i, (method, params))| JsonRpcRequest::new(i as u64 + 1, method, params))
+            .collect();
+        let responses: Vec<JsonRpcResponse<serde_json::Value>> = self.send(&requests)?;
+
+        // the responses of a batch may be returned in any order
+        let mut responses: BTreeMap<u64, JsonRpcResponse<serde_json::Value>> = responses
+            .into_iter()
+            .filter_map(|response| response.id.map(|id| (id, response)))
+            .collect();
+        Ok(requests
+            .iter()
+            .map(|request| {
+                responses
+                    .remove(&request.id)
+                    .ok_or(Error::BatchResponseNotFound(request.id))
+                    .and_then(decode_result)
+            })
+            .collect())
+    }
+
+    // Helper functions
+    fn send<B: Serialize, R: DeserializeOwned>(&self, body: &B) -> Result<R> {
+        let body = serde_json::to_string(body).map_err(Error::JSONEncodeError)?;
+        debug!("execution rpc request: body={}", body);
+        let request = request_format(&self.endpoint, &body).map_err(|e| Error::HTTPError(e.to_string()))?;
+        let response = match self.http_client.execute(request, false) {
+            Ok(response) => response,
+            // the transport returns the response of a non-2xx status as an error
+            Err(e) => match e.downcast_ref::<HttpStatusError>() {
+                Some(e) => return Err(status_error(e.status, &e.body)),
+                None => return Err(Error::HTTPError(e.to_string())),
+            },
+        };
+        debug!("execution rpc response: status={} body={}", response.status(), response.body());
+        if !response.status().is_success() {
+            return Err(status_error(response.status(), response.body()));
+        }
+        serde_json::from_str(response.body()).map_err(Error::JSONDecodeError)
+    }
+}
+
+/// the body of a non-2xx response is not decoded as the result, but a JSON-RPC error in it is kept
+fn status_error(status: http::StatusCode, body: &str) -> Error {
+    match serde_json::from_str::<JsonRpcResponse<serde_json::Value>>(body) {
+        Ok(JsonRpcResponse { error: Some(error), .. }) => error.into(),
+        _ => Error::HTTPStatusError(status.as_u16(), body.to_string()),
+    }
+}
+
+fn decode_result<T: DeserializeOwned>(response: JsonRpcResponse<serde_json::Value>) -> Result<T> {
+    if let Some(error) = response.error {
+        return Err(error.into());
+    }
+    // a `null` result is valid for some methods, e.g. an unknown block
+    serde_json::from_value(response.result.unwrap_or_default()).map_err(Error::JSONDecodeError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        // the path may carry the API key of the provider
+        assert_eq!(RPCClient::new("https://rpc.example/v3/key").unwrap().endpoint, "https://rpc.example/v3/key");
+        for endpoint in ["rpc.example:8545", "ws://rpc.example", "not a url"] {
+            assert!(matches!(RPCClient::new(endpoint), Err(Error::InvalidEndpoint(_))), "{}", endpoint);
+        }
+    }
+
+    #[test]
+    fn test_status_error() {
+        assert!(matches!(
+            status_error(http::StatusCode::BAD_GATEWAY, "<html>bad gateway</html>"),
+            Error::HTTPStatusError(502, body) if body == "<html>bad gateway</html>"
+        ));
+        let body = r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32005,"message":"rate limited"}}"#;
+        assert!(matches!(
+            status_error(http::StatusCode::TOO_MANY_REQUESTS, body),
+            Error::JsonRpcError { code: -32005, message } if message == "rate limited"
+        ));
+    }
+}
@@ -0,0 +1,34 @@
+use displaydoc::Display;
+
+#[derive(Debug, Display)]
+pub enum Error {
+    /// http error: `{0}`
+    HTTPError(String),
+    /// http status error: status=`{0}` body=`{1}`
+    HTTPStatusError(u16, String),
+    /// invalid endpoint: `{0}`
+    InvalidEndpoint(String),
+    /// json encode error: `{0}`
+    JSONEncodeError(serde_json::Error),
+    /// json decode error: `{0}`
+    JSONDecodeError(serde_json::Error),
+    /// json-rpc error: code=`{code}` message=`{message}`
+    JsonRpcError { code: i64, message: String },
+    /// json-rpc response id mismatch: expected=`{0}` actual=`{1:?}`
+    ResponseIdMismatch(u64, Option<u64>),
+    /// json-rpc batch response not found: id=`{0}`
+    BatchResponseNotFound(u64),
+    /// other error: `{description}`
+    Other { description: String },
+}
+
+impl From<crate::http::JsonRpcError> for Error {
+    fn from(value: crate::http::JsonRpcError) -> Self {
+        Self::JsonRpcError {
+            code: value.code,
+            message: value.message,
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,153 @@
+use crate::consensus::src::types::H256;
+use patricia_merkle_trie::keccak::keccak_256;
+
+/// Builds the trie of `values` keyed by `rlp(index)`, as the transactions and receipts tries of a block are,
+/// and returns its root and the proof of the value at `index`
+///
+/// Nodes embedded in their parent, i.e. shorter than 32 bytes, are not included in the proof.
+pub fn ordered_trie_proof(values: &[Vec<u8>], index: u64) -> (H256, Vec<Vec<u8>>) {
+    if values.is_empty() {
+        return (H256(keccak_256(&rlp::NULL_RLP)), vec![]);
+    }
+    let mut items: Vec<(Vec<u8>, &[u8])> = values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| (to_nibbles(&rlp::encode(&(i as u64))), value.as_slice()))
+        .collect();
+    items.sort_by(|a, b| a.0.cmp(&b.0));
+    let target = to_nibbles(&rlp::encode(&index));
+
+    let mut proof = vec![];
+    let root = encode_node(&items, 0, Some(&target), &mut proof);
+    // nodes are collected from the leaf to the root
+    proof.reverse();
+    (H256(keccak_256(&root)), proof)
+}
+
+fn encode_node(
+    items: &[(Vec<u8>, &[u8])],
+    depth: usize,
+    target: Option<&[u8]>,
+    proof: &mut Vec<Vec<u8>>,
+) -> Vec<u8> {
+    let node = if items.len() == 1 {
+        let mut s = rlp::RlpStream::new_list(2);
+        s.append(&hex_prefix(&items[0].0[depth..], true));
+        s.append(&items[0].1.to_vec());
+        s.out().to_vec()
+    } else {
+        let (first, last) = (&items[0].0, &items[items.len() - 1].0);
+        let common = first[depth..]
+            .iter()
+            .zip(&last[depth..])
+            .take_while(|(a, b)| a == b)
+            .count();
+        if common > 0 {
+            let path = &first[depth..depth + common];
+            let child_target =
+                target.filter(|t| t.len() >= depth + common && &t[depth..depth + common] == path);
+            let child = encode_node(items, depth + common, child_target, proof);
+            let mut s = rlp::RlpStream::new_list(2);
+            s.append(&hex_prefix(path, false));
+            append_child(&mut s, &child);
+            s.out().to_vec()
+        } else {
+            // items are sorted, so a key ending at this branch comes first and the others are grouped by nibble
+            let (value, mut rest) = if first.len() == depth {
+                (Some(items[0].1), &items[1..])
+            } else {
+                (None, items)
+            };
+            let mut s = rlp::RlpStream::new_list(17);
+            for nibble in 0..16u8 {
+                let end = rest
+                    .iter()
+                    .position(|(key, _)| key[depth] != nibble)
+                    .unwrap_or(rest.len());
+                let (group, next) = rest.split_at(end);
+                rest = next;
+                if group.is_empty() {
+                    s.append_empty_data();
+                    continue;
+                }
+                let child_target = target.filter(|t| t.len() > depth && t[depth] == nibble);
+                let child = encode_node(group, depth + 1, child_target, proof);
+                append_child(&mut s, &child);
+            }
+            match value {
+                Some(value) => s.append(&value.to_vec()),
+                None => s.append_empty_data(),
+            };
+            s.out().to_vec()
+        }
+    };
+    if target.is_some() && (depth == 0 || node.len() >= 32) {
+        proof.push(node.clone());
+    }
+    node
+}
+
+/// a child node is embedded if its encoding is shorter than 32 bytes, otherwise it is referenced by its hash
+fn append_child(s: &mut rlp::RlpStream, child: &[u8]) {
+    if child.len() < 32 {
+        s.append_raw(child, 1);
+    } else {
+        s.append(&keccak_256(child).to_vec());
+    }
+}
+
+fn hex_prefix(nibbles: &[u8], leaf: bool) -> Vec<u8> {
+    let flag = if leaf { 2 } else { 0 };
+    let (mut bz, rest) = if nibbles.len() % 2 == 1 {
+        (vec![((flag + 1) << 4) | nibbles[0]], &nibbles[1..])
+    } else {
+        (vec![flag << 4], nibbles)
+    };
+    bz.extend(rest.chunks(2).map(|pair| (pair[0] << 4) | pair[1]));
+    bz
+}
+
+fn to_nibbles(bz: &[u8]) -> Vec<u8> {
+    bz.iter().flat_map(|b| [b >> 4, b & 0x0f]).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::light_client_verifier::src::execution::{ExecutionVerifier, EMPTY_TRIE_ROOT};
+    use crate::light_client_verifier::src::receipt::{Log, Receipt, ReceiptOutcome};
+
+    #[test]
+    fn test_ordered_trie_proof() {
+        assert_eq!(ordered_trie_proof(&[], 0).0, EMPTY_TRIE_ROOT);
+
+        // enough receipts for the keys to span `rlp(0)`, single byte and two byte keys
+        let receipts: Vec<Receipt> = (0..130u64)
+            .map(|i| Receipt {
+                tx_type: (i % 5) as u8,
+                outcome: ReceiptOutcome::Status(i % 7 != 0),
+                cumulative_gas_used: 21000 * (i + 1),
+                logs_bloom: vec![0; 256],
+                logs: vec![Log {
+                    address: Default::default(),
+                    topics: vec![H256([i as u8; 32])],
+                    data: vec![],
+                }],
+            })
+            .collect();
+        let values: Vec<Vec<u8>> = receipts.iter().map(|r| r.to_rlp_bytes()).collect();
+        for index in [0u64, 1, 15, 127, 128, 129] {
+            let (root, proof) = ordered_trie_proof(&values, index);
+            let receipt = ExecutionVerifier
+                .verify_receipt(root, index, proof)
+                .unwrap()
+                .unwrap();
+            assert_eq!(receipt, receipts[index as usize]);
+        }
+        let (root, proof) = ordered_trie_proof(&values, 130);
+        assert!(ExecutionVerifier
+            .verify_receipt(root, 130, proof)
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,357 @@
+use crate::consensus::src::types::{serde_hex, Address, H256, U64};
+use crate::light_client_verifier::src::{
+    block_header::ExecutionBlockHeader,
+    receipt::{self, Receipt, ReceiptOutcome},
+};
+use core::ops::Deref;
+use primitive_types::U256;
+
+/// Block parameter of the execution JSON-RPC API
+/// https://ethereum.github.io/execution-apis/api-documentation/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockId {
+    Number(u64),
+    Earliest,
+    Latest,
+    Safe,
+    Finalized,
+    Pending,
+}
+
+impl From<u64> for BlockId {
+    fn from(value: u64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<U64> for BlockId {
+    fn from(value: U64) -> Self {
+        Self::Number(value.0)
+    }
+}
+
+impl serde::Serialize for BlockId {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Number(number) => serializer.collect_str(&format_args!("0x{:x}", number)),
+            Self::Earliest => serializer.serialize_str("earliest"),
+            Self::Latest => serializer.serialize_str("latest"),
+            Self::Safe => serializer.serialize_str("safe"),
+            Self::Finalized => serializer.serialize_str("finalized"),
+            Self::Pending => serializer.serialize_str("pending"),
+        }
+    }
+}
+
+/// Unformatted data encoded as a `0x`-prefixed hex string
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct Bytes(#[serde(with = "serde_hex")] pub Vec<u8>);
+
+impl Deref for Bytes {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Bytes> for Vec<u8> {
+    fn from(value: Bytes) -> Self {
+        value.0
+    }
+}
+
+/// Quantity encoded as a `0x`-prefixed hex string without leading zeros
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Quantity(pub u64);
+
+impl From<Quantity> for U64 {
+    fn from(value: Quantity) -> Self {
+        U64(value.0)
+    }
+}
+
+impl serde::Serialize for Quantity {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("0x{:x}", self.0))
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Quantity {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        u64::from_str_radix(strip_quantity_prefix(&s)?, 16)
+            .map(Self)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// 256-bit quantity encoded as a `0x`-prefixed hex string without leading zeros
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct U256Quantity(pub U256);
+
+impl serde::Serialize for U256Quantity {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("0x{:x}", self.0))
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for U256Quantity {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        U256::from_str_radix(strip_quantity_prefix(&s)?, 16)
+            .map(Self)
+            .map_err(|e| serde::de::Error::custom(format!("{:?}", e)))
+    }
+}
+
+fn strip_quantity_prefix<E: serde::de::Error>(s: &str) -> Result<&str, E> {
+    match s.strip_prefix("0x") {
+        Some(digits) if !digits.is_empty() => Ok(digits),
+        _ => Err(E::custom(format!("invalid quantity: {}", s))),
+    }
+}
+
+/// Response of `eth_getBlockByNumber` without full transactions
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Block {
+    #[serde(flatten)]
+    pub header: BlockHeader,
+    /// hashes of the transactions, only covered by the block hash through `transactions_root`
+    #[serde(default)]
+    pub transactions: Vec<H256>,
+}
+
+/// BlockHeader is the block hash and the fields of `eth_getBlockByNumber` that it is computed from
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockHeader {
+    pub hash: H256,
+    pub parent_hash: H256,
+    pub sha3_uncles: H256,
+    pub miner: Address,
+    pub state_root: H256,
+    pub transactions_root: H256,
+    pub receipts_root: H256,
+    pub logs_bloom: Bytes,
+    pub difficulty: U256Quantity,
+    pub number: Quantity,
+    pub gas_limit: Quantity,
+    pub gas_used: Quantity,
+    pub timestamp: Quantity,
+    pub extra_data: Bytes,
+    pub mix_hash: H256,
+    pub nonce: Bytes,
+    /// London
+    pub base_fee_per_gas: Option<U256Quantity>,
+    /// Shanghai
+    pub withdrawals_root: Option<H256>,
+    /// Cancun
+    pub blob_gas_used: Option<Quantity>,
+    pub excess_blob_gas: Option<Quantity>,
+    pub parent_beacon_block_root: Option<H256>,
+    /// Prague
+    pub requests_hash: Option<H256>,
+}
+
+impl BlockHeader {
+    /// header whose hash is the block hash
+    pub fn to_header(&self) -> Result<ExecutionBlockHeader, String> {
+        Ok(ExecutionBlockHeader {
+            parent_hash: self.parent_hash,
+            ommers_hash: self.sha3_uncles,
+            coinbase: self.miner.clone(),
+            state_root: self.state_root,
+            transactions_root: self.transactions_root,
+            receipts_root: self.receipts_root,
+            logs_bloom: self.logs_bloom.to_vec(),
+            difficulty: self.difficulty.0,
+            number: self.number.0,
+            gas_limit: self.gas_limit.0,
+            gas_used: self.gas_used.0,
+            timestamp: self.timestamp.0,
+            extra_data: self.extra_data.to_vec(),
+            mix_hash: self.mix_hash,
+            nonce: self
+                .nonce
+                .as_slice()
+                .try_into()
+                .map_err(|_| format!("invalid nonce: 0x{}", hex::encode(self.nonce.as_slice())))?,
+            base_fee_per_gas: self.base_fee_per_gas.map(|v| v.0),
+            withdrawals_root: self.withdrawals_root,
+            blob_gas_used: self.blob_gas_used.map(|v| v.0),
+            excess_blob_gas: self.excess_blob_gas.map(|v| v.0),
+            parent_beacon_block_root: self.parent_beacon_block_root,
+            requests_hash: self.requests_hash,
+        })
+    }
+}
+
+/// Response of `eth_getProof`
+/// https://eips.ethereum.org/EIPS/eip-1186
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountProof {
+    pub address: Address,
+    pub account_proof: Vec<Bytes>,
+    pub balance: U256Quantity,
+    pub code_hash: H256,
+    pub nonce: Quantity,
+    pub storage_hash: H256,
+    pub storage_proof: Vec<StorageProof>,
+}
+
+impl AccountProof {
+    /// account proof in the form expected by `ExecutionVerifier`
+    pub fn account_proof_nodes(&self) -> Vec<Vec<u8>> {
+        self.account_proof.iter().map(|node| node.to_vec()).collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct StorageProof {
+    /// some clients return the key as requested, without leading zeros
+    #[serde(deserialize_with = "deserialize_storage_key")]
+    pub key: H256,
+    pub value: U256Quantity,
+    pub proof: Vec<Bytes>,
+}
+
+impl StorageProof {
+    /// storage proof in the form expected by `ExecutionVerifier`
+    pub fn proof_nodes(&self) -> Vec<Vec<u8>> {
+        self.proof.iter().map(|node| node.to_vec()).collect()
+    }
+}
+
+/// Response of `eth_getBlockReceipts` and `eth_getTransactionReceipt`
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionReceipt {
+    pub transaction_hash: H256,
+    pub transaction_index: Quantity,
+    pub block_hash: H256,
+    pub block_number: Quantity,
+    /// absent before Berlin
+    #[serde(rename = "type")]
+    pub tx_type: Option<Quantity>,
+    /// Byzantium
+    pub status: Option<Quantity>,
+    /// pre-Byzantium
+    pub root: Option<H256>,
+    pub cumulative_gas_used: Quantity,
+    pub logs_bloom: Bytes,
+    pub logs: Vec<Log>,
+}
+
+impl TransactionReceipt {
+    /// receipt in the form stored in the receipts trie
+    pub fn to_receipt(&self) -> Result<Receipt, String> {
+        let tx_type = self.tx_type.map(|t| t.0).unwrap_or_default();
+        if tx_type > u8::MAX as u64 || !receipt::is_supported_tx_type(tx_type as u8) {
+            return Err(format!("unsupported transaction type: {}", tx_type));
+        }
+        let outcome = match (self.status, self.root) {
+            (Some(status), _) => ReceiptOutcome::Status(status.0 == 1),
+            (None, Some(root)) => ReceiptOutcome::PostState(root),
+            (None, None) => return Err("receipt has neither status nor root".into()),
+        };
+        Ok(Receipt {
+            tx_type: tx_type as u8,
+            outcome,
+            cumulative_gas_used: self.cumulative_gas_used.0,
+            logs_bloom: self.logs_bloom.to_vec(),
+            logs: self
+                .logs
+                .iter()
+                .map(|log| receipt::Log {
+                    address: log.address.clone(),
+                    topics: log.topics.clone(),
+                    data: log.data.to_vec(),
+                })
+                .collect(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Log {
+    pub address: Address,
+    pub topics: Vec<H256>,
+    pub data: Bytes,
+    /// index of the log in the block
+    pub log_index: Option<Quantity>,
+}
+
+/// parse a storage slot given as a `0x`-prefixed hex string of at most 32 bytes
+pub fn parse_storage_key(s: &str) -> Result<H256, String> {
+    let digits = s.strip_prefix("0x").unwrap_or(s);
+    let digits = if digits.len() % 2 == 1 {
+        format!("0{}", digits)
+    } else {
+        digits.to_string()
+    };
+    let bytes = hex::decode(digits).map_err(|e| format!("invalid storage key: {}: {}", s, e))?;
+    if bytes.is_empty() || bytes.len() > 32 {
+        return Err(format!("invalid storage key: {}", s));
+    }
+    let mut key = [0u8; 32];
+    key[32 - bytes.len()..].copy_from_slice(&bytes);
+    Ok(H256(key))
+}
+
+fn deserialize_storage_key<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<H256, D::Error> {
+    let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+    parse_storage_key(&s).map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quantity() {
+        assert_eq!(serde_json::from_str::<Quantity>("\"0x0\"").unwrap(), Quantity(0));
+        assert_eq!(serde_json::from_str::<Quantity>("\"0x1b4\"").unwrap(), Quantity(436));
+        assert!(serde_json::from_str::<Quantity>("\"0x\"").is_err());
+        assert!(serde_json::from_str::<Quantity>("\"1b4\"").is_err());
+        assert_eq!(serde_json::to_string(&Quantity(436)).unwrap(), "\"0x1b4\"");
+        assert_eq!(
+            serde_json::from_str::<U256Quantity>("\"0xde0b6b3a7640000\"").unwrap(),
+            U256Quantity(U256::from(1_000_000_000_000_000_000u64))
+        );
+        assert_eq!(serde_json::to_string(&BlockId::Number(16)).unwrap(), "\"0x10\"");
+        assert_eq!(serde_json::to_string(&BlockId::Finalized).unwrap(), "\"finalized\"");
+    }
+
+    #[test]
+    fn test_account_proof() {
+        let proof: AccountProof = serde_json::from_str(
+            r#"{
+                "address": "0x7f0d15c7faae65896648c8273b6d7e43f58fa842",
+                "accountProof": ["0xf90211a0", "0xf8718080"],
+                "balance": "0x0",
+                "codeHash": "0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+                "nonce": "0x1",
+                "storageHash": "0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421",
+                "storageProof": [
+                    {
+                        "key": "0x0000000000000000000000000000000000000000000000000000000000000000",
+                        "value": "0x0",
+                        "proof": []
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(proof.nonce, Quantity(1));
+        assert_eq!(proof.account_proof_nodes(), vec![vec![0xf9, 0x02, 0x11, 0xa0], vec![0xf8, 0x71, 0x80, 0x80]]);
+        assert_eq!(proof.storage_proof.len(), 1);
+        assert!(proof.storage_proof[0].proof_nodes().is_empty());
+        assert_eq!(parse_storage_key("0x1").unwrap().0[31], 1);
+        assert_eq!(parse_storage_key("0x0").unwrap(), H256::default());
+        assert!(parse_storage_key("0x").is_err());
+    }
+}
@@ -2,25 +2,37 @@ use serde::{Deserialize, Serialize};
 use http::Request;
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct TransactionRequest {
+pub struct JsonRpcRequest {
     pub jsonrpc: String,
-    pub id: i32,
+    pub id: u64,
     pub method: String,
-    pub params: Vec<String>
+    pub params: serde_json::Value,
+}
+
+impl JsonRpcRequest {
+    pub fn new(id: u64, method: impl Into<String>, params: serde_json::Value) -> Self {
+        Self {
+            jsonrpc: "2.0".into(),
+            id,
+            method: method.into(),
+            params,
+        }
+    }
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct JsonRpcResponse<T> {
-    jsonrpc: String,
-    id: u64,
-    result: Option<T>,
-    error: Option<JsonRpcError>,
+    pub jsonrpc: String,
+    /// `null` if the server could not read the id of the request
+    pub id: Option<u64>,
+    pub result: Option<T>,
+    pub error: Option<JsonRpcError>,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct JsonRpcError {
-    code: i64,
-    message: String,
+    pub code: i64,
+    pub message: String,
 }
 
 pub fn request_format(uri: &str, body: &str) -> Result<Request<String>, Box<dyn std::error::Error>> {
@@ -177,6 +177,15 @@ impl Client {
     ) -> Result<Response<String>, Box<dyn std::error::Error>> {
         self.execute_request(request, display)
     }
+
+    /// Executes a `Request` whose response body is binary, e.g. SSZ, and must not go through a string.
+    pub fn execute_bytes(
+        &self,
+        request: Request<String>,
+        display: bool
+    ) -> Result<Response<Vec<u8>>, Box<dyn std::error::Error>> {
+        self.execute_request_bytes(request, display)
+    }
     
     /// Convenience method to make a `GET` request to a URL.
     ///
@@ -199,6 +208,7 @@ impl Client {
 
 pub trait ExecuteRequest {
     fn execute_request(&self, req: Request<String>, display: bool) -> Result<Response<String>, Box<dyn std::error::Error>>;
+    fn execute_request_bytes(&self, req: Request<String>, display: bool) -> Result<Response<Vec<u8>>, Box<dyn std::error::Error>>;
 }
 
 impl ExecuteRequest for Client {
@@ -214,8 +224,48 @@ impl ExecuteRequest for Client {
         }
         
         if !response.status().is_success() {
-            return Err(format!("HTTP error: {}, {:?}", response.status(), response).into());
+            return Err(Box::new(HttpStatusError {
+                status: response.status(),
+                body: response.body().clone(),
+            }));
         }
         Ok(response)
     }
-}
\ No newline at end of file
+
+    fn execute_request_bytes(&self, req: Request<String>, display: bool) -> Result<Response<Vec<u8>>, Box<dyn std::error::Error>> {
+        if display {
+            klave::notifier::send_string(&format!("execute_request request: {:?}", req));
+        }
+
+        // the body is handed over as is, unlike `request` which decodes it as UTF-8
+        let response = klave::https::request_raw(&req)?;
+
+        if display {
+            klave::notifier::send_string(&format!("execute_request response: status={} {} bytes", response.status(), response.body().len()));
+        }
+
+        if !response.status().is_success() {
+            return Err(Box::new(HttpStatusError {
+                status: response.status(),
+                body: String::from_utf8_lossy(response.body()).into_owned(),
+            }));
+        }
+        Ok(response)
+    }
+}
+
+/// HttpStatusError is the error of a response that was received with a non-2xx status,
+/// as opposed to a request that could not be sent or whose response never came
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpStatusError {
+    pub status: StatusCode,
+    pub body: String,
+}
+
+impl std::fmt::Display for HttpStatusError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "HTTP error: {}, {}", self.status, self.body)
+    }
+}
+
+impl std::error::Error for HttpStatusError {}
\ No newline at end of file
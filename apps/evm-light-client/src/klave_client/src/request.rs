@@ -1,4 +1,5 @@
 use super::client::Client;
+use http::header::{HeaderName, HeaderValue};
 use http::{Request, Response};
 
 /// A builder to construct the properties of a `Request`.
@@ -17,10 +18,20 @@ impl RequestBuilder {
         builder
     }
 
+    /// Sets a header of the request, replacing the default value if any.
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> RequestBuilder {
+        self.request.headers_mut().insert(key, value);
+        self
+    }
+
     pub fn send(self, display: bool) -> Result<Response<String>, Box<dyn std::error::Error>> {
         match self.client.execute(self.request, display) {
             Ok(response) => Ok(response),
             Err(e) => Err(e),
         }
     }    
+
+    pub fn send_bytes(self, display: bool) -> Result<Response<Vec<u8>>, Box<dyn std::error::Error>> {
+        self.client.execute_bytes(self.request, display)
+    }
 }
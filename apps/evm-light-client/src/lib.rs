@@ -1,11 +1,14 @@
 #[allow(warnings)]
 mod bindings;
 
+use bindings::exports::component::evm_light_client::light_client as api;
 use bindings::Guest;
 use klave;
 
 pub mod light_client;
+pub mod requests;
 pub mod consensus;
+pub mod execution_rpc;
 pub mod klave_client;
 pub mod light_client_cli;
 pub mod light_client_verifier;
@@ -34,13 +37,30 @@ impl Guest for Component {
     fn register_routes(){  
         klave::router::add_user_query(&String::from("light_client_init"));
         klave::router::add_user_query(&String::from("light_client_update"));
+        klave::router::add_user_transaction(&String::from("light_client_update_and_commit"));
+        klave::router::add_user_query(&String::from("light_client_update_optimistic"));
         klave::router::add_user_query(&String::from("light_client_update_for_block_number"));
         klave::router::add_user_query(&String::from("light_client_update_for_period"));
         klave::router::add_user_query(&String::from("light_client_update_for_slot"));
         klave::router::add_user_query(&String::from("light_client_fetch_header_from_slot"));
         klave::router::add_user_query(&String::from("light_client_fetch_block_from_slot"));    
+        klave::router::add_user_query(&String::from("light_client_verify_account"));
+        klave::router::add_user_query(&String::from("light_client_verify_storage"));
+        klave::router::add_user_query(&String::from("light_client_verify_log"));
+        klave::router::add_user_query(&String::from("light_client_verify_execution_header"));
+        klave::router::add_user_query(&String::from("light_client_verify_transaction"));
 
         klave::router::add_user_transaction(&String::from("light_client_persist"));
+        klave::router::add_user_query(&String::from("light_client_get_state"));
+        klave::router::add_user_query(&String::from("light_client_get_header_at_slot"));
+        klave::router::add_user_query(&String::from("light_client_get_header_at_block_number"));
+        klave::router::add_user_query(&String::from("light_client_audit"));
+        klave::router::add_user_transaction(&String::from("light_client_submit_misbehaviour"));
+        klave::router::add_user_transaction(&String::from("light_client_unfreeze"));
+        klave::router::add_user_transaction(&String::from("light_client_add_member"));
+        klave::router::add_user_transaction(&String::from("light_client_remove_member"));
+        klave::router::add_user_query(&String::from("light_client_get_acl"));
+        klave::router::add_user_transaction(&String::from("light_client_claim_owner"));
     }
 
     fn light_client_init(cmd: String){
@@ -51,6 +71,14 @@ impl Guest for Component {
         light_client::light_client_update(cmd);
     }
 
+    fn light_client_update_and_commit(cmd: String){
+        light_client::light_client_update_and_commit(cmd);
+    }
+
+    fn light_client_update_optimistic(cmd: String){
+        light_client::light_client_update_optimistic(cmd);
+    }
+
     fn light_client_update_for_block_number(cmd: String){
         light_client::light_client_update_for_block_number(cmd);
     }
@@ -74,6 +102,97 @@ impl Guest for Component {
     fn light_client_persist(cmd: String){
         light_client::light_client_persist(cmd);
     }
+
+    fn light_client_verify_account(cmd: String){
+        light_client::light_client_verify_account(cmd);
+    }
+
+    fn light_client_verify_storage(cmd: String){
+        light_client::light_client_verify_storage(cmd);
+    }
+
+    fn light_client_verify_log(cmd: String){
+        light_client::light_client_verify_log(cmd);
+    }
+
+    fn light_client_verify_execution_header(cmd: String){
+        light_client::light_client_verify_execution_header(cmd);
+    }
+
+    fn light_client_verify_transaction(cmd: String){
+        light_client::light_client_verify_transaction(cmd);
+    }
+
+    fn light_client_get_state(cmd: String){
+        light_client::light_client_get_state(cmd);
+    }
+
+    fn light_client_get_header_at_slot(cmd: String){
+        light_client::light_client_get_header_at_slot(cmd);
+    }
+
+    fn light_client_get_header_at_block_number(cmd: String){
+        light_client::light_client_get_header_at_block_number(cmd);
+    }
+
+    fn light_client_audit(cmd: String){
+        light_client::light_client_audit(cmd);
+    }
+
+    fn light_client_submit_misbehaviour(cmd: String){
+        light_client::light_client_submit_misbehaviour(cmd);
+    }
+
+    fn light_client_unfreeze(cmd: String){
+        light_client::light_client_unfreeze(cmd);
+    }
+
+    fn light_client_add_member(cmd: String){
+        light_client::light_client_add_member(cmd);
+    }
+
+    fn light_client_remove_member(cmd: String){
+        light_client::light_client_remove_member(cmd);
+    }
+
+    fn light_client_get_acl(cmd: String){
+        light_client::light_client_get_acl(cmd);
+    }
+
+    fn light_client_claim_owner(cmd: String){
+        light_client::light_client_claim_owner(cmd);
+    }
+}
+
+impl api::Guest for Component {
+    fn init(params: api::InitParams) -> Result<api::PersistInfo, api::ErrorInfo> {
+        light_client::init(params)
+    }
+
+    fn update(
+        network: api::Network,
+        secondary_beacon_endpoints: Vec<String>,
+        target: api::UpdateTarget,
+    ) -> Result<api::UpdateResult, api::ErrorInfo> {
+        light_client::update(network, secondary_beacon_endpoints, target)
+    }
+
+    fn verify_account(
+        params: api::ReadParams,
+        address: String,
+        account_proof: Option<String>,
+    ) -> Result<api::VerifiedAccount, api::ErrorInfo> {
+        light_client::verify_account(params, address, account_proof)
+    }
+
+    fn verify_storage(
+        params: api::ReadParams,
+        address: String,
+        slots: Vec<String>,
+        account_proof: Option<String>,
+    ) -> Result<api::VerifiedStorage, api::ErrorInfo> {
+        light_client::verify_storage(params, address, slots, account_proof)
+    }
 }
 
 bindings::export!(Component with_types_in bindings);
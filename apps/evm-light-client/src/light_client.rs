@@ -1,296 +1,486 @@
-use serde_json::Value;
-use crate::light_client_cli::src::{cli::{Cli, Opts}, commands::{BlockCommand, Command, HeaderCommand, InitCommand, PersistCommand, UpdateCommand}};
+use crate::bindings::exports::component::evm_light_client::light_client as api;
+use crate::light_client_cli::src::{
+    cli::{Opts, Output},
+    commands::{PersistCommand, UpdateOutput, VerifiedAccount, VerifiedStorage},
+    response::{send_data, send_error, send_failure, ErrorBody, COMMAND_FAILED, INVALID_REQUEST},
+    state::{Anchor, StateSummary},
+};
+use crate::requests::{
+    AddMemberRequest, AuditRequest, ClaimOwnerRequest, Endpoints, FetchBlockRequest, FetchHeaderRequest, GetAclRequest,
+    GetHeaderAtBlockNumberRequest, GetHeaderAtSlotRequest, GetStateRequest, InitRequest, JsonArg, NetworkParams,
+    OptimisticUpdateRequest, PersistRequest, Quantity, RemoveMemberRequest, Request, SubmitMisbehaviourRequest,
+    UnfreezeRequest, UpdateAndCommitRequest, UpdateForBlockNumberRequest, UpdateForPeriodRequest, UpdateForSlotRequest,
+    UpdateRequest, VerifyAccountRequest, VerifyExecutionHeaderRequest, VerifyLogRequest,
+    VerifyStorageRequest, VerifyTransactionRequest,
+};
 
-static LEDGER_TABLE: &str = "light_client";
+/// parses the request of the route and runs its command, the outcome is sent back in a single response envelope
+fn run<R: Request>(cmd: String) {
+    let command_line = match R::parse(&cmd) {
+        Ok(command_line) => command_line,
+        Err(e) => return send_error(INVALID_REQUEST, e),
+    };
+    match command_line.run() {
+        Ok(output) => send_data(&output),
+        Err(e) => send_failure(&e),
+    }
+}
 
 pub fn light_client_init(cmd: String){
-    let Ok(v) = serde_json::from_str::<Value>(&cmd) else {
-        klave::notifier::send_string(&format!("failed to parse '{}' as json", cmd));
-        return
-    };
+    run::<InitRequest>(cmd)
+}
 
-    let network_name = match v["network_name"].as_str() {
-        Some(c) => c,
-        None => {
-            klave::notifier::send_string(&format!("ERROR: network not found"));
-            return;
-        }
-    };
-    let beacon_endpoint = match v["beacon_endpoint"].as_str() {
-        Some(c) => c,
-        None => {
-            klave::notifier::send_string(&format!("ERROR: beacon_endpoint not found"));
-            return;
-        }
-    };
+pub fn light_client_persist(cmd: String){
+    run::<PersistRequest>(cmd)
+}
 
-    let command_line = Cli {
-        opts: Opts {
-            ledger_table: String::from(format!("{}_{}", LEDGER_TABLE, network_name)),
-            beacon_endpoint: String::from(beacon_endpoint),
-            network: String::from(network_name),
-        },
-        cmd: Command::Init(InitCommand {
-            trusted_block_root: v["trusted_block_root"].as_str().map(|s| s.to_string()),
-            untrusted_slot: v["untrusted_slot"].as_u64(),
-        }),
-    };
-    let _ = command_line.run();
+pub fn light_client_update(cmd: String){
+    run::<UpdateRequest>(cmd)
 }
 
-pub fn light_client_persist(cmd: String){       
-    let Ok(v) = serde_json::from_str::<Value>(&cmd) else {
-        klave::notifier::send_string(&format!("failed to parse '{}' as json", cmd));
-        return
-    };
+pub fn light_client_update_and_commit(cmd: String){
+    run::<UpdateAndCommitRequest>(cmd)
+}
 
-    let network_name = match v["network_name"].as_str() {
-        Some(c) => c,
-        None => {
-            klave::notifier::send_string(&format!("ERROR: network not found"));
-            return;
-        }
-    };
-    let beacon_endpoint = match v["beacon_endpoint"].as_str() {
-        Some(c) => c,
-        None => {
-            klave::notifier::send_string(&format!("ERROR: beacon_endpoint not found"));
-            return;
-        }
-    };
+pub fn light_client_update_optimistic(cmd: String){
+    run::<OptimisticUpdateRequest>(cmd)
+}
 
-    let command_line = Cli {
-        opts: Opts {
-            ledger_table: String::from(format!("{}_{}", LEDGER_TABLE, network_name)),
-            beacon_endpoint: String::from(beacon_endpoint),
-            network: String::from(network_name),
-        },
-        cmd: Command::Persist(PersistCommand {
-            bootstrap_info: v["bootstrap_info"].as_str().map(|s| s.to_string()),
-            state_info: v["state_info"].as_str().map(|s| s.to_string()),
-            genesis_info: v["genesis_info"].as_str().map(|s| s.to_string()),
-        }),
-    };
-    let _ = command_line.run();
+pub fn light_client_update_for_block_number(cmd: String){
+    run::<UpdateForBlockNumberRequest>(cmd)
 }
 
-pub fn light_client_update(cmd: String){       
-    let Ok(v) = serde_json::from_str::<Value>(&cmd) else {
-        klave::notifier::send_string(&format!("failed to parse '{}' as json", cmd));
-        return
-    };
+pub fn light_client_update_for_period(cmd: String){
+    run::<UpdateForPeriodRequest>(cmd)
+}
 
-    let network_name = match v["network_name"].as_str() {
-        Some(c) => c,
-        None => {
-            klave::notifier::send_string(&format!("ERROR: network not found"));
-            return;
-        }
-    };
-    let beacon_endpoint = match v["beacon_endpoint"].as_str() {
-        Some(c) => c,
-        None => {
-            klave::notifier::send_string(&format!("ERROR: beacon_endpoint not found"));
-            return;
-        }
-    };
+pub fn light_client_update_for_slot(cmd: String){
+    run::<UpdateForSlotRequest>(cmd)
+}
 
-    let command_line = Cli {
-        opts: Opts {
-            ledger_table: String::from(format!("{}_{}", LEDGER_TABLE, network_name)),
-            beacon_endpoint: String::from(beacon_endpoint),
-            network: String::from(network_name),
-        },
-        cmd: Command::Update(UpdateCommand {
-            target: None,
-        }),
-    };
-    let _ = command_line.run();
+pub fn light_client_fetch_header_from_slot(cmd: String){
+    run::<FetchHeaderRequest>(cmd)
 }
 
-//block number: u64
-pub fn light_client_update_for_block_number(cmd: String){       
-    let Ok(v) = serde_json::from_str::<Value>(&cmd) else {
-        klave::notifier::send_string(&format!("failed to parse '{}' as json", cmd));
-        return
-    };
+pub fn light_client_fetch_block_from_slot(cmd: String){
+    run::<FetchBlockRequest>(cmd)
+}
 
-    let network_name = match v["network_name"].as_str() {
-        Some(c) => c,
-        None => {
-            klave::notifier::send_string(&format!("ERROR: network not found"));
-            return;
-        }
-    };
-    let beacon_endpoint = match v["beacon_endpoint"].as_str() {
-        Some(c) => c,
-        None => {
-            klave::notifier::send_string(&format!("ERROR: beacon_endpoint not found"));
-            return;
-        }
-    };
+pub fn light_client_verify_account(cmd: String){
+    run::<VerifyAccountRequest>(cmd)
+}
 
-    let command_line = Cli {
-        opts: Opts {
-            ledger_table: String::from(format!("{}_{}", LEDGER_TABLE, network_name)),
-            beacon_endpoint: String::from(beacon_endpoint),
-            network: String::from(network_name),
-        },
-        cmd: Command::Update(UpdateCommand {
-            target: match v["block_number"].as_str() {
-                Some(s) => match s.strip_prefix("0x") {
-                    Some(s) => Some(s.to_string() + &String::from("bn")),
-                    None => {
-                        klave::notifier::send_string(&format!("failed to parse '{}' as json", cmd));
-                        return                                                
-                    },
-                },
-                None => {
-                    klave::notifier::send_string(&format!("failed to parse '{}' as json", cmd));
-                    return
-                }
-            }
-        }),
-    };
-    let _ = command_line.run();
+pub fn light_client_verify_storage(cmd: String){
+    run::<VerifyStorageRequest>(cmd)
 }
 
-//period: u64
-pub fn light_client_update_for_period(cmd: String){
-    let Ok(v) = serde_json::from_str::<Value>(&cmd) else {
-        klave::notifier::send_string(&format!("failed to parse '{}' as json", cmd));
-        return
-    };
+pub fn light_client_verify_log(cmd: String){
+    run::<VerifyLogRequest>(cmd)
+}
 
-    let network_name = match v["network_name"].as_str() {
-        Some(c) => c,
-        None => {
-            klave::notifier::send_string(&format!("ERROR: network not found"));
-            return;
-        }
-    };
-    let beacon_endpoint = match v["beacon_endpoint"].as_str() {
-        Some(c) => c,
-        None => {
-            klave::notifier::send_string(&format!("ERROR: beacon_endpoint not found"));
-            return;
-        }
-    };
+pub fn light_client_verify_execution_header(cmd: String){
+    run::<VerifyExecutionHeaderRequest>(cmd)
+}
 
-    let command_line = Cli {
-        opts: Opts {
-            ledger_table: String::from(format!("{}_{}", LEDGER_TABLE, network_name)),
-            beacon_endpoint: String::from(beacon_endpoint),
-            network: String::from(network_name),
-        },
-        cmd: Command::Update(UpdateCommand {
-            target: v["period"].as_str().map(|s| s.to_string() + &String::from("period"))
-        }),
-    };
-    let _ = command_line.run();
+pub fn light_client_verify_transaction(cmd: String){
+    run::<VerifyTransactionRequest>(cmd)
 }
 
-//slot: u64
-pub fn light_client_update_for_slot(cmd: String){
-    let Ok(v) = serde_json::from_str::<Value>(&cmd) else {
-        klave::notifier::send_string(&format!("failed to parse '{}' as json", cmd));
-        return
-    };
+pub fn light_client_get_state(cmd: String){
+    run::<GetStateRequest>(cmd)
+}
 
-    let network_name = match v["network_name"].as_str() {
-        Some(c) => c,
-        None => {
-            klave::notifier::send_string(&format!("ERROR: network not found"));
-            return;
-        }
-    };
-    let beacon_endpoint = match v["beacon_endpoint"].as_str() {
-        Some(c) => c,
-        None => {
-            klave::notifier::send_string(&format!("ERROR: beacon_endpoint not found"));
-            return;
-        }
-    };
+pub fn light_client_get_header_at_slot(cmd: String){
+    run::<GetHeaderAtSlotRequest>(cmd)
+}
+
+pub fn light_client_get_header_at_block_number(cmd: String){
+    run::<GetHeaderAtBlockNumberRequest>(cmd)
+}
+
+pub fn light_client_audit(cmd: String){
+    run::<AuditRequest>(cmd)
+}
+
+pub fn light_client_submit_misbehaviour(cmd: String){
+    run::<SubmitMisbehaviourRequest>(cmd)
+}
+
+//admin only: clears the frozen flag and returns the evidence
+pub fn light_client_unfreeze(cmd: String){
+    run::<UnfreezeRequest>(cmd)
+}
 
-    let command_line = Cli {
-        opts: Opts {
-            ledger_table: String::from(format!("{}_{}", LEDGER_TABLE, network_name)),
-            beacon_endpoint: String::from(beacon_endpoint),
-            network: String::from(network_name),
-        },
-        cmd: Command::Update(UpdateCommand {
-            target: v["slot"].as_str().map(|s| s.to_string() + &String::from("slot"))
+//admin only: owners are only added or removed by owners
+pub fn light_client_add_member(cmd: String){
+    run::<AddMemberRequest>(cmd)
+}
+
+pub fn light_client_remove_member(cmd: String){
+    run::<RemoveMemberRequest>(cmd)
+}
+
+pub fn light_client_get_acl(cmd: String){
+    run::<GetAclRequest>(cmd)
+}
+
+//the first owner of a ledger without an acl, the owner of a fresh ledger is otherwise the sender of its first bootstrap
+pub fn light_client_claim_owner(cmd: String){
+    run::<ClaimOwnerRequest>(cmd)
+}
+
+// The typed entry points of the `light-client` interface run the same commands as the routes,
+// and map the output of the command to the types of the interface instead of notifying it.
+
+/// runs the command of the request and returns its output
+fn call<R: Request>(request: R) -> Result<(Opts, Output), api::ErrorInfo> {
+    let command_line = request
+        .into_cli()
+        .map_err(|e| api::ErrorInfo::from(ErrorBody::new(INVALID_REQUEST, e)))?;
+    let opts = command_line.opts.clone();
+    let output = command_line.run().map_err(|e| ErrorBody::from_error(&e))?;
+    Ok((opts, output))
+}
+
+fn unexpected_output(name: &str) -> api::ErrorInfo {
+    ErrorBody::new(COMMAND_FAILED, format!("no {} returned", name)).into()
+}
+
+fn state_summary(opts: &Opts, state_info: Option<&str>) -> Result<api::StateSummary, api::ErrorInfo> {
+    let summary = opts
+        .state_summary(state_info)
+        .map_err(|e| ErrorBody::from_error(&e))?;
+    Ok(summary.into())
+}
+
+fn network_params(network: api::Network) -> NetworkParams {
+    NetworkParams {
+        network_name: network.name,
+        beacon_endpoint: Some(Endpoints::Many(network.beacon_endpoints)),
+    }
+}
+
+fn read_network_params(params: &api::ReadParams) -> NetworkParams {
+    NetworkParams {
+        network_name: params.network_name.clone(),
+        beacon_endpoint: None,
+    }
+}
+
+fn anchor(anchor: api::Anchor) -> String {
+    match anchor {
+        api::Anchor::Finalized => Anchor::Finalized,
+        api::Anchor::Optimistic => Anchor::Optimistic,
+    }
+    .to_string()
+}
+
+fn hex_string(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+pub fn init(params: api::InitParams) -> Result<api::PersistInfo, api::ErrorInfo> {
+    let (opts, output) = call(InitRequest {
+        network: network_params(params.network),
+        trusted_block_root: params.trusted_block_root,
+        untrusted_slot: params.untrusted_slot.map(Quantity),
+    })?;
+    persist_info(&opts, output)
+}
+
+/// an init returns the persist info with the bootstrap and the genesis
+fn persist_info(opts: &Opts, output: Output) -> Result<api::PersistInfo, api::ErrorInfo> {
+    match output {
+        Output::Persist(PersistCommand {
+            bootstrap_info: Some(bootstrap_info),
+            state_info: Some(state_info),
+            genesis_info: Some(genesis_info),
+            ..
+        }) => Ok(api::PersistInfo {
+            state: state_summary(opts, Some(&state_info))?,
+            bootstrap_info,
+            state_info,
+            genesis_info,
         }),
-    };
-    let _ = command_line.run();
+        _ => Err(unexpected_output("persist info")),
+    }
 }
 
-//slot: u64
-pub fn light_client_fetch_header_from_slot(cmd: String){       
-    let Ok(v) = serde_json::from_str::<Value>(&cmd) else {
-        klave::notifier::send_string(&format!("failed to parse '{}' as json", cmd));
-        return
-    };
+pub fn update(
+    network: api::Network,
+    secondary_beacon_endpoints: Vec<String>,
+    target: api::UpdateTarget,
+) -> Result<api::UpdateResult, api::ErrorInfo> {
+    let network = network_params(network);
+    let (opts, output) = match target {
+        api::UpdateTarget::Latest => call(UpdateRequest {
+            network,
+            secondary_beacon_endpoints,
+        }),
+        api::UpdateTarget::BlockNumber(block_number) => call(UpdateForBlockNumberRequest {
+            network,
+            secondary_beacon_endpoints,
+            block_number: Quantity(block_number),
+        }),
+        api::UpdateTarget::Period(period) => call(UpdateForPeriodRequest {
+            network,
+            secondary_beacon_endpoints,
+            period: Quantity(period),
+        }),
+        api::UpdateTarget::Slot(slot) => call(UpdateForSlotRequest {
+            network,
+            secondary_beacon_endpoints,
+            slot: Quantity(slot),
+        }),
+    }?;
+    update_result(&opts, output)
+}
 
-    let network_name = match v["network_name"].as_str() {
-        Some(c) => c,
-        None => {
-            klave::notifier::send_string(&format!("ERROR: network not found"));
-            return;
-        }
-    };
-    let beacon_endpoint = match v["beacon_endpoint"].as_str() {
-        Some(c) => c,
-        None => {
-            klave::notifier::send_string(&format!("ERROR: beacon_endpoint not found"));
-            return;
+/// an update returns the state to persist with the updates from the stored state, none if no update was applied
+fn update_result(opts: &Opts, output: Output) -> Result<api::UpdateResult, api::ErrorInfo> {
+    let Output::Update(UpdateOutput { persist, .. }) = output else {
+        return Err(unexpected_output("update result"));
+    };
+    let (state_info, updates_info) = match persist {
+        Some(persist) => (persist.state_info, persist.updates_info),
+        None => (None, None),
+    };
+    Ok(api::UpdateResult {
+        state: state_summary(opts, state_info.as_deref())?,
+        state_info,
+        updates_info,
+    })
+}
+
+pub fn verify_account(
+    params: api::ReadParams,
+    address: String,
+    account_proof: Option<String>,
+) -> Result<api::VerifiedAccount, api::ErrorInfo> {
+    let (_, output) = call(VerifyAccountRequest {
+        network: read_network_params(&params),
+        address,
+        block_number: params.block_number.map(Quantity),
+        anchor: params.anchor.map(anchor),
+        account_proof: account_proof.map(JsonArg),
+        execution_endpoint: params.execution_endpoint,
+    })?;
+    match output {
+        Output::Account(verified) => Ok(verified.into()),
+        _ => Err(unexpected_output("verified account")),
+    }
+}
+
+pub fn verify_storage(
+    params: api::ReadParams,
+    address: String,
+    slots: Vec<String>,
+    account_proof: Option<String>,
+) -> Result<api::VerifiedStorage, api::ErrorInfo> {
+    let (_, output) = call(VerifyStorageRequest {
+        network: read_network_params(&params),
+        address,
+        slots,
+        block_number: params.block_number.map(Quantity),
+        anchor: params.anchor.map(anchor),
+        account_proof: account_proof.map(JsonArg),
+        execution_endpoint: params.execution_endpoint,
+    })?;
+    match output {
+        Output::Storage(verified) => Ok(verified.into()),
+        _ => Err(unexpected_output("verified storage")),
+    }
+}
+
+impl From<ErrorBody> for api::ErrorInfo {
+    fn from(value: ErrorBody) -> Self {
+        Self {
+            code: value.code,
+            message: value.message,
+            details: (!value.details.is_null()).then(|| value.details.to_string()),
         }
-    };
+    }
+}
 
-    let command_line = Cli {
-        opts: Opts {
-            ledger_table: String::from(format!("{}_{}", LEDGER_TABLE, network_name)),
-            beacon_endpoint: String::from(beacon_endpoint),
-            network: String::from(network_name),
-        },
-        cmd: Command::Header(HeaderCommand {
-            slot: v["slot"].as_u64(),
-        }),
-    };
-    let _ = command_line.run();
+impl From<StateSummary> for api::StateSummary {
+    fn from(value: StateSummary) -> Self {
+        Self {
+            finalized_slot: value.finalized_slot.0,
+            finalized_block_number: value.finalized_block_number.0,
+            finalized_block_hash: hex_string(&value.finalized_block_hash.0),
+            finalized_state_root: hex_string(&value.finalized_state_root.0),
+            optimistic_slot: value.optimistic_slot.map(|slot| slot.0),
+        }
+    }
 }
 
-//slot: u64
-pub fn light_client_fetch_block_from_slot(cmd: String){       
-    let Ok(v) = serde_json::from_str::<Value>(&cmd) else {
-        klave::notifier::send_string(&format!("failed to parse '{}' as json", cmd));
-        return
-    };
+impl From<VerifiedAccount> for api::VerifiedAccount {
+    fn from(value: VerifiedAccount) -> Self {
+        Self {
+            address: hex_string(&value.address.0),
+            exists: value.exists,
+            nonce: value.nonce.0,
+            balance: format!("0x{:x}", value.balance.0),
+            storage_root: hex_string(&value.storage_root.0),
+            code_hash: hex_string(&value.code_hash.0),
+            block_number: value.block_number.0,
+            state_root: hex_string(&value.state_root.0),
+        }
+    }
+}
 
-    let network_name = match v["network_name"].as_str() {
-        Some(c) => c,
-        None => {
-            klave::notifier::send_string(&format!("ERROR: network not found"));
-            return;
+impl From<VerifiedStorage> for api::VerifiedStorage {
+    fn from(value: VerifiedStorage) -> Self {
+        Self {
+            account: value.account.into(),
+            storage: value
+                .storage
+                .into_iter()
+                .map(|(key, value)| api::StorageSlot {
+                    key: hex_string(&key.0),
+                    value: hex_string(&value.0),
+                })
+                .collect(),
         }
-    };
-    let beacon_endpoint = match v["beacon_endpoint"].as_str() {
-        Some(c) => c,
-        None => {
-            klave::notifier::send_string(&format!("ERROR: beacon_endpoint not found"));
-            return;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consensus::src::types::{Address, H256, U64};
+    use crate::execution_rpc::src::types::U256Quantity;
+    use crate::light_client_cli::src::{
+        response::Response,
+        state::LightClientStore,
+        testing::{context, genesis, opts},
+    };
+    use crate::light_client_verifier::src::consensus::test_utils::{
+        gen_bootstrap, gen_update, minimal_context, MockSyncCommittee,
+    };
+    use primitive_types::U256;
+
+    fn verified_account() -> VerifiedAccount {
+        VerifiedAccount {
+            address: Address([0x11; 20]),
+            exists: true,
+            nonce: U64(1),
+            balance: U256Quantity(U256::from(255)),
+            storage_root: H256([0x22; 32]),
+            code_hash: H256([0x33; 32]),
+            block_number: U64(10),
+            state_root: H256([0x44; 32]),
         }
-    };
+    }
 
-    let command_line = Cli {
-        opts: Opts {
-            ledger_table: String::from(format!("{}_{}", LEDGER_TABLE, network_name)),
-            beacon_endpoint: String::from(beacon_endpoint),
-            network: String::from(network_name),
-        },
-        cmd: Command::Block(BlockCommand {
-            slot: v["slot"].as_u64(),
-        }),
-    };
-    let _ = command_line.run();
-}
\ No newline at end of file
+    #[test]
+    fn test_persist_info() {
+        let signer = MockSyncCommittee::new(1);
+        let bootstrap = gen_bootstrap(&minimal_context(32), &signer.committee, 8.into());
+        let state = LightClientStore::from_bootstrap(&bootstrap);
+        let persist = PersistCommand {
+            bootstrap_info: Some(serde_json::to_string(&bootstrap).unwrap()),
+            state_info: Some(serde_json::to_string(&state).unwrap()),
+            genesis_info: Some(serde_json::to_string(&genesis()).unwrap()),
+            ..Default::default()
+        };
+
+        let info = persist_info(&opts(), Output::Persist(persist.clone())).unwrap();
+        assert_eq!(info.state.finalized_slot, 8);
+        assert_eq!(Some(info.state_info), persist.state_info);
+        assert_eq!(Some(info.bootstrap_info), persist.bootstrap_info);
+
+        // a persist without the bootstrap is not the output of an init
+        let persist = PersistCommand {
+            bootstrap_info: None,
+            ..persist
+        };
+        let e = persist_info(&opts(), Output::Persist(persist)).unwrap_err();
+        assert_eq!(e.code, COMMAND_FAILED);
+        assert_eq!(persist_info(&opts(), Output::Json(serde_json::Value::Null)).unwrap_err().code, COMMAND_FAILED);
+    }
+
+    #[test]
+    fn test_update_result() {
+        let vctx = minimal_context(32);
+        let signer = MockSyncCommittee::new(1);
+        let bootstrap = gen_bootstrap(&vctx, &signer.committee, 8.into());
+        let state = LightClientStore::from_bootstrap(&bootstrap);
+        context().store_light_client_state(&state).unwrap();
+
+        // no update applied: the stored state is returned without info to persist
+        let result = update_result(&opts(), Output::Update(UpdateOutput::default())).unwrap();
+        assert_eq!(result.state.finalized_slot, 8);
+        assert_eq!(result.state_info, None);
+        assert_eq!(result.updates_info, None);
+
+        let update = gen_update(&vctx, &signer, 16.into(), 12.into(), Some(&signer.committee));
+        let new_state = state.apply_light_client_update(&vctx, &update).unwrap().unwrap();
+        let persist = PersistCommand {
+            state_info: Some(serde_json::to_string(&new_state).unwrap()),
+            updates_info: Some(serde_json::to_string(&[&update]).unwrap()),
+            ..Default::default()
+        };
+        let output = Output::Update(UpdateOutput {
+            persist: Some(persist.clone()),
+            endpoints: Vec::new(),
+        });
+        let result = update_result(&opts(), output).unwrap();
+        assert_eq!(result.state.finalized_slot, 12);
+        assert_eq!(result.state_info, persist.state_info);
+        assert_eq!(result.updates_info, persist.updates_info);
+
+        let e = update_result(&opts(), Output::Persist(persist)).unwrap_err();
+        assert_eq!(e.code, COMMAND_FAILED);
+    }
+
+    #[test]
+    fn test_verified_account() {
+        let account: api::VerifiedAccount = verified_account().into();
+        assert_eq!(account.address, format!("0x{}", "11".repeat(20)));
+        assert!(account.exists);
+        assert_eq!(account.nonce, 1);
+        assert_eq!(account.balance, "0xff");
+        assert_eq!(account.storage_root, format!("0x{}", "22".repeat(32)));
+        assert_eq!(account.code_hash, format!("0x{}", "33".repeat(32)));
+        assert_eq!(account.block_number, 10);
+        assert_eq!(account.state_root, format!("0x{}", "44".repeat(32)));
+    }
+
+    #[test]
+    fn test_verified_storage() {
+        let verified = VerifiedStorage {
+            account: verified_account(),
+            storage: [(H256([1; 32]), H256([2; 32])), (H256([0; 32]), H256([0; 32]))].into(),
+        };
+        let storage: api::VerifiedStorage = verified.into();
+        assert_eq!(storage.account.nonce, 1);
+        // the slots are returned in order
+        assert_eq!(
+            storage
+                .storage
+                .iter()
+                .map(|slot| (slot.key.clone(), slot.value.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (format!("0x{}", "00".repeat(32)), format!("0x{}", "00".repeat(32))),
+                (format!("0x{}", "01".repeat(32)), format!("0x{}", "02".repeat(32))),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_output_envelope() {
+        // a route sends its output as the data of a single envelope
+        let output = Output::Update(UpdateOutput::default());
+        assert_eq!(
+            serde_json::to_string(&Response::data(output)).unwrap(),
+            r#"{"ok":true,"data":{"persist":null}}"#
+        );
+        let output = Output::Json(serde_json::json!({"committed": false}));
+        assert_eq!(
+            serde_json::to_string(&Response::data(output)).unwrap(),
+            r#"{"ok":true,"data":{"committed":false}}"#
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use super::errors::Error;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, str::FromStr};
+
+/// Role of a member of the access control list, a role also grants the roles below it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    /// reads the state and runs the queries, once any reader is listed
+    Reader,
+    /// persists and commits updates of the state
+    Updater,
+    /// reinitializes and unfreezes the client, manages the members below owner
+    Admin,
+    /// manages all the members, the first owner is the sender of the first bootstrap
+    Owner,
+}
+
+impl core::fmt::Display for Role {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Role::Reader => write!(f, "reader"),
+            Role::Updater => write!(f, "updater"),
+            Role::Admin => write!(f, "admin"),
+            Role::Owner => write!(f, "owner"),
+        }
+    }
+}
+
+impl FromStr for Role {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "reader" => Ok(Role::Reader),
+            "updater" => Ok(Role::Updater),
+            "admin" => Ok(Role::Admin),
+            "owner" => Ok(Role::Owner),
+            s => Err(Error::Other {
+                description: format!("unknown role: {}", s),
+            }),
+        }
+    }
+}
+
+/// Acl maps the Klave sender identities to their role, it is shared by the clients of all the networks
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Acl {
+    pub members: BTreeMap<String, Role>,
+}
+
+impl Acl {
+    pub fn new(owner: String) -> Self {
+        Self {
+            members: BTreeMap::from([(owner, Role::Owner)]),
+        }
+    }
+
+    pub fn role(&self, member: &str) -> Option<Role> {
+        self.members.get(member).copied()
+    }
+
+    /// reads are open to anyone until a reader is listed
+    pub fn restricts_reads(&self) -> bool {
+        self.members.values().any(|role| *role == Role::Reader)
+    }
+
+    pub fn authorize(&self, sender: &str, role: Role) -> Result<(), Error> {
+        if role == Role::Reader && !self.restricts_reads() {
+            return Ok(());
+        }
+        match self.role(sender) {
+            Some(granted) if granted >= role => Ok(()),
+            _ => Err(Error::Unauthorized(sender.to_string(), role)),
+        }
+    }
+
+    /// gives `role` to `member`, replacing the role it had
+    pub fn add_member(&mut self, sender: &str, member: String, role: Role) -> Result<(), Error> {
+        let current = self.role(&member);
+        self.authorize_change(sender, current.map_or(role, |current| current.max(role)))?;
+        if current == Some(Role::Owner) && role != Role::Owner {
+            self.ensure_other_owner(&member)?;
+        }
+        self.members.insert(member, role);
+        Ok(())
+    }
+
+    pub fn remove_member(&mut self, sender: &str, member: &str) -> Result<Role, Error> {
+        let Some(role) = self.role(member) else {
+            return Err(Error::Other {
+                description: format!("not a member: {}", member),
+            });
+        };
+        self.authorize_change(sender, role)?;
+        if role == Role::Owner {
+            self.ensure_other_owner(member)?;
+        }
+        self.members.remove(member);
+        Ok(role)
+    }
+
+    /// admins change the members below owner, only owners change the owners
+    fn authorize_change(&self, sender: &str, role: Role) -> Result<(), Error> {
+        match role {
+            Role::Owner => self.authorize(sender, Role::Owner),
+            _ => self.authorize(sender, Role::Admin),
+        }
+    }
+
+    fn ensure_other_owner(&self, owner: &str) -> Result<(), Error> {
+        if self.members.iter().any(|(member, role)| *role == Role::Owner && member != owner) {
+            Ok(())
+        } else {
+            Err(Error::Other {
+                description: "the last owner cannot be removed".into(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+pub use super::testing::sender;
+
+/// identity of the sender of the request, as given by Klave
+#[cfg(not(test))]
+pub fn sender() -> Result<String, Error> {
+    klave::context::get("sender").map_err(|e| Error::Other {
+        description: format!("sender not found: {}", e),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acl() {
+        let mut acl = Acl::new("owner".into());
+        assert!(acl.authorize("anyone", Role::Reader).is_ok());
+        assert!(acl.authorize("anyone", Role::Updater).is_err());
+
+        acl.add_member("owner", "admin".into(), Role::Admin).unwrap();
+        acl.add_member("admin", "relayer".into(), Role::Updater).unwrap();
+        assert!(acl.authorize("relayer", Role::Updater).is_ok());
+        assert!(acl.authorize("relayer", Role::Admin).is_err());
+        assert!(acl.authorize("owner", Role::Updater).is_ok());
+
+        // admins cannot touch the owners
+        assert!(acl.add_member("admin", "admin".into(), Role::Owner).is_err());
+        assert!(acl.remove_member("admin", "owner").is_err());
+        assert!(acl.add_member("relayer", "other".into(), Role::Updater).is_err());
+
+        acl.add_member("admin", "reader".into(), Role::Reader).unwrap();
+        assert!(acl.authorize("anyone", Role::Reader).is_err());
+        assert!(acl.authorize("relayer", Role::Reader).is_ok());
+
+        assert!(acl.remove_member("owner", "owner").is_err());
+        assert_eq!(acl.remove_member("admin", "relayer").unwrap(), Role::Updater);
+        assert!(acl.authorize("relayer", Role::Updater).is_err());
+    }
+}
@@ -1,10 +1,25 @@
 use super::errors::Error;
 use crate::consensus::src::{
+    beacon::ForkDigest,
     config::{self, Config},
+    sync_protocol::SyncCommitteePeriod,
     types::H256,
 };
-use crate::light_client_verifier::src::updates::deneb::LightClientBootstrapInfo;
-use crate::lodestar_rpc::src::client::RPCClient;
+use crate::light_client_verifier::src::updates::{
+    capella, deneb, electra,
+    versioned::{
+        ConsensusUpdateInfo, LightClientBootstrapInfo, LightClientOptimisticUpdate,
+        OptimisticUpdateInfo,
+    },
+};
+use crate::lodestar_rpc::src::{
+    client::{EndpointHealth, RPCClient},
+    types::{
+        LightClientBootstrapResponse, LightClientFinalityUpdateResponse,
+        LightClientOptimisticUpdateResponse, LightClientUpdateResponse,
+    },
+};
+use log::*;
 use std::str::FromStr;
 
 type Result<T> = core::result::Result<T, Error>;
@@ -14,10 +29,50 @@ pub struct Chain {
 }
 
 impl Chain {
-    pub fn new(endpoint: impl Into<String>) -> Self {
-        Self {
-            rpc_client: RPCClient::new(endpoint),
-        }
+    pub fn new(endpoint: impl Into<String>) -> Result<Self> {
+        Ok(Self {
+            rpc_client: RPCClient::new(endpoint)?,
+        })
+    }
+
+    /// the chains the updates are cross-checked against. An invalid endpoint is skipped, as the
+    /// cross-check is done against the others
+    pub fn secondary_chains(endpoints: &[String], fork_digests: &[(ForkDigest, String)]) -> Vec<Self> {
+        endpoints
+            .iter()
+            .filter_map(|endpoint| match Self::new(endpoint) {
+                Ok(chain) => Some(chain.with_fork_digests(fork_digests.to_vec())),
+                Err(e) => {
+                    warn!("skipping secondary beacon endpoint {}: {}", endpoint, e);
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// the fork digests tag the SSZ encoded updates of a range with their fork
+    pub fn with_fork_digests(mut self, fork_digests: Vec<(ForkDigest, String)>) -> Self {
+        self.rpc_client = self.rpc_client.with_fork_digests(fork_digests);
+        self
+    }
+
+    pub fn endpoint(&self) -> &str {
+        self.rpc_client.endpoint()
+    }
+
+    /// the endpoint that served the last successful request
+    pub fn served_by(&self) -> String {
+        self.rpc_client
+            .served_by()
+            .unwrap_or_else(|| self.endpoint().to_string())
+    }
+
+    pub fn endpoint_health(&self) -> Vec<EndpointHealth> {
+        self.rpc_client.endpoint_health()
+    }
+
+    pub fn check_health(&self) {
+        self.rpc_client.check_health()
     }
 
     pub fn get_bootstrap<
@@ -40,15 +95,125 @@ impl Chain {
                 .finalized
                 .root
         };
-        Ok(LightClientBootstrapInfo(
-            self.rpc_client
-                .get_bootstrap::<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>(
-                    finalized_root,
-                )
-                ?
-                .data
-                .into(),
-        ))
+        let res = self
+            .rpc_client
+            .get_bootstrap::<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>(
+                finalized_root,
+            )?;
+        Ok(match res {
+            LightClientBootstrapResponse::Capella(data) => {
+                LightClientBootstrapInfo::Capella(capella::LightClientBootstrapInfo(data.into()))
+            }
+            LightClientBootstrapResponse::Deneb(data) => {
+                LightClientBootstrapInfo::Deneb(deneb::LightClientBootstrapInfo(data.into()))
+            }
+            LightClientBootstrapResponse::Electra(data) => {
+                LightClientBootstrapInfo::Electra(electra::LightClientBootstrapInfo(data.into()))
+            }
+        })
+    }
+
+    pub fn get_finality_update<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    >(
+        &self,
+    ) -> Result<ConsensusUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>
+    {
+        let res = self
+            .rpc_client
+            .get_finality_update::<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>()?;
+        Ok(match res {
+            LightClientFinalityUpdateResponse::Capella(data) => {
+                ConsensusUpdateInfo::Capella(capella::ConsensusUpdateInfo(data.into()))
+            }
+            LightClientFinalityUpdateResponse::Deneb(data) => {
+                ConsensusUpdateInfo::Deneb(deneb::ConsensusUpdateInfo(data.into()))
+            }
+            LightClientFinalityUpdateResponse::Electra(data) => {
+                ConsensusUpdateInfo::Electra(electra::ConsensusUpdateInfo(data.into()))
+            }
+        })
+    }
+
+    pub fn get_optimistic_update<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    >(
+        &self,
+    ) -> Result<OptimisticUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>
+    {
+        let res = self
+            .rpc_client
+            .get_optimistic_update::<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>()?;
+        Ok(match res {
+            LightClientOptimisticUpdateResponse::Capella(data) => {
+                OptimisticUpdateInfo::Capella(LightClientOptimisticUpdate {
+                    attested_header: data.attested_header,
+                    sync_aggregate: data.sync_aggregate,
+                    signature_slot: data.signature_slot,
+                })
+            }
+            LightClientOptimisticUpdateResponse::Deneb(data) => {
+                OptimisticUpdateInfo::Deneb(LightClientOptimisticUpdate {
+                    attested_header: data.attested_header,
+                    sync_aggregate: data.sync_aggregate,
+                    signature_slot: data.signature_slot,
+                })
+            }
+            LightClientOptimisticUpdateResponse::Electra(data) => {
+                OptimisticUpdateInfo::Electra(LightClientOptimisticUpdate {
+                    attested_header: data.attested_header,
+                    sync_aggregate: data.sync_aggregate,
+                    signature_slot: data.signature_slot,
+                })
+            }
+        })
+    }
+
+    pub fn get_light_client_updates<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    >(
+        &self,
+        start_period: SyncCommitteePeriod,
+        count: u64,
+    ) -> Result<
+        Vec<ConsensusUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>,
+    > {
+        Ok(self
+            .rpc_client
+            .get_light_client_updates::<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>(
+                start_period,
+                count,
+            )?
+            .0
+            .into_iter()
+            .map(consensus_update_info)
+            .collect())
+    }
+}
+
+pub(crate) fn consensus_update_info<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    res: LightClientUpdateResponse<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+) -> ConsensusUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES> {
+    match res {
+        LightClientUpdateResponse::Capella(data) => {
+            ConsensusUpdateInfo::Capella(capella::ConsensusUpdateInfo(data.into()))
+        }
+        LightClientUpdateResponse::Deneb(data) => {
+            ConsensusUpdateInfo::Deneb(deneb::ConsensusUpdateInfo(data.into()))
+        }
+        LightClientUpdateResponse::Electra(data) => {
+            ConsensusUpdateInfo::Electra(electra::ConsensusUpdateInfo(data.into()))
+        }
     }
 }
 
@@ -86,3 +251,21 @@ impl FromStr for Network {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secondary_chains() {
+        let endpoints = vec![
+            "http://a".to_string(),
+            "not a url".to_string(),
+            "http://b/eth".to_string(),
+            "http://c".to_string(),
+        ];
+        let chains = Chain::secondary_chains(&endpoints, &[]);
+        let endpoints: Vec<_> = chains.iter().map(Chain::endpoint).collect();
+        assert_eq!(endpoints, ["http://a", "http://c"]);
+    }
+}
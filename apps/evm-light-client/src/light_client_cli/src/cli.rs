@@ -1,12 +1,15 @@
 use anyhow::Result;
 use clap::Parser;
+use serde::Serialize;
+use serde_json::Value;
 use std::str::FromStr;
 
 use crate::light_client_cli::src::{
     chain::Network,
-    commands::Command,
+    commands::{Command, PersistCommand, UpdateOutput, VerifiedAccount, VerifiedStorage},
     context::Context,
     preset::{MainnetContext, MinimalContext},
+    state::{LightClientStore, StateSummary},
 };
     
 #[derive(Debug, Parser)]
@@ -29,7 +32,7 @@ pub struct Opts {
     #[arg(long = "home", help = "Path to home directory")]
     pub ledger_table: String,
 
-    #[arg(long = "beacon_endpoint")]
+    #[arg(long = "beacon_endpoint", help = "Comma separated beacon endpoints, tried in order")]
     pub beacon_endpoint: String,
 
     #[arg(long = "network")]
@@ -44,17 +47,59 @@ impl Opts {
     pub fn get_network(&self) -> Result<Network> {
         Network::from_str(&self.network).map_err(Into::into)
     }
+
+    /// summarizes the state given as `state_info` of the persist command, or the stored state
+    pub fn state_summary(&self, state_info: Option<&str>) -> Result<StateSummary> {
+        let network = self.get_network()?;
+        match network {
+            Network::Mainnet | Network::Holesky | Network::Sepolia => {
+                summarize(MainnetContext::build(network, self.clone())?, state_info)
+            }
+            Network::Minimal => summarize(MinimalContext::build(network, self.clone())?, state_info),
+        }
+    }
+}
+
+fn summarize<
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+>(
+    ctx: Context<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES, SYNC_COMMITTEE_SIZE>,
+    state_info: Option<&str>,
+) -> Result<StateSummary> {
+    let state = match state_info {
+        Some(state_info) => serde_json::from_str::<
+            LightClientStore<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        >(state_info)?,
+        None => ctx.get_light_client_state()?,
+    };
+    Ok(state.summary())
+}
+
+/// Output is the result of a command. The routes send it as the data of their response,
+/// and the typed functions of the `light-client` interface map it to the types of the interface
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Output {
+    /// the state to persist, with the bootstrap and the genesis it is initialized from
+    Persist(PersistCommand),
+    Update(UpdateOutput),
+    Account(VerifiedAccount),
+    Storage(VerifiedStorage),
+    /// the results that only the routes return
+    Json(Value),
+}
+
+impl Output {
+    fn json<T: Serialize>(value: T) -> Result<Self> {
+        Ok(Self::Json(serde_json::to_value(value)?))
+    }
 }
 
 impl Cli {
-    pub fn run(self) -> Result<()> {
-        let local_network = match self.opts.get_network() {
-            Ok(network) => network,
-            Err(e) => {
-                eprintln!("Invalid network: {}", e);
-                return Ok(());
-            }
-        };
+    pub fn run(self) -> Result<Output> {
+        let local_network = self.opts.get_network()?;
 
         let opts = self.opts.clone();
         match local_network {
@@ -75,13 +120,32 @@ impl Cli {
     >(
         self,
         ctx: Context<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES, SYNC_COMMITTEE_SIZE>,
-    ) -> Result<()> {
+    ) -> Result<Output> {
+        if let Some(role) = self.cmd.required_role() {
+            ctx.authorize(role)?;
+        }
         match self.cmd {
-            Command::Init(cmd) => cmd.run(ctx),
-            Command::Persist(cmd) => cmd.run(ctx),
-            Command::Update(cmd) => cmd.run(ctx),
-            Command::Header(cmd) => cmd.run(ctx),
-            Command::Block(cmd) => cmd.run(ctx),
+            Command::Init(cmd) => cmd.run(ctx).map(Output::Persist),
+            Command::Persist(cmd) => Output::json(cmd.run(ctx)?),
+            Command::Update(cmd) => cmd.run(ctx).map(Output::Update),
+            Command::Commit(cmd) => cmd.run(ctx).map(Output::Json),
+            Command::OptimisticUpdate(cmd) => cmd.run(ctx).map(Output::Update),
+            Command::Header(cmd) => Output::json(cmd.run(ctx)?),
+            Command::Block(cmd) => Output::json(cmd.run(ctx)?),
+            Command::Account(cmd) => cmd.run(ctx).map(Output::Account),
+            Command::Storage(cmd) => cmd.run(ctx).map(Output::Storage),
+            Command::Receipt(cmd) => Output::json(cmd.run(ctx)?),
+            Command::ExecutionHeader(cmd) => Output::json(cmd.run(ctx)?),
+            Command::Transaction(cmd) => cmd.run(ctx).map(Output::Json),
+            Command::FinalizedHeader(cmd) => cmd.run(ctx).map(Output::Json),
+            Command::Audit(cmd) => cmd.run(ctx).map(Output::Json),
+            Command::State(cmd) => cmd.run(ctx).map(Output::Json),
+            Command::Misbehaviour(cmd) => cmd.run(ctx).map(Output::Json),
+            Command::Unfreeze(cmd) => Output::json(cmd.run(ctx)?),
+            Command::AddMember(cmd) => cmd.run(ctx).map(Output::Json),
+            Command::RemoveMember(cmd) => cmd.run(ctx).map(Output::Json),
+            Command::Acl(cmd) => cmd.run(ctx).map(Output::Json),
+            Command::ClaimOwner(cmd) => cmd.run(ctx).map(Output::Json),
         }
     }
 }
@@ -2,24 +2,31 @@ use crate::light_client_cli::src::{
     chain::Chain,
     context::Context,
     errors::Error,
-    state::{ExecutionUpdateInfo, LightClientStore},
+    state::{Anchor, ExecutionUpdateInfo, FrozenState, LightClientStore},
+    ticket::{state_hash, EnclaveKey, PersistTicket},
 };
 use crate::consensus::src::{
-    beacon::{BlockNumber, Root, Slot},
+    beacon::{BeaconBlockHeader, BlockNumber, Root, Slot},
     compute::compute_sync_committee_period_at_slot,
     context::ChainContext,
-    fork::deneb::{self, LightClientUpdate},
     sync_protocol::SyncCommitteePeriod,
     types::{H256, U64}
 };
 use crate::light_client_verifier::src::{
     consensus::SyncProtocolVerifier,
     context::{ChainConsensusVerificationContext, Fraction, LightClientContext},
-    updates::deneb::{ConsensusUpdateInfo, LightClientBootstrapInfo},
+    misbehaviour::{FinalizedHeaderMisbehaviour, Misbehaviour, NextSyncCommitteeMisbehaviour},
+    updates::{
+        versioned::{ConsensusUpdateInfo, LightClientBootstrapInfo, OptimisticUpdateInfo},
+        ConsensusUpdate,
+    },
 };
-use crate::lodestar_rpc::src::types::GenesisData;
+use crate::lodestar_rpc::src::{client::EndpointHealth, types::GenesisData};
 use super::commands::PersistCommand;
+#[cfg(test)]
+use super::testing::trusted_time_secs;
 use log::*;
+use std::cell::RefCell;
 
 const EXECUTION_PAYLOAD_STATE_ROOT_SUBTREE_INDEX: usize = 2;
 const EXECUTION_PAYLOAD_BLOCK_NUMBER_SUBTREE_INDEX: usize = 6;
@@ -35,6 +42,12 @@ type Updates<
     ExecutionUpdateInfo,
 );
 
+/// the share of the sync committee that must sign an update
+pub fn trust_level() -> Fraction {
+    // safe to unwrap: `2/3` is valid fraction
+    Fraction::new(2, 3).unwrap()
+}
+
 pub struct LightClient<
     const BYTES_PER_LOGS_BLOOM: usize,
     const MAX_EXTRA_DATA_BYTES: usize,
@@ -48,9 +61,26 @@ pub struct LightClient<
     >,
     genesis_time: U64,
     genesis_validators_root: Root,
-    trust_level: Fraction,
+    secondary_chains: Vec<Chain>,
+    /// the consensus updates applied by this run
+    applied_updates: RefCell<
+        Vec<ConsensusUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>,
+    >,
+    /// the store after the last applied update
+    applied_state: RefCell<
+        Option<LightClientStore<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>,
+    >,
 }
 
+type SecondaryUpdates<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+> = Vec<(
+    String,
+    Vec<ConsensusUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>,
+)>;
+
 impl<
         const BYTES_PER_LOGS_BLOOM: usize,
         const MAX_EXTRA_DATA_BYTES: usize,
@@ -62,7 +92,6 @@ impl<
         chain: Chain,
         genesis_time: U64,
         genesis_validators_root: Root,
-        trust_level: Option<Fraction>,
     ) -> Self {
         Self {
             ctx,
@@ -70,102 +99,223 @@ impl<
             verifier: Default::default(),
             genesis_time,
             genesis_validators_root,
-            // safe to unwrap: `2/3` is valid fraction
-            trust_level: trust_level.unwrap_or(Fraction::new(2, 3).unwrap()),
+            secondary_chains: Vec::new(),
+            applied_updates: RefCell::new(Vec::new()),
+            applied_state: RefCell::new(None),
         }
     }
 
-    pub fn init_with_bootstrap(&self, trusted_block_root: Option<H256>, genesis_data: &GenesisData) -> Result<()> {
-        let bootstrap: LightClientBootstrapInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES> = self.chain.get_bootstrap(trusted_block_root).unwrap();
+    /// updates are cross-checked against the same updates served by the secondary chains before being applied
+    pub fn with_secondary_chains(mut self, secondary_chains: Vec<Chain>) -> Self {
+        self.secondary_chains = secondary_chains;
+        self
+    }
 
-        let vctx = self.build_verification_context();
-        match self.verifier
-            .validate_boostrap(&vctx, &bootstrap, trusted_block_root) {
-            Ok(_) => (),
-            Err(e) => {
-                klave::notifier::send_string(&format!("failed to validate bootstrap: {:?}, {:?}, {:?}", bootstrap, SYNC_COMMITTEE_SIZE, e));
-                return Err(Error::Other {
-                    description: "failed to validate bootstrap".into(),
-                });
+    pub fn init_with_bootstrap(&self, trusted_block_root: Option<H256>, genesis_data: &GenesisData) -> Result<PersistCommand> {
+        let bootstrap: LightClientBootstrapInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES> = self.chain.get_bootstrap(trusted_block_root)?;
+
+        let state = self.verify_bootstrap(&bootstrap, trusted_block_root)?;
+
+        self.persist_command(
+            None,
+            &state,
+            PersistCommand {
+                bootstrap_info: Some(serde_json::to_string(&bootstrap)?),
+                genesis_info: Some(serde_json::to_string(&genesis_data)?),
+                ..Default::default()
+            },
+        )
+    }
+
+    /// the state to persist with a ticket binding it to the prior state, which is the stored state.
+    /// The persist verifies a bootstrap again, which needs no signature check, so no ticket is issued for it.
+    /// Without the ticket key, which the first persist creates, the persist verifies the updates again instead
+    fn persist_command(
+        &self,
+        prior_state: Option<&LightClientStore<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>,
+        state: &LightClientStore<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        mut persist: PersistCommand,
+    ) -> Result<PersistCommand> {
+        persist.state_info = Some(serde_json::to_string(state)?);
+        if persist.bootstrap_info.is_none() {
+            match EnclaveKey::load() {
+                Ok(key) => {
+                    let ticket = PersistTicket::issue(
+                        &key,
+                        prior_state.map(state_hash).transpose()?,
+                        state_hash(state)?,
+                        persist.info_hash()?,
+                    )?;
+                    persist.ticket = Some(serde_json::to_string(&ticket)?);
+                }
+                Err(e) => warn!("persist without a ticket: {:?}", e),
             }
+        }
+        Ok(persist)
+    }
+
+    /// the store after the updates applied by this run to persist with the updates, none if no update was applied
+    pub fn applied_persist_command(&self) -> Result<Option<PersistCommand>> {
+        let Some((state, updates)) = self.applied() else {
+            return Ok(None);
         };
-        let state = LightClientStore::from_bootstrap(
-            bootstrap.clone().0,
-            bootstrap.header.execution.clone(),
-        );        
-
-        let bootstrap_value = serde_json::to_string(&bootstrap)?;
-        let state_value = serde_json::to_string(&state)?;
-        let genesis_value = serde_json::to_string(&genesis_data)?;
-
-        let to_persist = serde_json::to_string(&PersistCommand{
-            bootstrap_info: Some(bootstrap_value),
-            state_info: Some(state_value),
-            genesis_info: Some(genesis_value),
-        })?;
-        klave::notifier::send_string(&format!("{}", to_persist));
-        Ok(())
+        let prior_state = self.ctx.get_light_client_state()?;
+        self.persist_command(
+            Some(&prior_state),
+            &state,
+            PersistCommand {
+                updates_info: Some(serde_json::to_string(&updates)?),
+                ..Default::default()
+            },
+        )
+        .map(Some)
+    }
+
+    /// the store after the updates applied by this run and the updates, none if no update was applied
+    pub fn applied(
+        &self,
+    ) -> Option<(
+        LightClientStore<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        Vec<ConsensusUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>,
+    )> {
+        let state = self.applied_state.borrow().clone()?;
+        Some((state, self.applied_updates.borrow().clone()))
+    }
+
+    /// validates the bootstrap and returns the store it initializes
+    pub fn verify_bootstrap(
+        &self,
+        bootstrap: &LightClientBootstrapInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        trusted_block_root: Option<H256>,
+    ) -> Result<LightClientStore<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>> {
+        let vctx = self.build_verification_context();
+        if let Err(e) = self.verifier.validate_boostrap(&vctx, bootstrap, trusted_block_root) {
+            return Err(Error::Other {
+                description: format!("failed to validate bootstrap: {:?}", e),
+            });
+        }
+        Ok(LightClientStore::from_bootstrap(bootstrap))
+    }
+
+    /// validates and applies the updates in order, each of them must change the store
+    pub fn verify_updates(
+        &self,
+        mut state: LightClientStore<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        updates: Vec<ConsensusUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>,
+    ) -> Result<LightClientStore<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>> {
+        let vctx = self.build_verification_context();
+        for (index, update) in (0..).zip(updates) {
+            state = self
+                .replay_update(&vctx, update, &state)
+                .map_err(|e| Error::UpdateRejected(index, Box::new(e)))?;
+        }
+        Ok(state)
+    }
+
+    /// validates the optimistic update and returns the store with its header, unchanged if the header is not newer
+    pub fn verify_optimistic_update(
+        &self,
+        state: LightClientStore<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        update: &OptimisticUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    ) -> Result<LightClientStore<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>> {
+        let vctx = self.build_verification_context();
+        self.verifier
+            .validate_optimistic_update(&vctx, &state, update)?;
+        Ok(state.apply_optimistic_update(update).unwrap_or(state))
     }
 
     pub fn store_boostrap(&self, bootstrap: LightClientBootstrapInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>) -> Result<()> {
-        match self.ctx.store_boostrap(&bootstrap) {
-            Ok(_) => (),
-            Err(e) => {
-                klave::notifier::send_string(&format!("failed to store bootstrap: {:?}", e));
-                return Err(Error::Other {
-                    description: "failed to store bootstrap".into(),
-                });
-            }
-        };
-        Ok(())
+        self.ctx.store_boostrap(&bootstrap).map_err(|e| Error::Other {
+            description: format!("failed to store bootstrap: {:?}", e),
+        })
+    }
+
+    /// the sender of the first bootstrap becomes the owner of the light client
+    pub fn ensure_owner(&self) -> Result<()> {
+        self.ctx.ensure_owner()
     }
 
     pub fn store_light_client_state(&self, state: LightClientStore<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>) -> Result<()> {
-        match self.ctx.store_light_client_state(&state) {
-            Ok(_) => (),
-            Err(e) => {
-                klave::notifier::send_string(&format!("failed to store light client state: {:?}", e));
-                return Err(Error::Other {
-                    description: "failed to store light client state".into(),
-                });
-            }
-        };
-        Ok(())
+        self.ctx.store_light_client_state(&state).map_err(|e| Error::Other {
+            description: format!("failed to store light client state: {:?}", e),
+        })
+    }
+
+    pub fn archive_updates(
+        &self,
+        updates: Vec<ConsensusUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>,
+    ) -> Result<()> {
+        self.ctx.archive_updates(&updates).map_err(|e| Error::Other {
+            description: format!("failed to archive updates: {:?}", e),
+        })
+    }
+
+    /// replays the archived updates on the stored bootstrap, returning the resulting store and the number of updates
+    pub fn replay_archived_updates(
+        &self,
+    ) -> Result<(
+        LightClientStore<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        u64,
+    )> {
+        let state = self.verify_bootstrap(&self.ctx.get_bootstrap()?, None)?;
+        let count = self.ctx.get_archived_update_count()?;
+        let updates = (0..count)
+            .map(|index| self.ctx.get_archived_update(index))
+            .collect::<Result<Vec<_>>>()?;
+        Ok((self.verify_updates(state, updates)?, count))
     }
 
     pub fn store_genesis(&self, genesis: &GenesisData) -> Result<()> {
-        match self.ctx.store_genesis(&genesis) {
-            Ok(_) => (),
-            Err(e) => {
-                klave::notifier::send_string(&format!("failed to store genesis: {:?}", e));
-                return Err(Error::Other {
-                    description: "failed to store genesis".into(),
-                });
-            }
+        self.ctx.store_genesis(genesis).map_err(|e| Error::Other {
+            description: format!("failed to store genesis: {:?}", e),
+        })
+    }
+
+    /// validates both updates of the misbehaviour against the stored sync committees and freezes the client
+    pub fn submit_misbehaviour(
+        &self,
+        misbehaviour: Misbehaviour<
+            SYNC_COMMITTEE_SIZE,
+            ConsensusUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        >,
+    ) -> Result<FrozenState> {
+        if let Some(frozen) = self.ctx.get_frozen_state()? {
+            return Err(Error::Frozen(frozen.misbehaviour_slot, frozen.kind));
+        }
+        let state = self.ctx.get_light_client_state()?;
+        let evidence = serde_json::to_value(&misbehaviour)?;
+        let (kind, misbehaviour_slot) = misbehaviour_kind(&misbehaviour);
+
+        let vctx = self.build_verification_context();
+        self.verifier
+            .validate_misbehaviour(&vctx, &state, misbehaviour)?;
+
+        let frozen = FrozenState {
+            store_slot: state.latest_finalized_header.slot,
+            misbehaviour_slot,
+            kind: kind.into(),
+            frozen_at: U64::from(trusted_time_secs()),
+            evidence,
         };
-        Ok(())
+        self.ctx.store_frozen_state(Some(&frozen))?;
+        Ok(frozen)
+    }
+
+    /// marks the endpoints that are syncing or behind as unhealthy, so that they are tried last
+    pub fn check_endpoints(&self) {
+        self.chain.check_health()
+    }
+
+    pub fn endpoint_health(&self) -> Vec<EndpointHealth> {
+        self.chain.endpoint_health()
     }
 
     pub fn update_until_target(&self, target: Target) -> Result<bool> {
-        if let Some((slot, bn)) = match self.update_sync_committee() {
-            Ok(Some(v)) => Some(v),
-            Ok(None) => None,
-            Err(e) => {
-                klave::notifier::send_string(&format!("failed to update sync committee: {:?}", e));
-                return Err(e);
-            }
-        } {
+        if let Some((slot, bn)) = self.update_sync_committee()? {
             if target <= Updated(slot, bn) {
                 return Ok(false);
             }
-        } else if let Some((slot, bn)) = match self.update_slot_on_current_period() {
-            Ok(Some(v)) => Some(v),
-            Ok(None) => None,
-            Err(e) => {
-                klave::notifier::send_string(&format!("failed to update slot on current period: {:?}", e));
-                return Err(e);
-            }
-        } {
+        } else if let Some((slot, bn)) = self.update_slot_on_current_period()? {
             if target <= Updated(slot, bn) {
                 return Ok(false);
             }
@@ -175,11 +325,45 @@ impl<
         Ok(true)
     }
 
+    /// validates the latest optimistic update and returns the store with the new optimistic header to persist
+    pub fn update_optimistic_header(&self) -> Result<Option<PersistCommand>> {
+        let state = self.ctx.get_light_client_state()?;
+        let update = self
+            .chain
+            .get_optimistic_update::<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>()?;
+        let served_by = self.chain.served_by();
+
+        let vctx = self.build_verification_context();
+        self.verifier
+            .validate_optimistic_update(&vctx, &state, &update)?;
+        let Some(new_state) = state.apply_optimistic_update(&update) else {
+            info!("no new optimistic header");
+            return Ok(None);
+        };
+
+        let persist = self.persist_command(
+            Some(&state),
+            &new_state,
+            PersistCommand {
+                optimistic_update_info: Some(serde_json::to_string(&update)?),
+                ..Default::default()
+            },
+        )?;
+        let (beacon, execution) = new_state.anchor_headers(Anchor::Optimistic);
+        info!(
+            "post optimistic header: slot={} block_number={} served_by={}",
+            beacon.slot,
+            execution.block_number(),
+            served_by
+        );
+        Ok(Some(persist))
+    }
+
     fn update_sync_committee(&self) -> Result<Option<(Slot, U64)>> {
         let state = match self.ctx.get_light_client_state() {
             Ok(state) => state,
             Err(e) => {
-                klave::notifier::send_string(&format!("light client state not found: {}", e));
+                info!("light client state not found: {}", e);
                 return Ok(None);
             }
         };
@@ -189,12 +373,17 @@ impl<
 
         let mut updates = self
             .chain
-            .rpc_client
             .get_light_client_updates(period, 2)
             ?
-            .0
-            .into_iter()
-            .map(|u| u.data.into());
+            .into_iter();
+        info!(
+            "updates: period={} served_by={}",
+            period,
+            self.chain.served_by()
+        );
+        let secondary_updates = self.fetch_secondary_updates(|chain| {
+            chain.get_light_client_updates::<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>(period, 2)
+        });
 
         // if next_sync_committee is known, first update is skipped
         if state.next_sync_committee.is_some() {
@@ -205,10 +394,12 @@ impl<
         let new_state = match [updates.next(), updates.next()] {
             [None, None] => return Ok(None), // do nothing here
             [Some(update), None] => {
+                self.cross_check_update(&vctx, &state, &update, &secondary_updates)?;
                 self.process_light_client_update(&vctx, update, &state)
                     ?
             }
             [Some(update_first), Some(update_second)] => {
+                self.cross_check_update(&vctx, &state, &update_first, &secondary_updates)?;
                 let state = if let Some(new_state) = self
                     .process_light_client_update(&vctx, update_first, &state)
                     ?
@@ -217,6 +408,7 @@ impl<
                 } else {
                     state
                 };
+                self.cross_check_update(&vctx, &state, &update_second, &secondary_updates)?;
                 self.process_light_client_update(&vctx, update_second, &state)
                     ?
             }
@@ -225,7 +417,7 @@ impl<
         if let Some(new_state) = new_state {
             Ok(Some((
                 new_state.latest_finalized_header.slot,
-                new_state.latest_execution_payload_header.block_number,
+                new_state.latest_execution_payload_header.block_number(),
             )))
         } else {
             Ok(None)
@@ -239,75 +431,167 @@ impl<
 
         let update = self
             .chain
-            .rpc_client
             .get_finality_update::<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>(
             )
-            ?
-            .data;
+            ?;
+        let served_by = self.chain.served_by();
         let finality_update_period =
-            compute_sync_committee_period_at_slot(&self.ctx, update.finalized_header.beacon.slot);
+            compute_sync_committee_period_at_slot(&self.ctx, update.finalized_beacon_header().slot);
 
         if store_period != finality_update_period
-            || state.latest_finalized_header.slot >= update.finalized_header.beacon.slot
+            || state.latest_finalized_header.slot >= update.finalized_beacon_header().slot
         {
-            debug!("this finality update cannot apply to the store: store_period={} store_slot={} update_slot={}", store_period, state.latest_finalized_header.slot, update.finalized_header.beacon.slot);
+            debug!("this finality update cannot apply to the store: store_period={} store_slot={} update_slot={}", store_period, state.latest_finalized_header.slot, update.finalized_beacon_header().slot);
             return Ok(None);
         }
 
         let vctx = self.build_verification_context();
+        let secondary_updates = self.fetch_secondary_updates(|chain| {
+            Ok(vec![chain.get_finality_update::<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>()?])
+        });
+        self.cross_check_update(&vctx, &state, &update, &secondary_updates)?;
         if let Some(new_state) = self
-            .process_light_client_update(&vctx, update.into(), &state)
+            .process_light_client_update(&vctx, update, &state)
             ?
         {
-            klave::notifier::send_string(&format!(
-                "post finalized header: period={} slot={}",
+            info!(
+                "post finalized header: period={} slot={} served_by={}",
                 compute_sync_committee_period_at_slot(
                     &self.ctx,
                     new_state.latest_finalized_header.slot
                 ),
-                new_state.latest_finalized_header.slot
-            ));
+                new_state.latest_finalized_header.slot,
+                served_by
+            );
             Ok(Some((
                 new_state.latest_finalized_header.slot,
-                new_state.latest_execution_payload_header.block_number,
+                new_state.latest_execution_payload_header.block_number(),
             )))
         } else {
             Ok(None)
         }
     }
 
+    /// a secondary chain that cannot serve the updates is skipped
+    fn fetch_secondary_updates<F>(
+        &self,
+        fetch: F,
+    ) -> SecondaryUpdates<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
+    where
+        F: Fn(
+            &Chain,
+        ) -> Result<
+            Vec<ConsensusUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>,
+        >,
+    {
+        self.secondary_chains
+            .iter()
+            .filter_map(|chain| match fetch(chain) {
+                Ok(updates) => Some((chain.endpoint().to_string(), updates)),
+                Err(e) => {
+                    warn!(
+                        "failed to fetch updates from secondary beacon endpoint {}: {:?}",
+                        chain.endpoint(),
+                        e
+                    );
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// fails with the misbehaviour if a secondary update conflicts with `update` while both verify against `state`
+    fn cross_check_update(
+        &self,
+        vctx: &impl ChainConsensusVerificationContext,
+        state: &LightClientStore<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        update: &ConsensusUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        secondary_updates: &SecondaryUpdates<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    ) -> Result<()> {
+        for (endpoint, updates) in secondary_updates.iter() {
+            for secondary_update in updates.iter() {
+                let Some(misbehaviour) = self.find_misbehaviour(vctx, update, secondary_update) else {
+                    continue;
+                };
+                if let Err(e) = self
+                    .verifier
+                    .validate_misbehaviour(vctx, state, misbehaviour.clone())
+                {
+                    debug!("conflicting update from {} does not verify: {:?}", endpoint, e);
+                    continue;
+                }
+                let (kind, slot) = misbehaviour_kind(&misbehaviour);
+                return Err(Error::MisbehaviourDetected(
+                    endpoint.clone(),
+                    kind.into(),
+                    slot,
+                    Box::new(serde_json::to_value(&misbehaviour)?),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn find_misbehaviour(
+        &self,
+        vctx: &impl ChainConsensusVerificationContext,
+        update_1: &ConsensusUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        update_2: &ConsensusUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    ) -> Option<
+        Misbehaviour<
+            SYNC_COMMITTEE_SIZE,
+            ConsensusUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        >,
+    > {
+        let (header_1, header_2) = (update_1.finalized_beacon_header(), update_2.finalized_beacon_header());
+        if header_1.slot == header_2.slot && header_1 != header_2 {
+            return Some(Misbehaviour::FinalizedHeader(FinalizedHeaderMisbehaviour {
+                consensus_update_1: update_1.clone(),
+                consensus_update_2: update_2.clone(),
+            }));
+        }
+        if update_1.has_finalized_next_sync_committee(vctx)
+            && update_2.has_finalized_next_sync_committee(vctx)
+            && compute_sync_committee_period_at_slot(vctx, update_1.attested_beacon_header().slot)
+                == compute_sync_committee_period_at_slot(vctx, update_2.attested_beacon_header().slot)
+            && update_1.next_sync_committee() != update_2.next_sync_committee()
+        {
+            return Some(Misbehaviour::NextSyncCommittee(NextSyncCommitteeMisbehaviour {
+                consensus_update_1: update_1.clone(),
+                consensus_update_2: update_2.clone(),
+            }));
+        }
+        None
+    }
+
     fn build_updates(
         &self,
-        update: LightClientUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        update: ConsensusUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
     ) -> Result<Updates<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>> {
-        if update.finalized_header.beacon == Default::default() {
+        if update.finalized_beacon_header() == &BeaconBlockHeader::default() {
             return Err(Error::FinalizedHeaderNotFound);
         }
 
         let execution_update = {
-            let execution_payload_header = update.finalized_header.execution.clone();
-            let (_, state_root_branch) = deneb::prover::gen_execution_payload_field_proof(
-                &execution_payload_header,
-                EXECUTION_PAYLOAD_STATE_ROOT_SUBTREE_INDEX,
-            )?;
-            let (_, block_number_branch) = deneb::prover::gen_execution_payload_field_proof(
-                &execution_payload_header,
-                EXECUTION_PAYLOAD_BLOCK_NUMBER_SUBTREE_INDEX,
-            )?;
+            let execution_payload_header = update.finalized_execution_payload_header();
+            let (_, state_root_branch) = execution_payload_header
+                .gen_execution_payload_field_proof(EXECUTION_PAYLOAD_STATE_ROOT_SUBTREE_INDEX)?;
+            let (_, block_number_branch) = execution_payload_header
+                .gen_execution_payload_field_proof(EXECUTION_PAYLOAD_BLOCK_NUMBER_SUBTREE_INDEX)?;
             ExecutionUpdateInfo {
-                state_root: execution_payload_header.state_root,
+                state_root: execution_payload_header.state_root(),
                 state_root_branch: state_root_branch.to_vec(),
-                block_number: execution_payload_header.block_number,
+                block_number: execution_payload_header.block_number(),
                 block_number_branch: block_number_branch.to_vec(),
             }
         };
-        Ok((ConsensusUpdateInfo(update), execution_update))
+        Ok((update, execution_update))
     }
 
     fn process_light_client_update(
         &self,
         vctx: &impl ChainConsensusVerificationContext,
-        update: LightClientUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        update: ConsensusUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
         state: &LightClientStore<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
     ) -> Result<
         Option<LightClientStore<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>,
@@ -315,7 +599,7 @@ impl<
         let updates = match self.build_updates(update) {
             Ok(updates) => updates,
             Err(Error::FinalizedHeaderNotFound) => {
-                klave::notifier::send_string(&format!("updates: finalized header not found"));
+                info!("updates: finalized header not found");
                 return Ok(None);
             }
             Err(e) => return Err(e),
@@ -325,41 +609,66 @@ impl<
             .validate_updates(vctx, state, &updates.0, &updates.1)?;
 
         if let Some(new_store) = state.apply_light_client_update(vctx, &updates.0)? {
-            let state_value = serde_json::to_string(&new_store)?;
-            match klave::notifier::send_json(&PersistCommand{
-                bootstrap_info: None,
-                state_info: Some(state_value),
-                genesis_info: None,
-            }) {
-                Ok(_) => (),
-                Err(e) => {
-                    klave::notifier::send_string(&format!("failed to serialize state: {:?}", e));
-                    return Err(Error::Other {
-                        description: "failed to serialize state".into(),
-                    });
-                }
-            };
+            // the updates are persisted together, each following the state after the one before
+            self.applied_updates.borrow_mut().push(updates.0);
+            *self.applied_state.borrow_mut() = Some(new_store.clone());
             Ok(Some(new_store))
         } else {
-            klave::notifier::send_string("No new state");
+            info!("no new state");
             Ok(None)
         }
     }
 
-    fn build_verification_context(&self) -> impl ChainConsensusVerificationContext {
-        let trusted_time_ns = u64::from_str_radix(&klave::context::get("trusted_time").unwrap(), 10).unwrap();
-        let trusted_time_secs = trusted_time_ns / 1_000_000_000;
+    /// unlike `process_light_client_update`, an update that does not change the store is an error
+    fn replay_update(
+        &self,
+        vctx: &impl ChainConsensusVerificationContext,
+        update: ConsensusUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        state: &LightClientStore<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    ) -> Result<LightClientStore<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>> {
+        let updates = self.build_updates(update)?;
+        self.verifier
+            .validate_updates(vctx, state, &updates.0, &updates.1)?;
+        state
+            .apply_light_client_update(vctx, &updates.0)?
+            .ok_or_else(|| Error::Other {
+                description: "the update does not change the store".into(),
+            })
+    }
 
+    fn build_verification_context(&self) -> impl ChainConsensusVerificationContext {
         LightClientContext::new_with_config(
             self.ctx.config.clone(),
             self.genesis_validators_root,
             self.genesis_time,
-            self.trust_level.clone(),
-            U64::from(trusted_time_secs),
+            trust_level(),
+            U64::from(trusted_time_secs()),
         )
     }
 }
 
+/// the kind of the misbehaviour and the slot of its conflicting headers
+fn misbehaviour_kind<const SYNC_COMMITTEE_SIZE: usize, CU: ConsensusUpdate<SYNC_COMMITTEE_SIZE>>(
+    misbehaviour: &Misbehaviour<SYNC_COMMITTEE_SIZE, CU>,
+) -> (&'static str, Slot) {
+    match misbehaviour {
+        Misbehaviour::FinalizedHeader(data) => (
+            "finalized_header",
+            data.consensus_update_1.finalized_beacon_header().slot,
+        ),
+        Misbehaviour::NextSyncCommittee(data) => (
+            "next_sync_committee",
+            data.consensus_update_1.attested_beacon_header().slot,
+        ),
+    }
+}
+
+#[cfg(not(test))]
+fn trusted_time_secs() -> u64 {
+    let trusted_time_ns = u64::from_str_radix(&klave::context::get("trusted_time").unwrap(), 10).unwrap();
+    trusted_time_ns / 1_000_000_000
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum Target {
     None,
@@ -395,14 +704,10 @@ impl Target {
             let bn: u64 = match bn.parse() {
                 Ok(bn) => bn,
                 Err(_) => {
-                    let bn = match u64::from_str_radix(bn, 16) {
+                    match u64::from_str_radix(bn, 16) {
                         Ok(bn) => bn,
-                        Err(_) => {
-                            klave::notifier::send_string(&format!("unsupported format: {}", value));
-                            anyhow::bail!("unsupported format: {}", value);
-                        }
-                    };
-                    bn
+                        Err(_) => anyhow::bail!("unsupported format: {}", value),
+                    }
                 }
             };
             Ok(Target::BlockNumber(bn.into()))
@@ -439,3 +744,162 @@ impl PartialOrd<Updated> for Target {
 fn compute_last_slot_at_period<CC: ChainContext>(ctx: &CC, period: SyncCommitteePeriod) -> Slot {
     (period + 1) * ctx.epochs_per_sync_committee_period() * ctx.slots_per_epoch() - 1
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::consensus::src::types::H256;
+    use crate::light_client_cli::src::{
+        errors::Error,
+        state::LightClientStore,
+        testing::{context, light_client},
+    };
+    use crate::light_client_verifier::src::{
+        consensus::test_utils::{gen_bootstrap, gen_update, minimal_context, MockSyncCommittee},
+        misbehaviour::{Misbehaviour, NextSyncCommitteeMisbehaviour},
+        updates::{electra, versioned::ConsensusUpdateInfo},
+    };
+
+    #[test]
+    fn test_replay_archived_updates() {
+        let ctx = context();
+        let vctx = minimal_context(32);
+        let signer = MockSyncCommittee::new(1);
+        let bootstrap = gen_bootstrap(&vctx, &signer.committee, 8.into());
+        let updates = vec![
+            gen_update(&vctx, &signer, 16.into(), 12.into(), Some(&signer.committee)),
+            gen_update(&vctx, &signer, 24.into(), 20.into(), None),
+        ];
+        ctx.store_boostrap(&bootstrap).unwrap();
+        ctx.store_light_client_state(&LightClientStore::from_bootstrap(&bootstrap)).unwrap();
+        ctx.archive_updates(&updates).unwrap();
+
+        let client = light_client(ctx);
+        let (state, count) = client.replay_archived_updates().unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(state.latest_finalized_header.slot, 20.into());
+        assert_eq!(state.next_sync_committee, Some(signer.committee.clone()));
+    }
+
+    #[test]
+    fn test_submit_misbehaviour() {
+        let ctx = context();
+        let vctx = minimal_context(32);
+        let signer = MockSyncCommittee::new(1);
+        let other = MockSyncCommittee::new(2);
+        let bootstrap = gen_bootstrap(&vctx, &signer.committee, 8.into());
+        ctx.store_light_client_state(&LightClientStore::from_bootstrap(&bootstrap)).unwrap();
+        let client = light_client(ctx);
+
+        // two updates of the same period that agree on the next sync committee do not conflict
+        let update = gen_update(&vctx, &signer, 16.into(), 12.into(), Some(&signer.committee));
+        let misbehaviour = Misbehaviour::NextSyncCommittee(NextSyncCommitteeMisbehaviour {
+            consensus_update_1: update.clone(),
+            consensus_update_2: update.clone(),
+        });
+        assert!(client.submit_misbehaviour(misbehaviour).is_err());
+        assert_eq!(client.ctx.get_frozen_state().unwrap(), None);
+
+        let misbehaviour = Misbehaviour::NextSyncCommittee(NextSyncCommitteeMisbehaviour {
+            consensus_update_1: update,
+            consensus_update_2: gen_update(&vctx, &signer, 16.into(), 12.into(), Some(&other.committee)),
+        });
+        let frozen = client.submit_misbehaviour(misbehaviour.clone()).unwrap();
+        assert_eq!(frozen.kind, "next_sync_committee");
+        assert_eq!(frozen.misbehaviour_slot, 16.into());
+        assert_eq!(frozen.store_slot, 8.into());
+        assert_eq!(frozen.evidence, serde_json::to_value(&misbehaviour).unwrap());
+        assert_eq!(client.ctx.get_frozen_state().unwrap(), Some(frozen));
+        assert!(matches!(client.ctx.ensure_not_frozen(), Err(Error::Frozen(..))));
+
+        // a frozen client takes no other misbehaviour
+        assert!(matches!(client.submit_misbehaviour(misbehaviour), Err(Error::Frozen(..))));
+    }
+
+    /// the update with another finalized header at the same slot, which its finality branch does not prove
+    fn forge_finalized_header<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    >(
+        update: &ConsensusUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    ) -> ConsensusUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES> {
+        let mut forged = update.clone();
+        let ConsensusUpdateInfo::Electra(electra::ConsensusUpdateInfo(inner)) = &mut forged else {
+            unreachable!("the test updates are electra updates")
+        };
+        inner.finalized_header.beacon.state_root = H256([1; 32]);
+        forged
+    }
+
+    #[test]
+    fn test_find_misbehaviour() {
+        let vctx = minimal_context(32);
+        let signer = MockSyncCommittee::new(1);
+        let other = MockSyncCommittee::new(2);
+        let client = light_client(context());
+
+        let update = gen_update(&vctx, &signer, 16.into(), 12.into(), Some(&signer.committee));
+        assert!(client.find_misbehaviour(&vctx, &update, &update).is_none());
+        // updates of different finalized headers do not conflict
+        let later = gen_update(&vctx, &signer, 24.into(), 20.into(), None);
+        assert!(client.find_misbehaviour(&vctx, &update, &later).is_none());
+
+        let forged = forge_finalized_header(&update);
+        assert!(matches!(
+            client.find_misbehaviour(&vctx, &update, &forged),
+            Some(Misbehaviour::FinalizedHeader(_))
+        ));
+
+        // another next sync committee in the same period
+        let conflicting = gen_update(&vctx, &signer, 16.into(), 12.into(), Some(&other.committee));
+        assert!(matches!(
+            client.find_misbehaviour(&vctx, &update, &conflicting),
+            Some(Misbehaviour::NextSyncCommittee(_))
+        ));
+        // an update without a next sync committee does not conflict with one
+        let without = gen_update(&vctx, &signer, 16.into(), 12.into(), None);
+        assert!(client.find_misbehaviour(&vctx, &update, &without).is_none());
+    }
+
+    #[test]
+    fn test_cross_check_update() {
+        let ctx = context();
+        let vctx = minimal_context(32);
+        let signer = MockSyncCommittee::new(1);
+        let other = MockSyncCommittee::new(2);
+        let bootstrap = gen_bootstrap(&vctx, &signer.committee, 8.into());
+        let state = LightClientStore::from_bootstrap(&bootstrap);
+        let client = light_client(ctx);
+
+        let update = gen_update(&vctx, &signer, 16.into(), 12.into(), Some(&signer.committee));
+        assert!(client.cross_check_update(&vctx, &state, &update, &Vec::new()).is_ok());
+        let agreeing = vec![("http://b".to_string(), vec![update.clone()])];
+        assert!(client.cross_check_update(&vctx, &state, &update, &agreeing).is_ok());
+
+        // a conflicting update that does not verify is not a misbehaviour
+        let forged = forge_finalized_header(&update);
+        let unverified = vec![("http://b".to_string(), vec![forged])];
+        assert!(client.cross_check_update(&vctx, &state, &update, &unverified).is_ok());
+
+        let conflicting = gen_update(&vctx, &signer, 16.into(), 12.into(), Some(&other.committee));
+        let secondary_updates = vec![
+            ("http://b".to_string(), vec![update.clone()]),
+            ("http://c".to_string(), vec![conflicting.clone()]),
+        ];
+        let misbehaviour = Misbehaviour::NextSyncCommittee(NextSyncCommitteeMisbehaviour {
+            consensus_update_1: update.clone(),
+            consensus_update_2: conflicting,
+        });
+        match client.cross_check_update(&vctx, &state, &update, &secondary_updates) {
+            Err(Error::MisbehaviourDetected(endpoint, kind, slot, evidence)) => {
+                assert_eq!(endpoint, "http://c");
+                assert_eq!(kind, "next_sync_committee");
+                assert_eq!(slot, 16.into());
+                assert_eq!(*evidence, serde_json::to_value(&misbehaviour).unwrap());
+            }
+            res => panic!("unexpected result: {:?}", res),
+        }
+        // the check does not freeze the client, the misbehaviour is submitted separately
+        assert_eq!(client.ctx.get_frozen_state().unwrap(), None);
+    }
+}
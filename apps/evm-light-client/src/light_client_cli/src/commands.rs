@@ -1,15 +1,42 @@
+pub use account::{AccountCommand, VerifiedAccount};
+pub use acl::{AclCommand, AddMemberCommand, ClaimOwnerCommand, RemoveMemberCommand};
+pub use audit::AuditCommand;
 pub use block::BlockCommand;
+pub use commit::CommitCommand;
+pub use execution_header::ExecutionHeaderCommand;
+pub use finalized_header::FinalizedHeaderCommand;
+use crate::light_client_cli::src::acl::Role;
 use clap::Parser;
 pub use header::HeaderCommand;
 pub use init::InitCommand;
-pub use update::UpdateCommand;
+pub use misbehaviour::MisbehaviourCommand;
+pub use optimistic_update::OptimisticUpdateCommand;
+pub use update::{UpdateCommand, UpdateOutput};
 pub use persist::PersistCommand;
+pub use receipt::ReceiptCommand;
+pub use state::StateCommand;
+pub use storage::{StorageCommand, VerifiedStorage};
+pub use transaction::TransactionCommand;
+pub use unfreeze::UnfreezeCommand;
 
+mod account;
+mod acl;
+mod audit;
 mod block;
+mod commit;
+mod execution_header;
+mod finalized_header;
 mod header;
 mod init;
+mod misbehaviour;
+mod optimistic_update;
 mod update;
 mod persist;
+mod receipt;
+mod state;
+mod storage;
+mod transaction;
+mod unfreeze;
 
 #[derive(Parser, Debug)]
 pub enum Command {
@@ -19,8 +46,59 @@ pub enum Command {
     Persist(PersistCommand),
     #[clap(about = "Update light client")]
     Update(UpdateCommand),
+    #[clap(about = "Verify and commit updates if the finalized slot is still the expected one")]
+    Commit(CommitCommand),
+    #[clap(about = "Update the optimistic header of light client")]
+    OptimisticUpdate(OptimisticUpdateCommand),
     #[clap(about = "Fetch specific header")]
     Header(HeaderCommand),
     #[clap(about = "Fetch specific block")]
     Block(BlockCommand),
+    #[clap(about = "Verify an account against the latest finalized or optimistic execution state root")]
+    Account(AccountCommand),
+    #[clap(about = "Verify storage slots of an account against the latest finalized or optimistic execution state root")]
+    Storage(StorageCommand),
+    #[clap(about = "Verify a log against the latest finalized or optimistic execution receipts root")]
+    Receipt(ReceiptCommand),
+    #[clap(about = "Verify an execution block header against the latest finalized or optimistic block hash")]
+    ExecutionHeader(ExecutionHeaderCommand),
+    #[clap(about = "Verify a transaction against the latest finalized or optimistic transactions root")]
+    Transaction(TransactionCommand),
+    #[clap(about = "Show a finalized header from the historical index")]
+    FinalizedHeader(FinalizedHeaderCommand),
+    #[clap(about = "Replay the archived updates from the bootstrap and compare with the stored state")]
+    Audit(AuditCommand),
+    #[clap(about = "Show the stored light client state")]
+    State(StateCommand),
+    #[clap(about = "Submit a misbehaviour and freeze light client")]
+    Misbehaviour(MisbehaviourCommand),
+    #[clap(about = "Unfreeze light client")]
+    Unfreeze(UnfreezeCommand),
+    #[clap(about = "Add a member to the access control list or change its role")]
+    AddMember(AddMemberCommand),
+    #[clap(about = "Remove a member from the access control list")]
+    RemoveMember(RemoveMemberCommand),
+    #[clap(about = "Show the access control list")]
+    Acl(AclCommand),
+    #[clap(about = "Become the owner of a light client without an access control list")]
+    ClaimOwner(ClaimOwnerCommand),
+}
+
+impl Command {
+    /// role the sender needs to run the command, none if anyone can
+    pub fn required_role(&self) -> Option<Role> {
+        match self {
+            // a persist with a bootstrap reinitializes the client
+            Command::Persist(cmd) if cmd.bootstrap_info.is_some() => Some(Role::Admin),
+            Command::Persist(_) | Command::Commit(_) => Some(Role::Updater),
+            Command::Unfreeze(_) => Some(Role::Admin),
+            // members are checked against the role they are given or had, by the stored acl
+            Command::AddMember(_) | Command::RemoveMember(_) => None,
+            // only a ledger without an acl can be claimed, see `Context::claim_owner`
+            Command::ClaimOwner(_) => None,
+            // the misbehaviour is verified, so anyone can submit evidence
+            Command::Misbehaviour(_) => None,
+            _ => Some(Role::Reader),
+        }
+    }
 }
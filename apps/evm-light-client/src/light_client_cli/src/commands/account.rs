@@ -0,0 +1,226 @@
+use crate::consensus::src::{
+    beacon::BeaconBlockHeader,
+    fork::versioned::ExecutionPayloadHeader,
+    types::{Address, H256, U64},
+};
+use crate::execution_rpc::src::{
+    client::RPCClient as ExecutionRPCClient,
+    types::{AccountProof, U256Quantity},
+};
+use crate::light_client_cli::src::{context::Context, state::Anchor};
+use crate::light_client_verifier::src::execution::{
+    Account, ExecutionVerifier, EMPTY_CODE_HASH, EMPTY_TRIE_ROOT,
+};
+use anyhow::{bail, Result};
+use clap::Parser;
+use primitive_types::U256;
+use std::str::FromStr;
+
+#[derive(Clone, Debug, Parser, PartialEq)]
+pub struct AccountCommand {
+    #[clap(long = "address", help = "Account address")]
+    pub address: String,
+    #[clap(long = "block_number", help = "Block number the proof must be anchored to")]
+    pub block_number: Option<u64>,
+    #[clap(long = "anchor", help = "Header the proof is anchored to: finalized (default) or optimistic")]
+    pub anchor: Option<String>,
+    #[clap(long = "account_proof", help = "eth_getProof response")]
+    pub account_proof: Option<String>,
+    #[clap(long = "execution_endpoint", help = "Execution RPC endpoint to fetch the proof from")]
+    pub execution_endpoint: Option<String>,
+}
+
+/// VerifiedAccount is an account verified against the execution state root of the latest finalized header
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct VerifiedAccount {
+    pub address: Address,
+    /// false if the proof shows that the account does not exist
+    pub exists: bool,
+    pub nonce: U64,
+    pub balance: U256Quantity,
+    pub storage_root: H256,
+    pub code_hash: H256,
+    pub block_number: U64,
+    pub state_root: H256,
+}
+
+impl AccountCommand {
+    pub fn run<
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+    >(
+        self,
+        ctx: Context<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES, SYNC_COMMITTEE_SIZE>,
+    ) -> Result<VerifiedAccount> {
+        let address = parse_address(&self.address)?;
+        let header = execution_anchor(&ctx, self.anchor.as_deref(), self.block_number)?;
+        let (block_number, state_root) = (header.block_number(), header.state_root());
+        let proof = fetch_account_proof(
+            &address,
+            &[],
+            block_number,
+            self.account_proof,
+            self.execution_endpoint,
+        )?;
+        verify_account(&address, &proof, state_root, block_number)
+    }
+}
+
+/// Returns the execution payload header selected by `anchor`, failing if `requested` is another block
+pub(crate) fn execution_anchor<
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+>(
+    ctx: &Context<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES, SYNC_COMMITTEE_SIZE>,
+    anchor: Option<&str>,
+    requested: Option<u64>,
+) -> Result<ExecutionPayloadHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>> {
+    Ok(anchor_headers(ctx, anchor, requested)?.1)
+}
+
+/// Returns the beacon and execution headers selected by `anchor`, the latest finalized ones by default.
+/// A `requested` block that is not the anchored one must be a past finalized block of the historical index
+pub(crate) fn anchor_headers<
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+>(
+    ctx: &Context<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES, SYNC_COMMITTEE_SIZE>,
+    anchor: Option<&str>,
+    requested: Option<u64>,
+) -> Result<(
+    BeaconBlockHeader,
+    ExecutionPayloadHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+)> {
+    // a frozen client no longer trusts its finalized header
+    ctx.ensure_not_frozen()?;
+    let anchor = anchor.map(Anchor::from_str).transpose()?.unwrap_or_default();
+    let state = ctx.get_light_client_state()?;
+    let (beacon, header) = state.anchor_headers(anchor);
+    match requested {
+        Some(requested) if requested != header.block_number().0 => {
+            // past finalized blocks are anchored to the historical index
+            match ctx.get_finalized_header_at_block_number(requested)? {
+                Some(finalized) if anchor == Anchor::Finalized => {
+                    Ok((finalized.beacon, finalized.execution))
+                }
+                _ => bail!(
+                    "block {} is not anchored: the latest {} block is {}",
+                    requested,
+                    anchor,
+                    header.block_number()
+                ),
+            }
+        }
+        _ => Ok((beacon.clone(), header.clone())),
+    }
+}
+
+pub(crate) fn parse_address(value: &str) -> Result<Address> {
+    let bytes = hex::decode(value.strip_prefix("0x").unwrap_or(value))?;
+    Ok(Address::try_from(bytes.as_slice())?)
+}
+
+/// Returns the given `eth_getProof` response, or fetches it at `block_number` from `execution_endpoint`
+pub(crate) fn fetch_account_proof(
+    address: &Address,
+    storage_keys: &[H256],
+    block_number: U64,
+    account_proof: Option<String>,
+    execution_endpoint: Option<String>,
+) -> Result<AccountProof> {
+    let proof: AccountProof = match (account_proof, execution_endpoint) {
+        (Some(account_proof), _) => serde_json::from_str(&account_proof)?,
+        (None, Some(execution_endpoint)) => ExecutionRPCClient::new(execution_endpoint)?.get_proof(
+            address,
+            storage_keys,
+            block_number.into(),
+        )?,
+        (None, None) => bail!("either account_proof or execution_endpoint is required"),
+    };
+    if &proof.address != address {
+        bail!(
+            "account proof is for another address: expected=0x{} actual=0x{}",
+            hex::encode(address.0),
+            hex::encode(proof.address.0)
+        );
+    }
+    Ok(proof)
+}
+
+pub(crate) fn verify_account(
+    address: &Address,
+    proof: &AccountProof,
+    state_root: H256,
+    block_number: U64,
+) -> Result<VerifiedAccount> {
+    let account = ExecutionVerifier.verify_account(state_root, address, proof.account_proof_nodes())?;
+    Ok(to_verified_account(address, account, state_root, block_number))
+}
+
+pub(crate) fn to_verified_account(
+    address: &Address,
+    account: Option<Account>,
+    state_root: H256,
+    block_number: U64,
+) -> VerifiedAccount {
+    match account {
+        Some(account) => VerifiedAccount {
+            address: address.clone(),
+            exists: true,
+            nonce: account.nonce,
+            balance: U256Quantity(U256::from_big_endian(&account.balance)),
+            storage_root: account.storage_root,
+            code_hash: account.code_hash,
+            block_number,
+            state_root,
+        },
+        None => VerifiedAccount {
+            address: address.clone(),
+            exists: false,
+            nonce: U64(0),
+            balance: U256Quantity(U256::zero()),
+            storage_root: EMPTY_TRIE_ROOT,
+            code_hash: EMPTY_CODE_HASH,
+            block_number,
+            state_root,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::light_client_cli::src::{state::LightClientStore, testing::context};
+    use crate::light_client_verifier::src::consensus::test_utils::{
+        gen_bootstrap, gen_update, minimal_context, MockSyncCommittee,
+    };
+
+    #[test]
+    fn test_anchor_past_block() {
+        let ctx = context();
+        let vctx = minimal_context(32);
+        let signer = MockSyncCommittee::new(1);
+        let state = LightClientStore::from_bootstrap(&gen_bootstrap(&vctx, &signer.committee, 8.into()));
+        let update = gen_update(&vctx, &signer, 16.into(), 12.into(), Some(&signer.committee));
+        let new_state = state.apply_light_client_update(&vctx, &update).unwrap().unwrap();
+        ctx.store_light_client_state(&state).unwrap();
+        ctx.store_light_client_state(&new_state).unwrap();
+
+        // the latest finalized block by default, a past one through the historical index
+        let (beacon, header) = anchor_headers(&ctx, None, None).unwrap();
+        assert_eq!(beacon.slot, 12.into());
+        assert_eq!(header.block_number(), 12.into());
+        assert_eq!(anchor_headers(&ctx, None, Some(12)).unwrap().0.slot, 12.into());
+        let (beacon, header) = anchor_headers(&ctx, None, Some(8)).unwrap();
+        assert_eq!(beacon, state.latest_finalized_header);
+        assert_eq!(header, state.latest_execution_payload_header);
+        assert_eq!(execution_anchor(&ctx, Some("finalized"), Some(8)).unwrap().block_number(), 8.into());
+
+        // a block that was never the finalized one, or a past block read from the optimistic header
+        assert!(anchor_headers(&ctx, None, Some(10)).is_err());
+        assert!(anchor_headers(&ctx, Some("optimistic"), Some(8)).is_err());
+    }
+}
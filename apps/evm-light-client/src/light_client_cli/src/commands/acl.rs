@@ -0,0 +1,114 @@
+use crate::light_client_cli::src::{
+    acl::{sender, Acl, Role},
+    context::Context,
+    errors::Error,
+};
+use anyhow::Result;
+use clap::Parser;
+use serde_json::{json, Value};
+use std::str::FromStr;
+
+#[derive(Clone, Debug, Parser, PartialEq)]
+pub struct AddMemberCommand {
+    #[clap(long = "member", help = "Klave sender identity of the member")]
+    pub member: String,
+    #[clap(long = "role", help = "Role of the member: reader, updater, admin or owner")]
+    pub role: String,
+}
+
+impl AddMemberCommand {
+    pub fn run<
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+    >(
+        self,
+        ctx: Context<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES, SYNC_COMMITTEE_SIZE>,
+    ) -> Result<Value> {
+        let role = Role::from_str(&self.role)?;
+        let mut acl = stored_acl(&ctx)?;
+        acl.add_member(&sender()?, self.member.clone(), role)?;
+        ctx.store_acl(&acl)?;
+        Ok(json!({"member": self.member, "role": role}))
+    }
+}
+
+#[derive(Clone, Debug, Parser, PartialEq)]
+pub struct RemoveMemberCommand {
+    #[clap(long = "member", help = "Klave sender identity of the member")]
+    pub member: String,
+}
+
+impl RemoveMemberCommand {
+    pub fn run<
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+    >(
+        self,
+        ctx: Context<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES, SYNC_COMMITTEE_SIZE>,
+    ) -> Result<Value> {
+        let mut acl = stored_acl(&ctx)?;
+        let role = acl.remove_member(&sender()?, &self.member)?;
+        ctx.store_acl(&acl)?;
+        Ok(json!({"member": self.member, "role": role}))
+    }
+}
+
+#[derive(Clone, Debug, Parser, PartialEq)]
+pub struct AclCommand {}
+
+impl AclCommand {
+    pub fn run<
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+    >(
+        self,
+        ctx: Context<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES, SYNC_COMMITTEE_SIZE>,
+    ) -> Result<Value> {
+        let acl = ctx.get_acl()?.unwrap_or_default();
+        Ok(json!({
+            "members": acl.members,
+            "restricts_reads": acl.restricts_reads(),
+        }))
+    }
+}
+
+/// the identity that may claim a ledger whose state was stored before the access control list existed,
+/// given when the app is built to migrate such a ledger
+const LEGACY_OWNER: Option<&str> = option_env!("LIGHT_CLIENT_LEGACY_OWNER");
+
+#[derive(Clone, Debug, Parser, PartialEq)]
+pub struct ClaimOwnerCommand {}
+
+impl ClaimOwnerCommand {
+    pub fn run<
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+    >(
+        self,
+        ctx: Context<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES, SYNC_COMMITTEE_SIZE>,
+    ) -> Result<Value> {
+        let acl = ctx.claim_owner(LEGACY_OWNER)?;
+        Ok(json!({
+            "members": acl.members,
+            "restricts_reads": acl.restricts_reads(),
+        }))
+    }
+}
+
+/// the members are only managed by the members of a stored acl
+fn stored_acl<
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+>(
+    ctx: &Context<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES, SYNC_COMMITTEE_SIZE>,
+) -> Result<Acl> {
+    match ctx.get_acl()? {
+        Some(acl) => Ok(acl),
+        None => Err(Error::Unauthorized(sender()?, Role::Admin).into()),
+    }
+}
@@ -0,0 +1,43 @@
+use crate::light_client_cli::src::{chain::Chain, client::LightClient, context::Context};
+use crate::light_client_verifier::src::updates::LightClientBootstrap;
+use anyhow::Result;
+use clap::Parser;
+use serde_json::{json, Value};
+
+#[derive(Clone, Debug, Parser, PartialEq)]
+pub struct AuditCommand {}
+
+impl AuditCommand {
+    /// re-verifies the archived updates from the stored bootstrap, the beacon endpoint is never called
+    pub fn run<
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+    >(
+        self,
+        ctx: Context<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES, SYNC_COMMITTEE_SIZE>,
+    ) -> Result<Value> {
+        let chain = Chain::new(ctx.beacon_endpoint())?;
+        let genesis = ctx.get_genesis()?;
+        let mut state = ctx.get_light_client_state()?;
+        let bootstrap_slot = ctx.get_bootstrap()?.beacon_header().slot;
+        let lc = LightClient::new(
+            ctx,
+            chain,
+            genesis.genesis_time,
+            genesis.genesis_validators_root,
+        );
+        let (mut replayed, updates) = lc.replay_archived_updates()?;
+
+        // the optimistic header is not derived from the archived updates
+        replayed.optimistic_header = None;
+        state.optimistic_header = None;
+        Ok(json!({
+            "bootstrap_slot": bootstrap_slot,
+            "updates": updates,
+            "replayed_finalized_slot": replayed.latest_finalized_header.slot,
+            "finalized_slot": state.latest_finalized_header.slot,
+            "consistent": replayed == state,
+        }))
+    }
+}
@@ -2,6 +2,7 @@ use crate::light_client_cli::src::context::Context;
 use anyhow::Result;
 use clap::Parser;
 use crate::lodestar_rpc::src::client::RPCClient;
+use crate::consensus::src::preset::mainnet::DenebBeaconBlock;
 
 #[derive(Clone, Debug, Parser, PartialEq)]
 pub struct BlockCommand {
@@ -17,8 +18,8 @@ impl BlockCommand {
     >(
         self,
         ctx: Context<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES, SYNC_COMMITTEE_SIZE>,
-    ) -> Result<()> {
-        let client = RPCClient::new(ctx.beacon_endpoint());
+    ) -> Result<DenebBeaconBlock> {
+        let client = RPCClient::new(ctx.beacon_endpoint())?;
 
         let res = match self.slot {
             Some(slot) => client.get_beacon_block_by_slot(slot.into())?,
@@ -29,11 +30,10 @@ impl BlockCommand {
                     MAX_EXTRA_DATA_BYTES,
                 >()?;
                 client
-                    .get_beacon_block_by_slot(res.data.finalized_header.beacon.slot)
+                    .get_beacon_block_by_slot(res.finalized_beacon_header().slot)
                     ?
             }
         };
-        klave::notifier::send_string(&format!("{}", serde_json::to_string(&res.data.message)?));
-        Ok(())
+        Ok(res.data.message)
     }
 }
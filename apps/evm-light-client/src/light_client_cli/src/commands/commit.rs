@@ -0,0 +1,170 @@
+use crate::light_client_cli::src::{
+    chain::Chain,
+    client::{LightClient, Target},
+    context::Context,
+    errors::Error,
+    state::LightClientStore,
+};
+use anyhow::{anyhow, Result};
+use clap::Parser;
+use serde_json::{json, Value};
+
+#[derive(Clone, Debug, Parser, PartialEq)]
+pub struct CommitCommand {
+    #[clap(long = "expected_finalized_slot", help = "Finalized slot the stored state must be at")]
+    pub expected_finalized_slot: u64,
+    #[clap(long = "updates_info", help = "Consensus updates to apply, as a JSON array, otherwise fetched from the beacon endpoint")]
+    pub updates_info: Option<String>,
+    #[clap(long = "secondary_beacon_endpoint", help = "Beacon endpoints the fetched updates are cross-checked against")]
+    pub secondary_beacon_endpoints: Vec<String>,
+}
+
+impl CommitCommand {
+    /// verifies and commits the updates in the same transaction, if the stored state is still at `expected_finalized_slot`
+    pub fn run<
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+    >(
+        self,
+        ctx: Context<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES, SYNC_COMMITTEE_SIZE>,
+    ) -> Result<Value> {
+        ctx.ensure_not_frozen()?;
+        let stored_state = ctx.get_light_client_state()?;
+        let stored_slot = stored_state.latest_finalized_header.slot;
+        // nothing is fetched if the ledger already moved
+        check_commit(self.expected_finalized_slot, &stored_state, None)?;
+
+        let genesis = ctx.get_genesis()?;
+        let fork_digests = ctx.fork_digests(genesis.genesis_validators_root);
+        let chain = Chain::new(ctx.beacon_endpoint())?.with_fork_digests(fork_digests.clone());
+        let lc = LightClient::new(
+            ctx,
+            chain,
+            genesis.genesis_time,
+            genesis.genesis_validators_root,
+        )
+        .with_secondary_chains(Chain::secondary_chains(
+            &self.secondary_beacon_endpoints,
+            &fork_digests,
+        ));
+
+        let applied = match self.updates_info {
+            Some(updates_info) => {
+                let updates: Vec<_> = serde_json::from_str(&updates_info)
+                    .map_err(|e| anyhow!("invalid updates info: {}", e))?;
+                if updates.is_empty() {
+                    None
+                } else {
+                    Some((lc.verify_updates(stored_state.clone(), updates.clone())?, updates))
+                }
+            }
+            None => {
+                lc.check_endpoints();
+                lc.update_until_target(Target::None)?;
+                lc.applied()
+            }
+        };
+
+        let committed = check_commit(
+            self.expected_finalized_slot,
+            &stored_state,
+            applied.as_ref().map(|(state, _)| state),
+        )?;
+        let Some((state, updates)) = applied.filter(|_| committed) else {
+            return Ok(json!({
+                "committed": false,
+                "finalized_slot": stored_slot,
+            }));
+        };
+        let new_slot = state.latest_finalized_header.slot;
+        let output = json!({
+            "committed": true,
+            "previous_finalized_slot": stored_slot,
+            "finalized_slot": new_slot,
+            "finalized_block_number": state.latest_execution_payload_header.block_number(),
+            "updates": updates.len(),
+        });
+        lc.archive_updates(updates)?;
+        lc.store_light_client_state(state)?;
+        Ok(output)
+    }
+}
+
+/// decides the commit before anything is written: the stored state must still be at `expected_finalized_slot`
+/// and the new state must follow it. Returns whether the new state is committed
+fn check_commit<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    expected_finalized_slot: u64,
+    stored: &LightClientStore<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    new: Option<&LightClientStore<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>,
+) -> Result<bool, Error> {
+    let stored_slot = stored.current_slot();
+    if stored_slot.0 != expected_finalized_slot {
+        return Err(Error::FinalizedSlotMismatch(expected_finalized_slot.into(), stored_slot));
+    }
+    match new {
+        Some(new) if new != stored => {
+            new.ensure_follows(stored)?;
+            Ok(true)
+        }
+        _ => Ok(false),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Store = LightClientStore<32, 256, 32>;
+
+    fn store_at(slot: u64) -> Store {
+        let mut store = Store::default();
+        store.latest_finalized_header.slot = slot.into();
+        store
+    }
+
+    #[test]
+    fn test_check_commit() {
+        let stored = store_at(64);
+
+        // the ledger moved since the caller read the finalized slot
+        assert!(matches!(
+            check_commit(32, &stored, None),
+            Err(Error::FinalizedSlotMismatch(expected, actual)) if expected.0 == 32 && actual.0 == 64
+        ));
+        assert!(matches!(
+            check_commit(32, &stored, Some(&store_at(96))),
+            Err(Error::FinalizedSlotMismatch(..))
+        ));
+
+        // nothing to commit
+        assert!(!check_commit(64, &stored, None).unwrap());
+        assert!(!check_commit(64, &stored, Some(&stored)).unwrap());
+
+        // a newer finalized header, or a new sync committee at the same one
+        assert!(check_commit(64, &stored, Some(&store_at(96))).unwrap());
+        let mut same_slot = stored.clone();
+        same_slot.next_sync_committee = Some(Default::default());
+        assert!(check_commit(64, &stored, Some(&same_slot)).unwrap());
+
+        // the finalized slot never goes backwards
+        assert!(matches!(
+            check_commit(64, &stored, Some(&store_at(32))),
+            Err(Error::FinalizedSlotRegression(stored, new)) if stored.0 == 64 && new.0 == 32
+        ));
+    }
+
+    #[test]
+    fn test_ensure_follows() {
+        assert!(store_at(64).ensure_follows(&store_at(64)).is_ok());
+        assert!(store_at(96).ensure_follows(&store_at(64)).is_ok());
+        assert!(matches!(
+            store_at(32).ensure_follows(&store_at(64)),
+            Err(Error::FinalizedSlotRegression(..))
+        ));
+    }
+}
@@ -0,0 +1,57 @@
+use super::account::execution_anchor;
+use crate::execution_rpc::src::{
+    client::RPCClient as ExecutionRPCClient,
+    types::{Block, BlockHeader, BlockId},
+};
+use crate::light_client_cli::src::context::Context;
+use crate::light_client_verifier::src::execution::ExecutionVerifier;
+use anyhow::{anyhow, bail, Result};
+use clap::Parser;
+
+#[derive(Clone, Debug, Parser, PartialEq)]
+pub struct ExecutionHeaderCommand {
+    #[clap(long = "block_number", help = "Block number the header must be anchored to")]
+    pub block_number: Option<u64>,
+    #[clap(long = "anchor", help = "Header the proof is anchored to: finalized (default) or optimistic")]
+    pub anchor: Option<String>,
+    #[clap(long = "block", help = "eth_getBlockByNumber response")]
+    pub block: Option<String>,
+    #[clap(long = "execution_endpoint", help = "Execution RPC endpoint to fetch the block from")]
+    pub execution_endpoint: Option<String>,
+}
+
+impl ExecutionHeaderCommand {
+    pub fn run<
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+    >(
+        self,
+        ctx: Context<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES, SYNC_COMMITTEE_SIZE>,
+    ) -> Result<BlockHeader> {
+        let header = execution_anchor(&ctx, self.anchor.as_deref(), self.block_number)?;
+        let block: Block = match (self.block, self.execution_endpoint) {
+            (Some(block), _) => serde_json::from_str(&block)?,
+            (None, Some(execution_endpoint)) => ExecutionRPCClient::new(execution_endpoint)?
+                .get_block_by_number(BlockId::Number(header.block_number().0))?
+                .ok_or_else(|| anyhow!("block not found: {}", header.block_number()))?,
+            (None, None) => bail!("either block or execution_endpoint is required"),
+        };
+
+        // the block hash covers the header fields, including the ones the execution payload does not carry,
+        // e.g. `logs_bloom`, `difficulty` and `ommers_hash`, but not the transactions, so only the header is returned
+        let block_header = block.header;
+        ExecutionVerifier.verify_block_header(
+            header.block_hash(),
+            &block_header.to_header().map_err(|e| anyhow!(e))?,
+        )?;
+        if block_header.hash != header.block_hash() {
+            bail!(
+                "block hash mismatch: expected={:?} actual={:?}",
+                header.block_hash(),
+                block_header.hash
+            );
+        }
+        Ok(block_header)
+    }
+}
@@ -0,0 +1,34 @@
+use crate::light_client_cli::src::context::Context;
+use anyhow::{anyhow, bail, Result};
+use clap::Parser;
+
+#[derive(Clone, Debug, Parser, PartialEq)]
+pub struct FinalizedHeaderCommand {
+    #[clap(long = "slot", help = "Slot of the finalized header")]
+    pub slot: Option<u64>,
+    #[clap(long = "block_number", help = "Execution block number of the finalized header")]
+    pub block_number: Option<u64>,
+}
+
+impl FinalizedHeaderCommand {
+    /// looks up a header the light client has finalized in the historical index of the ledger
+    pub fn run<
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+    >(
+        self,
+        ctx: Context<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES, SYNC_COMMITTEE_SIZE>,
+    ) -> Result<serde_json::Value> {
+        let header = match (self.slot, self.block_number) {
+            (Some(slot), None) => ctx
+                .get_finalized_header_at_slot(slot.into())?
+                .ok_or_else(|| anyhow!("no finalized header at slot {}", slot))?,
+            (None, Some(block_number)) => ctx
+                .get_finalized_header_at_block_number(block_number)?
+                .ok_or_else(|| anyhow!("no finalized header at block {}", block_number))?,
+            _ => bail!("either slot or block_number is required"),
+        };
+        Ok(header.to_json())
+    }
+}
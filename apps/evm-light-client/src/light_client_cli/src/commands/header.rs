@@ -2,6 +2,7 @@ use crate::light_client_cli::src::context::Context;
 use anyhow::Result;
 use clap::Parser;
 use crate::lodestar_rpc::src::client::RPCClient;
+use crate::consensus::src::beacon::BeaconBlockHeader;
 
 #[derive(Clone, Debug, Parser, PartialEq)]
 pub struct HeaderCommand {
@@ -17,8 +18,8 @@ impl HeaderCommand {
     >(
         self,
         ctx: Context<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES, SYNC_COMMITTEE_SIZE>,
-    ) -> Result<()> {
-        let client = RPCClient::new(ctx.beacon_endpoint());
+    ) -> Result<BeaconBlockHeader> {
+        let client = RPCClient::new(ctx.beacon_endpoint())?;
         let res = match self.slot {
             Some(slot) => client.get_beacon_header_by_slot(slot.into())?,
             None => {
@@ -28,11 +29,10 @@ impl HeaderCommand {
                     MAX_EXTRA_DATA_BYTES,
                 >()?;
                 client
-                    .get_beacon_header_by_slot(res.data.finalized_header.beacon.slot)
+                    .get_beacon_header_by_slot(res.finalized_beacon_header().slot)
                     ?
             }
         };
-        klave::notifier::send_string(&format!("{}", serde_json::to_string(&res.data.header.message)?));
-        Ok(())
+        Ok(res.data.header.message)
     }
 }
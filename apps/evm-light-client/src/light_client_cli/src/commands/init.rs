@@ -1,3 +1,4 @@
+use super::PersistCommand;
 use crate::light_client_cli::src::{chain::Chain, client::LightClient, context::Context};
 use anyhow::Result;
 use clap::Parser;
@@ -19,8 +20,9 @@ impl InitCommand {
     >(
         self,
         ctx: Context<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES, SYNC_COMMITTEE_SIZE>,
-    ) -> Result<()> {
-        let chain = Chain::new(ctx.beacon_endpoint());
+    ) -> Result<PersistCommand> {
+        ctx.ensure_not_frozen()?;
+        let chain = Chain::new(ctx.beacon_endpoint())?;
         let trusted_block_root = if let Some(trusted_block_root) = self.trusted_block_root {
             Some(H256::from_hex(&trusted_block_root).map_err(|e| anyhow::Error::msg(e.to_string()))?)
         } else if let Some(untrusted_slot) = self.untrusted_slot {
@@ -42,9 +44,7 @@ impl InitCommand {
             chain,
             genesis.genesis_time,
             genesis.genesis_validators_root,
-            None,
         );
-        lc.init_with_bootstrap(trusted_block_root, &genesis)?;
-        Ok(())
+        Ok(lc.init_with_bootstrap(trusted_block_root, &genesis)?)
     }
 }
@@ -0,0 +1,38 @@
+use crate::light_client_cli::src::{chain::Chain, client::LightClient, context::Context};
+use anyhow::Result;
+use clap::Parser;
+
+#[derive(Clone, Debug, Parser, PartialEq)]
+pub struct MisbehaviourCommand {
+    #[clap(long = "misbehaviour", help = "FinalizedHeader or NextSyncCommittee misbehaviour with two conflicting updates")]
+    pub misbehaviour: String,
+}
+
+impl MisbehaviourCommand {
+    pub fn run<
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+    >(
+        self,
+        ctx: Context<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES, SYNC_COMMITTEE_SIZE>,
+    ) -> Result<serde_json::Value> {
+        let misbehaviour = serde_json::from_str(&self.misbehaviour)?;
+        let chain = Chain::new(ctx.beacon_endpoint())?;
+        let genesis = ctx.get_genesis()?;
+        let lc = LightClient::new(
+            ctx,
+            chain,
+            genesis.genesis_time,
+            genesis.genesis_validators_root,
+        );
+
+        let frozen = lc.submit_misbehaviour(misbehaviour)?;
+        Ok(serde_json::json!({
+            "frozen": true,
+            "kind": frozen.kind,
+            "misbehaviour_slot": frozen.misbehaviour_slot,
+            "store_slot": frozen.store_slot,
+        }))
+    }
+}
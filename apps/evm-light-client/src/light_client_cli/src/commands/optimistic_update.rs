@@ -0,0 +1,32 @@
+use super::UpdateOutput;
+use crate::light_client_cli::src::{chain::Chain, client::LightClient, context::Context};
+use anyhow::Result;
+use clap::Parser;
+
+#[derive(Clone, Debug, Parser, PartialEq)]
+pub struct OptimisticUpdateCommand {}
+
+impl OptimisticUpdateCommand {
+    pub fn run<
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+    >(
+        self,
+        ctx: Context<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES, SYNC_COMMITTEE_SIZE>,
+    ) -> Result<UpdateOutput> {
+        ctx.ensure_not_frozen()?;
+        let chain = Chain::new(ctx.beacon_endpoint())?;
+        let genesis = ctx.get_genesis()?;
+        let lc = LightClient::new(
+            ctx,
+            chain,
+            genesis.genesis_time,
+            genesis.genesis_validators_root,
+        );
+        Ok(UpdateOutput {
+            persist: lc.update_optimistic_header()?,
+            endpoints: Vec::new(),
+        })
+    }
+}
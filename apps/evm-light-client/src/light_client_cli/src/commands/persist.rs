@@ -1,10 +1,19 @@
-use crate::light_client_cli::src::{chain::Chain, client::LightClient, context::Context};
-use anyhow::Result;
+use crate::light_client_cli::src::{
+    chain::Chain, client::LightClient, context::Context, errors::Error,
+    state::{LightClientStore, StateSummary},
+    ticket::{self, state_hash, EnclaveKey, PersistTicket},
+};
+use crate::consensus::src::{compute::hash_tree_root, types::H256};
+use crate::light_client_verifier::src::updates::{versioned::LightClientBootstrapInfo, LightClientBootstrap};
+use anyhow::{anyhow, bail, Result};
 use clap::Parser;
 use crate::lodestar_rpc::src::types::GenesisData;
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
-#[derive(Clone, Debug, Parser, PartialEq, Serialize, Deserialize)]
+/// PersistCommand carries the bootstrap or the updates that the state follows from.
+/// The state is derived again from them and compared with `state_info` before it is written,
+/// unless a ticket of the enclave vouches for `state_info`.
+#[derive(Clone, Debug, Default, Parser, PartialEq, Serialize, Deserialize)]
 pub struct PersistCommand {
     #[clap(long = "bootstrap_info", help = "Bootstrap Info")]
     pub bootstrap_info: Option<String>,
@@ -12,6 +21,15 @@ pub struct PersistCommand {
     pub state_info: Option<String>,
     #[clap(long = "genesis_info", help = "Genesis Info")]
     pub genesis_info: Option<String>,
+    #[clap(long = "updates_info", help = "Consensus updates that led to the state, as a JSON array")]
+    #[serde(default)]
+    pub updates_info: Option<String>,
+    #[clap(long = "optimistic_update_info", help = "Optimistic update that led to the state")]
+    #[serde(default)]
+    pub optimistic_update_info: Option<String>,
+    #[clap(long = "ticket", help = "Persist ticket signed by the enclave, skips the verification")]
+    #[serde(default)]
+    pub ticket: Option<String>,
 }
 
 impl PersistCommand {
@@ -22,58 +40,324 @@ impl PersistCommand {
     >(
         self,
         ctx: Context<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES, SYNC_COMMITTEE_SIZE>
-    ) -> Result<()> {
-        let chain = Chain::new(ctx.beacon_endpoint());
+    ) -> Result<Option<StateSummary>> {
+        ctx.ensure_not_frozen()?;
+        // the queries sign the persist tickets with the key, which only a transaction can create
+        EnclaveKey::create()?;
+        let chain = Chain::new(ctx.beacon_endpoint())?;
 
         let genesis = match ctx.get_genesis() {
             Ok(genesis) => genesis,
-            Err(e) => {
-                match self.genesis_info {
-                    Some(genesis_info) => match serde_json::from_str::<GenesisData>(&genesis_info) {
-                        Ok(genesis) => genesis,
-                        Err(e) => {
-                            klave::notifier::send_string(&format!("Invalid genesis info: {}", e));
-                            return Ok(());
-                        }
-                    },
-                    None => {
-                        klave::notifier::send_string(&format!("Missing genesis info: {}", e));
-                        return Ok(());
-                    }
+            Err(e) => match (&self.genesis_info, &self.bootstrap_info) {
+                (Some(genesis_info), Some(_)) => {
+                    let genesis = parse_info::<GenesisData>(genesis_info, "genesis info")?;
+                    check_genesis(&ctx, &genesis)?;
+                    genesis
                 }
-            }
+                (Some(_), None) => bail!("genesis info is only accepted with a bootstrap"),
+                (None, _) => return Err(anyhow!("missing genesis info: {}", e)),
+            },
+        };
+
+        // nothing is written until the whole transition is verified
+        let bootstrap: Option<LightClientBootstrapInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>> =
+            match &self.bootstrap_info {
+                Some(bootstrap_info) => Some(parse_info(bootstrap_info, "bootstrap info")?),
+                None => None,
+            };
+        let updates = match &self.updates_info {
+            Some(updates_info) => parse_info(updates_info, "updates info")?,
+            None => Vec::new(),
         };
+        let expected: Option<LightClientStore<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>> =
+            match &self.state_info {
+                Some(state_info) => Some(parse_info(state_info, "state info")?),
+                None => None,
+            };
 
+        let stored_state = ctx.find_light_client_state()?;
+        // once a state is stored, a bootstrap must be one of the finalized headers of the client, whose root is then
+        // the trusted block root, and `ensure_follows` below refuses one older than the stored state: only the latest
+        // finalized header may bootstrap the client again. The first bootstrap is trusted as the sender's
+        let trusted_block_root = match (&bootstrap, &stored_state) {
+            (Some(bootstrap), Some(_)) => {
+                let slot = bootstrap.beacon_header().slot;
+                let Some(finalized) = ctx.get_finalized_header_at_slot(slot)? else {
+                    bail!("the bootstrap at slot {} is not a finalized header of the light client", slot);
+                };
+                if hash_tree_root(bootstrap.beacon_header().clone())? != finalized.beacon_header_root {
+                    bail!("the bootstrap at slot {} does not match the finalized header of the light client", slot);
+                }
+                Some(finalized.beacon_header_root)
+            }
+            _ => None,
+        };
         let lc = LightClient::new(
             ctx,
-            chain,            
+            chain,
             genesis.genesis_time,
             genesis.genesis_validators_root,
-            None,
         );
 
-        let _ = match self.bootstrap_info {
-            Some(bootstrap_info) => lc.store_boostrap(match serde_json::from_str(&bootstrap_info) {
-                Ok(bootstrap) => bootstrap,
-                Err(e) => {
-                    klave::notifier::send_string(&format!("Invalid bootstrap info: {}", e));
-                    return Ok(());
+        let state = match &self.ticket {
+            // the ticket vouches for the verification done by the query that issued it
+            Some(ticket) => {
+                let ticket: PersistTicket = parse_info(ticket, "persist ticket")?;
+                let Some(expected) = expected else {
+                    bail!("a persist ticket is only accepted with state info");
+                };
+                if bootstrap.is_some() {
+                    bail!("a bootstrap is verified again and is not accepted with a persist ticket");
                 }
-            }),
-            None => Ok(()),
-        };
-        let _ = match self.state_info {
-            Some(state_info) => lc.store_light_client_state(match serde_json::from_str(&state_info) {
-                Ok(state) => state,
-                Err(e) => {
-                    eprintln!("Invalid state info: {}", e);
-                    return Ok(());
+                let prior_state_hash = stored_state.as_ref().map(state_hash).transpose()?;
+                ticket.verify(&EnclaveKey::load()?, prior_state_hash, state_hash(&expected)?, self.info_hash()?)?;
+                // the updates archived with the state are the ones the ticket covers through the info hash
+                Some(expected)
+            }
+            None => {
+                let mut state = match &bootstrap {
+                    Some(bootstrap) => Some(lc.verify_bootstrap(bootstrap, trusted_block_root)?),
+                    None => stored_state.clone(),
+                };
+                if !updates.is_empty() {
+                    let Some(current) = state else {
+                        bail!("light client state not found: the updates must follow a bootstrap");
+                    };
+                    state = Some(lc.verify_updates(current, updates.clone())?);
+                }
+                if let Some(optimistic_update_info) = &self.optimistic_update_info {
+                    let Some(current) = state else {
+                        bail!("light client state not found: the optimistic update must follow a bootstrap");
+                    };
+                    state = Some(lc.verify_optimistic_update(
+                        current,
+                        &parse_info(optimistic_update_info, "optimistic update info")?,
+                    )?);
+                }
+                if expected.is_some() && state != expected {
+                    bail!("state info does not follow from the stored state, the bootstrap and the updates");
                 }
-            }),
-            None => Ok(()),
+                state
+            }
         };
+
+        if let (Some(stored), Some(new)) = (&stored_state, &state) {
+            new.ensure_follows(stored)?;
+        }
+
+        if let Some(bootstrap) = bootstrap {
+            lc.ensure_owner()?;
+            lc.store_boostrap(bootstrap)?;
+        }
+        // the summary of the state the light client is at once persisted
+        let summary = state.as_ref().or(stored_state.as_ref()).map(|state| state.summary());
+        // a state that is already stored has had its updates archived
+        if let Some(state) = state.filter(|state| stored_state.as_ref() != Some(state)) {
+            lc.archive_updates(updates)?;
+            lc.store_light_client_state(state)?;
+        }
         lc.store_genesis(&genesis)?;
-        klave::notifier::send_string("Light client genesis, boostrap and state info persisted");
-        Ok(())
+        Ok(summary)
+    }
+}
+
+impl PersistCommand {
+    /// hash of the infos that a persist ticket covers besides the state
+    pub fn info_hash(&self) -> Result<H256, Error> {
+        ticket::info_hash(&[
+            self.bootstrap_info.as_deref(),
+            self.genesis_info.as_deref(),
+            self.updates_info.as_deref(),
+            self.optimistic_update_info.as_deref(),
+        ])
+    }
+}
+
+/// the genesis must be the one of the network the client is built for
+fn check_genesis<
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+>(
+    ctx: &Context<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES, SYNC_COMMITTEE_SIZE>,
+    genesis: &GenesisData,
+) -> Result<()> {
+    let genesis_version = format!("0x{}", hex::encode(ctx.config.fork_parameters.genesis_version().0));
+    if !genesis.genesis_fork_version.eq_ignore_ascii_case(&genesis_version) {
+        bail!(
+            "genesis fork version {} is not the one of the {:?} network: {}",
+            genesis.genesis_fork_version,
+            ctx.network(),
+            genesis_version
+        );
+    }
+    if genesis.genesis_time < ctx.config.min_genesis_time {
+        bail!(
+            "genesis time {} is before the minimum genesis time of the {:?} network: {}",
+            genesis.genesis_time,
+            ctx.network(),
+            ctx.config.min_genesis_time
+        );
+    }
+    Ok(())
+}
+
+fn parse_info<T: DeserializeOwned>(value: &str, name: &str) -> Result<T> {
+    serde_json::from_str(value).map_err(|e| anyhow!("invalid {}: {}", name, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::light_client_cli::src::testing::{
+        context, genesis, ledger_set, opts, MinimalStore, MinimalSyncCommittee,
+    };
+    use crate::light_client_verifier::src::{
+        consensus::test_utils::{gen_bootstrap, gen_update, minimal_context, MockSyncCommittee},
+        context::LightClientContext,
+    };
+
+    /// a client bootstrapped at slot 8 by the committee of `signer`
+    fn setup(signer: &MinimalSyncCommittee) -> (LightClientContext, MinimalStore) {
+        let ctx = context();
+        let vctx = minimal_context(32);
+        let bootstrap = gen_bootstrap(&vctx, &signer.committee, 8.into());
+        let state = LightClientStore::from_bootstrap(&bootstrap);
+        ctx.store_genesis(&genesis()).unwrap();
+        ctx.store_boostrap(&bootstrap).unwrap();
+        ctx.store_light_client_state(&state).unwrap();
+        (vctx, state)
+    }
+
+    fn stored_slot() -> u64 {
+        context().get_light_client_state().unwrap().latest_finalized_header.slot.0
+    }
+
+    #[test]
+    fn test_persist_updates() {
+        let signer = MockSyncCommittee::new(1);
+        let (vctx, state) = setup(&signer);
+        let update = gen_update(&vctx, &signer, 16.into(), 12.into(), Some(&signer.committee));
+        let new_state = state.apply_light_client_update(&vctx, &update).unwrap().unwrap();
+        let summary = PersistCommand {
+            updates_info: Some(serde_json::to_string(&[&update]).unwrap()),
+            state_info: Some(serde_json::to_string(&new_state).unwrap()),
+            ..Default::default()
+        }
+        .run(context())
+        .unwrap();
+        assert_eq!(summary, Some(new_state.summary()));
+        assert_eq!(stored_slot(), 12);
+        assert_eq!(context().get_archived_update_count().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_forged_state() {
+        let signer = MockSyncCommittee::new(1);
+        let (vctx, state) = setup(&signer);
+        let update = gen_update(&vctx, &signer, 16.into(), 12.into(), Some(&signer.committee));
+        let new_state = state.apply_light_client_update(&vctx, &update).unwrap().unwrap();
+        let mut forged = new_state.clone();
+        forged.next_sync_committee = Some(MockSyncCommittee::new(2).committee);
+
+        // the state derived from the updates is not the one given
+        let persist = PersistCommand {
+            updates_info: Some(serde_json::to_string(&[&update]).unwrap()),
+            state_info: Some(serde_json::to_string(&forged).unwrap()),
+            ..Default::default()
+        };
+        let e = persist.clone().run(context()).unwrap_err();
+        assert!(e.to_string().contains("does not follow"), "{}", e);
+        assert_eq!(stored_slot(), 8);
+
+        // a ticket issued for the derived state does not cover another one
+        EnclaveKey::create().unwrap();
+        let ticket = PersistTicket::issue(
+            &EnclaveKey::load().unwrap(),
+            Some(state_hash(&state).unwrap()),
+            state_hash(&new_state).unwrap(),
+            persist.info_hash().unwrap(),
+        )
+        .unwrap();
+        let persist = PersistCommand {
+            ticket: Some(serde_json::to_string(&ticket).unwrap()),
+            ..persist
+        };
+        assert!(persist.run(context()).is_err());
+        assert_eq!(stored_slot(), 8);
+        assert_eq!(context().get_archived_update_count().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_updates_not_following() {
+        let signer = MockSyncCommittee::new(1);
+        let (vctx, state) = setup(&signer);
+
+        // signed by a committee that is not the one of the stored state
+        let update = gen_update(&vctx, &MockSyncCommittee::new(2), 16.into(), 12.into(), None);
+        let e = PersistCommand {
+            updates_info: Some(serde_json::to_string(&[&update]).unwrap()),
+            ..Default::default()
+        }
+        .run(context())
+        .unwrap_err();
+        assert!(matches!(e.downcast_ref::<Error>(), Some(Error::UpdateRejected(0, _))), "{:?}", e);
+        assert_eq!(stored_slot(), 8);
+
+        // an update that the stored state already follows
+        let update = gen_update(&vctx, &signer, 16.into(), 12.into(), Some(&signer.committee));
+        let new_state = state.apply_light_client_update(&vctx, &update).unwrap().unwrap();
+        context().store_light_client_state(&new_state).unwrap();
+        let e = PersistCommand {
+            updates_info: Some(serde_json::to_string(&[&update]).unwrap()),
+            ..Default::default()
+        }
+        .run(context())
+        .unwrap_err();
+        assert!(matches!(e.downcast_ref::<Error>(), Some(Error::UpdateRejected(0, _))), "{:?}", e);
+        assert_eq!(stored_slot(), 12);
+    }
+
+    #[test]
+    fn test_bootstrap_not_indexed() {
+        let signer = MockSyncCommittee::new(1);
+        let (vctx, _) = setup(&signer);
+        let persist_bootstrap = |slot: u64| {
+            PersistCommand {
+                bootstrap_info: Some(
+                    serde_json::to_string(&gen_bootstrap(&vctx, &signer.committee, slot.into())).unwrap(),
+                ),
+                genesis_info: Some(serde_json::to_string(&genesis()).unwrap()),
+                ..Default::default()
+            }
+            .run(context())
+        };
+
+        let e = persist_bootstrap(16).unwrap_err();
+        assert!(e.to_string().contains("not a finalized header"), "{}", e);
+        assert_eq!(stored_slot(), 8);
+        // the latest finalized header of the client is indexed
+        assert!(persist_bootstrap(8).is_ok());
+        assert_eq!(stored_slot(), 8);
+    }
+
+    #[test]
+    fn test_unreadable_state() {
+        let signer = MockSyncCommittee::new(1);
+        let (vctx, _) = setup(&signer);
+        ledger_set(&opts().ledger_table(), "state", b"{").unwrap();
+
+        // a state that cannot be read is not taken as absent, so no bootstrap replaces it
+        let e = PersistCommand {
+            bootstrap_info: Some(
+                serde_json::to_string(&gen_bootstrap(&vctx, &signer.committee, 16.into())).unwrap(),
+            ),
+            genesis_info: Some(serde_json::to_string(&genesis()).unwrap()),
+            ..Default::default()
+        }
+        .run(context())
+        .unwrap_err();
+        assert!(matches!(e.downcast_ref::<Error>(), Some(Error::SerdeError(_))), "{:?}", e);
+        assert!(context().find_light_client_state().is_err());
     }
 }
@@ -0,0 +1,119 @@
+use super::account::execution_anchor;
+use crate::consensus::src::types::{H256, U64};
+use crate::execution_rpc::src::{
+    client::RPCClient as ExecutionRPCClient,
+    trie::ordered_trie_proof,
+    types::{BlockId, Bytes},
+};
+use crate::light_client_cli::src::context::Context;
+use crate::light_client_verifier::src::{
+    execution::ExecutionVerifier,
+    receipt::{Log, ReceiptOutcome},
+};
+use anyhow::{anyhow, bail, Result};
+use clap::Parser;
+
+#[derive(Clone, Debug, Parser, PartialEq)]
+pub struct ReceiptCommand {
+    #[clap(long = "log", help = "Log to prove: {address, topics, data}")]
+    pub log: String,
+    #[clap(long = "tx_index", help = "Index of the transaction in the block")]
+    pub tx_index: Option<u64>,
+    #[clap(long = "tx_hash", help = "Hash of the transaction, resolved with the execution endpoint")]
+    pub tx_hash: Option<String>,
+    #[clap(long = "log_index", help = "Index of the log in the receipt")]
+    pub log_index: Option<usize>,
+    #[clap(long = "block_number", help = "Block number the proof must be anchored to")]
+    pub block_number: Option<u64>,
+    #[clap(long = "anchor", help = "Header the proof is anchored to: finalized (default) or optimistic")]
+    pub anchor: Option<String>,
+    #[clap(long = "receipt_proof", help = "Receipt trie proof as an array of hex nodes")]
+    pub receipt_proof: Option<String>,
+    #[clap(long = "execution_endpoint", help = "Execution RPC endpoint to fetch the block receipts from")]
+    pub execution_endpoint: Option<String>,
+}
+
+/// VerifiedLog is a log verified against the receipts root of the latest finalized header
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct VerifiedLog {
+    pub log: Log,
+    pub tx_index: u64,
+    /// index of the log in the receipt of the transaction
+    pub log_index: usize,
+    pub tx_type: u8,
+    pub outcome: ReceiptOutcome,
+    pub block_number: U64,
+    pub block_hash: H256,
+    pub receipts_root: H256,
+}
+
+impl ReceiptCommand {
+    pub fn run<
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+    >(
+        self,
+        ctx: Context<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES, SYNC_COMMITTEE_SIZE>,
+    ) -> Result<VerifiedLog> {
+        let log: Log = serde_json::from_str(&self.log)?;
+        let header = execution_anchor(&ctx, self.anchor.as_deref(), self.block_number)?;
+        let receipts_root = header.receipts_root();
+
+        let (tx_index, proof) = match (self.receipt_proof, self.execution_endpoint) {
+            (Some(receipt_proof), _) => {
+                let Some(tx_index) = self.tx_index else {
+                    bail!("tx_index is required with receipt_proof");
+                };
+                let proof: Vec<Bytes> = serde_json::from_str(&receipt_proof)?;
+                (tx_index, proof.into_iter().map(Into::into).collect())
+            }
+            (None, Some(execution_endpoint)) => {
+                let receipts = ExecutionRPCClient::new(execution_endpoint)?
+                    .get_block_receipts(BlockId::Number(header.block_number().0))?
+                    .ok_or_else(|| anyhow!("block not found: {}", header.block_number()))?;
+                let tx_index = match (self.tx_index, self.tx_hash) {
+                    (Some(tx_index), _) => tx_index,
+                    (None, Some(tx_hash)) => {
+                        let tx_hash = H256::from_hex(&tx_hash)?;
+                        receipts
+                            .iter()
+                            .position(|receipt| receipt.transaction_hash == tx_hash)
+                            .ok_or_else(|| anyhow!("transaction not found in the block: {:?}", tx_hash))?
+                            as u64
+                    }
+                    (None, None) => bail!("either tx_index or tx_hash is required"),
+                };
+                // the proof is built from all receipts, so it only verifies if they match the receipts root
+                let values = receipts
+                    .iter()
+                    .map(|receipt| Ok(receipt.to_receipt().map_err(|e| anyhow!(e))?.to_rlp_bytes()))
+                    .collect::<Result<Vec<_>>>()?;
+                let (root, proof) = ordered_trie_proof(&values, tx_index);
+                if root != receipts_root {
+                    bail!(
+                        "receipts of block {} do not match the receipts root: expected={:?} actual={:?}",
+                        header.block_number(),
+                        receipts_root,
+                        root
+                    );
+                }
+                (tx_index, proof)
+            }
+            (None, None) => bail!("either receipt_proof or execution_endpoint is required"),
+        };
+
+        let (receipt, log_index) =
+            ExecutionVerifier.verify_log(receipts_root, tx_index, self.log_index, &log, proof)?;
+        Ok(VerifiedLog {
+            log,
+            tx_index,
+            log_index,
+            tx_type: receipt.tx_type,
+            outcome: receipt.outcome,
+            block_number: header.block_number(),
+            block_hash: header.block_hash(),
+            receipts_root,
+        })
+    }
+}
@@ -0,0 +1,89 @@
+use crate::consensus::src::compute::{compute_sync_committee_period_at_slot, hash_tree_root};
+use crate::light_client_cli::src::{client::trust_level, context::Context};
+use anyhow::Result;
+use clap::Parser;
+use serde_json::{json, Value};
+
+#[derive(Clone, Debug, Parser, PartialEq)]
+pub struct StateCommand {}
+
+impl StateCommand {
+    /// only reads the ledger, the beacon endpoint is never called
+    pub fn run<
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+    >(
+        self,
+        ctx: Context<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES, SYNC_COMMITTEE_SIZE>,
+    ) -> Result<Value> {
+        let state = ctx.get_light_client_state()?;
+        let genesis = ctx.get_genesis()?;
+        let finalized = &state.latest_finalized_header;
+        let execution = &state.latest_execution_payload_header;
+        let summary = state.summary();
+        let period = compute_sync_committee_period_at_slot(&ctx, finalized.slot);
+        let beacon_header_root = hash_tree_root(finalized.clone())?;
+        let trust_level = trust_level();
+
+        Ok(json!({
+            "finalized": {
+                "slot": finalized.slot,
+                "period": period,
+                "beacon_header_root": beacon_header_root,
+                "block_number": execution.block_number(),
+                "block_hash": execution.block_hash(),
+                "state_root": execution.state_root(),
+            },
+            "optimistic_slot": summary.optimistic_slot,
+            "current_sync_committee": {
+                "aggregate_pubkey": state.current_sync_committee.aggregate_pubkey,
+            },
+            "next_sync_committee_known": state.next_sync_committee.is_some(),
+            "next_sync_committee": state.next_sync_committee.as_ref().map(|committee| json!({
+                "aggregate_pubkey": committee.aggregate_pubkey,
+            })),
+            "genesis": genesis,
+            "trust_level": trust_level,
+            "frozen": ctx.get_frozen_state()?,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consensus::src::beacon::Slot;
+    use crate::light_client_cli::src::{
+        state::LightClientStore,
+        testing::{context, genesis, MinimalSyncCommittee},
+    };
+    use crate::light_client_verifier::src::consensus::test_utils::{gen_bootstrap, minimal_context};
+    use crate::light_client_verifier::src::updates::LightClientBootstrap;
+
+    #[test]
+    fn test_state() {
+        // nothing to report before the bootstrap is persisted
+        assert!(StateCommand {}.run(context()).is_err());
+
+        let ctx = context();
+        let vctx = minimal_context(32);
+        let signer = MinimalSyncCommittee::new(1);
+        let bootstrap = gen_bootstrap(&vctx, &signer.committee, 72.into());
+        ctx.store_genesis(&genesis()).unwrap();
+        ctx.store_light_client_state(&LightClientStore::from_bootstrap(&bootstrap)).unwrap();
+
+        let state = StateCommand {}.run(ctx).unwrap();
+        let header_root = hash_tree_root(bootstrap.beacon_header().clone()).unwrap();
+        assert_eq!(state["finalized"]["slot"], json!(Slot::from(72)));
+        assert_eq!(state["finalized"]["period"], json!(1));
+        assert_eq!(state["finalized"]["beacon_header_root"], json!(header_root));
+        assert_eq!(state["finalized"]["block_number"], json!(Slot::from(72)));
+        assert_eq!(state["optimistic_slot"], Value::Null);
+        assert_eq!(state["current_sync_committee"]["aggregate_pubkey"], json!(signer.committee.aggregate_pubkey));
+        assert_eq!(state["next_sync_committee_known"], json!(false));
+        assert_eq!(state["genesis"], json!(genesis()));
+        assert_eq!(state["trust_level"], json!(trust_level()));
+        assert_eq!(state["frozen"], Value::Null);
+    }
+}
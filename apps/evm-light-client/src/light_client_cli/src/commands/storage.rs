@@ -0,0 +1,104 @@
+use super::account::{
+    execution_anchor, fetch_account_proof, parse_address, to_verified_account, VerifiedAccount,
+};
+use crate::consensus::src::types::{Address, H256, U64};
+use crate::execution_rpc::src::types::{parse_storage_key, AccountProof};
+use crate::light_client_cli::src::context::Context;
+use crate::light_client_verifier::src::execution::ExecutionVerifier;
+use anyhow::{anyhow, bail, Result};
+use clap::Parser;
+use std::collections::BTreeMap;
+
+#[derive(Clone, Debug, Parser, PartialEq)]
+pub struct StorageCommand {
+    #[clap(long = "address", help = "Contract address")]
+    pub address: String,
+    #[clap(long = "slot", help = "Storage slots to read")]
+    pub slots: Vec<String>,
+    #[clap(long = "block_number", help = "Block number the proof must be anchored to")]
+    pub block_number: Option<u64>,
+    #[clap(long = "anchor", help = "Header the proof is anchored to: finalized (default) or optimistic")]
+    pub anchor: Option<String>,
+    #[clap(long = "account_proof", help = "eth_getProof response including the storage proofs")]
+    pub account_proof: Option<String>,
+    #[clap(long = "execution_endpoint", help = "Execution RPC endpoint to fetch the proof from")]
+    pub execution_endpoint: Option<String>,
+}
+
+/// VerifiedStorage is a set of storage slots verified against the storage root of a verified account
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct VerifiedStorage {
+    pub account: VerifiedAccount,
+    /// slot to value, where a slot that doesn't exist is zero
+    pub storage: BTreeMap<H256, H256>,
+}
+
+impl StorageCommand {
+    pub fn run<
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+    >(
+        self,
+        ctx: Context<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES, SYNC_COMMITTEE_SIZE>,
+    ) -> Result<VerifiedStorage> {
+        let address = parse_address(&self.address)?;
+        let slots = parse_slots(&self.slots)?;
+        let header = execution_anchor(&ctx, self.anchor.as_deref(), self.block_number)?;
+        let (block_number, state_root) = (header.block_number(), header.state_root());
+        let proof = fetch_account_proof(
+            &address,
+            &slots,
+            block_number,
+            self.account_proof,
+            self.execution_endpoint,
+        )?;
+        verify_storage(&address, &slots, &proof, state_root, block_number)
+    }
+}
+
+/// every requested slot is returned, so a slot that is invalid or repeated fails the whole request
+fn parse_slots(slots: &[String]) -> Result<Vec<H256>> {
+    let mut parsed = Vec::with_capacity(slots.len());
+    let mut invalid = Vec::new();
+    for slot in slots {
+        match parse_storage_key(slot) {
+            Ok(key) if parsed.contains(&key) => bail!("duplicate storage slot: {}", slot),
+            Ok(key) => parsed.push(key),
+            Err(_) => invalid.push(slot.as_str()),
+        }
+    }
+    if !invalid.is_empty() {
+        return Err(anyhow!("invalid storage slots: {}", invalid.join(", ")));
+    }
+    Ok(parsed)
+}
+
+/// Verifies the account proof against `state_root`, and then the storage proofs of `slots` against the account's storage root
+pub(crate) fn verify_storage(
+    address: &Address,
+    slots: &[H256],
+    proof: &AccountProof,
+    state_root: H256,
+    block_number: U64,
+) -> Result<VerifiedStorage> {
+    let storage_proofs = slots
+        .iter()
+        .map(|slot| {
+            match proof.storage_proof.iter().find(|storage_proof| &storage_proof.key == slot) {
+                Some(storage_proof) => Ok((*slot, storage_proof.proof_nodes())),
+                None => bail!("storage proof not found: slot=0x{}", hex::encode(slot.0)),
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let (account, storage) = ExecutionVerifier.verify_account_storage(
+        state_root,
+        address,
+        proof.account_proof_nodes(),
+        storage_proofs,
+    )?;
+    Ok(VerifiedStorage {
+        account: to_verified_account(address, account, state_root, block_number),
+        storage,
+    })
+}
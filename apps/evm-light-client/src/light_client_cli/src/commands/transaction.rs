@@ -0,0 +1,170 @@
+use super::account::anchor_headers;
+use crate::consensus::src::{
+    beacon::Slot,
+    fork::deneb::prover::gen_transaction_proof,
+    preset::{mainnet, minimal},
+    types::H256,
+};
+use crate::execution_rpc::src::types::Bytes;
+use crate::light_client_cli::src::{chain::Network, context::Context};
+use crate::light_client_verifier::src::{
+    execution::ExecutionVerifier,
+    transaction::{transaction_hash, Transaction},
+};
+use crate::lodestar_rpc::src::client::RPCClient;
+use anyhow::{anyhow, bail, Result};
+use clap::Parser;
+use serde_json::{json, Value};
+
+#[derive(Clone, Debug, Parser, PartialEq)]
+pub struct TransactionCommand {
+    #[clap(long = "tx_hash", help = "Hash of the transaction")]
+    pub tx_hash: Option<String>,
+    #[clap(long = "tx_index", help = "Index of the transaction in the execution payload")]
+    pub tx_index: Option<u64>,
+    #[clap(long = "block_number", help = "Block number the proof must be anchored to")]
+    pub block_number: Option<u64>,
+    #[clap(long = "anchor", help = "Header the proof is anchored to: finalized (default) or optimistic")]
+    pub anchor: Option<String>,
+    #[clap(long = "transaction", help = "Transaction envelope")]
+    pub transaction: Option<String>,
+    #[clap(long = "transaction_proof", help = "Merkle branch of the transaction against the transactions root")]
+    pub transaction_proof: Option<String>,
+}
+
+impl TransactionCommand {
+    pub fn run<
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+    >(
+        self,
+        ctx: Context<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES, SYNC_COMMITTEE_SIZE>,
+    ) -> Result<Value> {
+        let (beacon, header) = anchor_headers(&ctx, self.anchor.as_deref(), self.block_number)?;
+        let tx_hash = self.tx_hash.as_deref().map(H256::from_hex).transpose()?;
+
+        let (tx_index, transaction, branch) = match (self.transaction, self.transaction_proof) {
+            (Some(transaction), Some(transaction_proof)) => {
+                let Some(tx_index) = self.tx_index else {
+                    bail!("tx_index is required with transaction_proof");
+                };
+                let transaction: Bytes = serde_json::from_value(json!(transaction))?;
+                let branch: Vec<H256> = serde_json::from_str(&transaction_proof)?;
+                (tx_index, transaction.to_vec(), branch)
+            }
+            (None, None) => {
+                if ctx.beacon_endpoint().is_empty() {
+                    bail!("either transaction_proof or beacon_endpoint is required");
+                }
+                // the proof is built from the full payload of the anchored beacon block
+                let client = RPCClient::new(ctx.beacon_endpoint())?;
+                match ctx.network() {
+                    Network::Minimal => prove_transaction::<
+                        BYTES_PER_LOGS_BLOOM,
+                        MAX_EXTRA_DATA_BYTES,
+                        { minimal::PRESET.MAX_BYTES_PER_TRANSACTION },
+                        { minimal::PRESET.MAX_TRANSACTIONS_PER_PAYLOAD },
+                        { minimal::PRESET.MAX_WITHDRAWALS_PER_PAYLOAD },
+                    >(&client, beacon.slot, self.tx_index, tx_hash)?,
+                    Network::Mainnet | Network::Holesky | Network::Sepolia => prove_transaction::<
+                        BYTES_PER_LOGS_BLOOM,
+                        MAX_EXTRA_DATA_BYTES,
+                        { mainnet::PRESET.MAX_BYTES_PER_TRANSACTION },
+                        { mainnet::PRESET.MAX_TRANSACTIONS_PER_PAYLOAD },
+                        { mainnet::PRESET.MAX_WITHDRAWALS_PER_PAYLOAD },
+                    >(&client, beacon.slot, self.tx_index, tx_hash)?,
+                }
+            }
+            _ => bail!("transaction and transaction_proof must be given together"),
+        };
+
+        let preset = ctx.preset();
+        ExecutionVerifier.verify_transaction(
+            header.transactions_root(),
+            tx_index,
+            &transaction,
+            &branch,
+            preset.MAX_BYTES_PER_TRANSACTION,
+            preset.MAX_TRANSACTIONS_PER_PAYLOAD,
+        )?;
+        let hash = transaction_hash(&transaction);
+        if let Some(tx_hash) = tx_hash {
+            if tx_hash != hash {
+                bail!("transaction hash mismatch: expected={:?} actual={:?}", tx_hash, hash);
+            }
+        }
+
+        let tx = Transaction::from_envelope(&transaction)?;
+        Ok(json!({
+            "hash": hash,
+            "tx_index": tx_index,
+            "block_number": header.block_number(),
+            "block_hash": header.block_hash(),
+            "transactions_root": header.transactions_root(),
+            "transaction": {
+                "type": tx.tx_type,
+                "chain_id": tx.chain_id,
+                "nonce": tx.nonce,
+                "gas_price": tx.gas_price.map(|v| format!("0x{:x}", v)),
+                "max_priority_fee_per_gas": tx.max_priority_fee_per_gas.map(|v| format!("0x{:x}", v)),
+                "max_fee_per_gas": tx.max_fee_per_gas.map(|v| format!("0x{:x}", v)),
+                "max_fee_per_blob_gas": tx.max_fee_per_blob_gas.map(|v| format!("0x{:x}", v)),
+                "gas_limit": tx.gas_limit,
+                "to": tx.to,
+                "value": format!("0x{:x}", tx.value),
+                "input": format!("0x{}", hex::encode(&tx.input)),
+                "access_list": tx.access_list.iter().map(|item| json!({
+                    "address": item.address,
+                    "storage_keys": item.storage_keys,
+                })).collect::<Vec<_>>(),
+                "blob_versioned_hashes": tx.blob_versioned_hashes,
+                "authorization_list": tx.authorization_list.iter().map(|auth| json!({
+                    "chain_id": format!("0x{:x}", auth.chain_id),
+                    "address": auth.address,
+                    "nonce": auth.nonce,
+                    "y_parity": auth.y_parity,
+                    "r": format!("0x{:x}", auth.r),
+                    "s": format!("0x{:x}", auth.s),
+                })).collect::<Vec<_>>(),
+                "v": tx.v,
+                "r": format!("0x{:x}", tx.r),
+                "s": format!("0x{:x}", tx.s),
+            },
+        }))
+    }
+}
+
+/// the transaction at `tx_index`, or with `tx_hash`, in the payload of the block at `slot`, with its proof.
+/// The payload is decoded with the limits of the preset of the network
+fn prove_transaction<
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const MAX_BYTES_PER_TRANSACTION: usize,
+    const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+>(
+    client: &RPCClient,
+    slot: Slot,
+    tx_index: Option<u64>,
+    tx_hash: Option<H256>,
+) -> Result<(u64, Vec<u8>, Vec<H256>)> {
+    let block = client.get_beacon_block_payload_by_slot::<
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+    >(slot)?;
+    let transactions = &block.execution_payload().transactions;
+    let tx_index = match (tx_index, tx_hash) {
+        (Some(tx_index), _) => tx_index as usize,
+        (None, Some(tx_hash)) => transactions
+            .iter()
+            .position(|tx| transaction_hash(tx.as_ref()) == tx_hash)
+            .ok_or_else(|| anyhow!("transaction not found in slot {}: {:?}", slot, tx_hash))?,
+        (None, None) => bail!("either tx_index or tx_hash is required"),
+    };
+    let (_, branch) = gen_transaction_proof(transactions, tx_index)?;
+    Ok((tx_index as u64, transactions[tx_index].as_ref().to_vec(), branch))
+}
@@ -0,0 +1,141 @@
+use crate::light_client_cli::src::{
+    acl::{sender, Role},
+    context::Context,
+    errors::Error,
+    state::FrozenState,
+};
+use anyhow::{bail, Result};
+use clap::Parser;
+
+#[derive(Clone, Debug, Parser, PartialEq)]
+pub struct UnfreezeCommand {}
+
+impl UnfreezeCommand {
+    pub fn run<
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+    >(
+        self,
+        ctx: Context<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES, SYNC_COMMITTEE_SIZE>,
+    ) -> Result<FrozenState> {
+        // unlike the other routes, an unfreeze is never open to anyone: it needs an admin of a stored acl
+        match ctx.get_acl()? {
+            Some(acl) => acl.authorize(&sender()?, Role::Admin)?,
+            None => return Err(Error::Unauthorized(sender()?, Role::Admin).into()),
+        }
+        let Some(frozen) = ctx.get_frozen_state()? else {
+            bail!("light client is not frozen");
+        };
+        // the evidence is not kept once the client is unfrozen, so it is returned to the caller
+        ctx.store_frozen_state(None)?;
+        Ok(frozen)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::light_client_cli::src::{
+        acl::Acl,
+        commands::{
+            AccountCommand, CommitCommand, InitCommand, OptimisticUpdateCommand, PersistCommand, StorageCommand,
+            UpdateCommand,
+        },
+        testing::{context, set_sender},
+    };
+
+    fn freeze() -> FrozenState {
+        let frozen = FrozenState {
+            store_slot: 8.into(),
+            misbehaviour_slot: 16.into(),
+            kind: "next_sync_committee".into(),
+            frozen_at: 1.into(),
+            evidence: serde_json::Value::Null,
+        };
+        context().store_frozen_state(Some(&frozen)).unwrap();
+        frozen
+    }
+
+    fn assert_frozen<T: std::fmt::Debug>(result: Result<T>) {
+        let e = result.unwrap_err();
+        assert!(matches!(e.downcast_ref::<Error>(), Some(Error::Frozen(..))), "{:?}", e);
+    }
+
+    fn assert_unauthorized<T: std::fmt::Debug>(result: Result<T>) {
+        let e = result.unwrap_err();
+        assert!(matches!(e.downcast_ref::<Error>(), Some(Error::Unauthorized(..))), "{:?}", e);
+    }
+
+    #[test]
+    fn test_frozen_refusals() {
+        freeze();
+        // the writes are refused
+        assert_frozen(
+            InitCommand {
+                trusted_block_root: None,
+                untrusted_slot: None,
+            }
+            .run(context()),
+        );
+        assert_frozen(PersistCommand::default().run(context()));
+        assert_frozen(
+            UpdateCommand {
+                target: None,
+                secondary_beacon_endpoints: Vec::new(),
+            }
+            .run(context()),
+        );
+        assert_frozen(
+            CommitCommand {
+                expected_finalized_slot: 8,
+                updates_info: None,
+                secondary_beacon_endpoints: Vec::new(),
+            }
+            .run(context()),
+        );
+        assert_frozen(OptimisticUpdateCommand {}.run(context()));
+        // and so are the verified reads, before any proof is fetched
+        let address = format!("0x{}", "11".repeat(20));
+        assert_frozen(
+            AccountCommand {
+                address: address.clone(),
+                block_number: None,
+                anchor: None,
+                account_proof: None,
+                execution_endpoint: None,
+            }
+            .run(context()),
+        );
+        assert_frozen(
+            StorageCommand {
+                address,
+                slots: vec![format!("0x{}", "00".repeat(32))],
+                block_number: None,
+                anchor: None,
+                account_proof: None,
+                execution_endpoint: None,
+            }
+            .run(context()),
+        );
+    }
+
+    #[test]
+    fn test_unfreeze() {
+        let frozen = freeze();
+        // no admin can unfreeze a client without an acl
+        assert_unauthorized(UnfreezeCommand {}.run(context()));
+
+        let mut acl = Acl::new("owner".into());
+        acl.add_member("owner", "updater".into(), Role::Updater).unwrap();
+        context().store_acl(&acl).unwrap();
+        set_sender("updater");
+        assert_unauthorized(UnfreezeCommand {}.run(context()));
+        assert_eq!(context().get_frozen_state().unwrap(), Some(frozen.clone()));
+
+        set_sender("owner");
+        assert_eq!(UnfreezeCommand {}.run(context()).unwrap(), frozen);
+        assert_eq!(context().get_frozen_state().unwrap(), None);
+        assert!(UnfreezeCommand {}.run(context()).is_err());
+    }
+}
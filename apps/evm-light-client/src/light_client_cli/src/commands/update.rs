@@ -1,15 +1,29 @@
+use super::PersistCommand;
 use crate::light_client_cli::src::{
     chain::Chain,
     client::{LightClient, Target},
     context::Context,
 };
+use crate::lodestar_rpc::src::client::EndpointHealth;
 use anyhow::Result;
 use clap::Parser;
+use log::*;
 
 #[derive(Clone, Debug, Parser, PartialEq)]
 pub struct UpdateCommand {
     #[clap(long = "target")]
     pub target: Option<String>,
+    #[clap(long = "secondary_beacon_endpoint", help = "Beacon endpoints the updates are cross-checked against")]
+    pub secondary_beacon_endpoints: Vec<String>,
+}
+
+/// UpdateOutput is the result of an update: the state to persist, none if no update was applied,
+/// and the health of the beacon endpoints when more than one is configured
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize)]
+pub struct UpdateOutput {
+    pub persist: Option<PersistCommand>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub endpoints: Vec<EndpointHealth>,
 }
 
 impl UpdateCommand {
@@ -20,8 +34,11 @@ impl UpdateCommand {
     >(
         self,
         ctx: Context<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES, SYNC_COMMITTEE_SIZE>,
-    ) -> Result<()> {
-        let chain = Chain::new(ctx.beacon_endpoint());
+    ) -> Result<UpdateOutput> {
+        ctx.ensure_not_frozen()?;
+        let genesis = ctx.get_genesis()?;
+        let fork_digests = ctx.fork_digests(genesis.genesis_validators_root);
+        let chain = Chain::new(ctx.beacon_endpoint())?.with_fork_digests(fork_digests.clone());
         let target = if let Some(target) = self.target {
             Target::from_string(&ctx, &target)?
             // Target::None
@@ -29,18 +46,30 @@ impl UpdateCommand {
             Target::None
         };
 
-        let genesis = ctx.get_genesis()?;
         let lc = LightClient::new(
             ctx,
             chain,
             genesis.genesis_time,
             genesis.genesis_validators_root,
-            None,
-        );
+        )
+        .with_secondary_chains(Chain::secondary_chains(
+            &self.secondary_beacon_endpoints,
+            &fork_digests,
+        ));
         
-        let _finished = lc
-            .update_until_target(target.clone())
-            ?;
-        Ok(())
+        lc.check_endpoints();
+        let result = lc.update_until_target(target.clone());
+        let mut endpoints = lc.endpoint_health();
+        if endpoints.len() <= 1 {
+            endpoints.clear();
+        }
+        if let Err(e) = result {
+            debug!("update failed: endpoints={:?}", endpoints);
+            return Err(e.into());
+        }
+        Ok(UpdateOutput {
+            persist: lc.applied_persist_command()?,
+            endpoints,
+        })
     }
 }
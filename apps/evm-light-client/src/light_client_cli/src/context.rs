@@ -1,19 +1,29 @@
 use super::{
-    chain::Network, 
+    acl::{sender, Acl, Role},
+    chain::{consensus_update_info, Network}, 
     cli::Opts,
     db::{FileDB, DB},
     errors::Error,
-    state::LightClientStore
+    state::{FinalizedHeader, FrozenState, LightClientStore},
 };
 use crate::consensus::src::{
+    beacon::{ForkDigest, Root, Slot},
+    compute::{compute_fork_digest, hash_tree_root},
     config::Config,
     context::ChainContext,
-    fork::deneb::LightClientBootstrap
+    fork::{CAPELLA_INDEX, DENEB_INDEX, ELECTRA_INDEX, FULU_INDEX},
+    preset::Preset,
 };
-use crate::lodestar_rpc::src::types::GenesisData;
+use crate::light_client_verifier::src::updates::versioned::{
+    ConsensusUpdateInfo, LightClientBootstrapInfo,
+};
+use crate::lodestar_rpc::src::{ssz, types::GenesisData};
 use log::*;
 use std::str::FromStr;
 
+/// the access control list is shared by the clients of all the networks
+const ACL_TABLE: &str = "light_client_acl";
+
 #[derive(Debug)]
 pub struct Context<
     const BYTES_PER_LOGS_BLOOM: usize,
@@ -24,6 +34,9 @@ pub struct Context<
     pub(crate) beacon_endpoint: String,
     pub(crate) network: Network,
     db: FileDB,
+    /// the consensus updates applied since the bootstrap, in order
+    archive: FileDB,
+    acl: FileDB,
 }
 
 impl<
@@ -36,7 +49,9 @@ impl<
         let home_dir = opts.ledger_table();
         Ok(Self {
             config: network.config(),
+            archive: FileDB::open(format!("{}_updates", home_dir))?,
             db: FileDB::open(home_dir)?,
+            acl: FileDB::open(ACL_TABLE.into())?,
             beacon_endpoint: opts.beacon_endpoint,
             network: Network::from_str(&opts.network)?,
         })
@@ -50,23 +65,58 @@ impl<
         self.network.clone()
     }
 
+    pub fn preset(&self) -> &Preset {
+        &self.config.preset
+    }
+
+    /// fork digests of the forks whose light client updates can be decoded from SSZ.
+    /// Since fulu, the digest also changes with each entry of the blob schedule, so fulu has one digest per entry.
+    pub fn fork_digests(&self, genesis_validators_root: Root) -> Vec<(ForkDigest, String)> {
+        let forks = self.fork_parameters().forks();
+        let mut digests: Vec<_> = [(CAPELLA_INDEX, "capella"), (DENEB_INDEX, "deneb"), (ELECTRA_INDEX, "electra")]
+            .into_iter()
+            .filter_map(|(index, version)| Some((forks.get(index)?, None, version)))
+            .collect();
+        if let Some(fulu) = forks.get(FULU_INDEX) {
+            let epochs = core::iter::once(fulu.epoch).chain(
+                self.config
+                    .blob_schedule
+                    .iter()
+                    .map(|blob_parameters| blob_parameters.epoch)
+                    .filter(|epoch| *epoch > fulu.epoch),
+            );
+            digests.extend(epochs.map(|epoch| (fulu, self.config.blob_parameters(epoch), "fulu")));
+        }
+        digests
+            .into_iter()
+            .filter_map(|(fork, blob_parameters, version)| {
+                let digest =
+                    compute_fork_digest(fork.version.clone(), genesis_validators_root, blob_parameters).ok()?;
+                Some((digest, version.to_string()))
+            })
+            .collect()
+    }
+
     /// Store accessors
     pub fn get_bootstrap(
         &self,
     ) -> Result<
-        LightClientBootstrap<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        LightClientBootstrapInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
         Error,
     > {
-        Ok(serde_json::from_slice(&self.db.get("bootstrap")?.ok_or(
-            Error::Other {
-                description: "bootstrap not found".into(),
-            },
-        )?)?)
+        let value = self.db.get("bootstrap")?.ok_or(Error::Other {
+            description: "bootstrap not found".into(),
+        })?;
+        match serde_json::from_slice(&value) {
+            Ok(bootstrap) => Ok(bootstrap),
+            // bootstraps persisted before fork-aware decoding are bare deneb bootstraps
+            Err(_) => Ok(LightClientBootstrapInfo::Deneb(serde_json::from_slice(&value)?)),
+        }
     }
 
     pub fn store_boostrap(
         &self,
-        bootstrap: &LightClientBootstrap<
+        bootstrap: &LightClientBootstrapInfo<
             SYNC_COMMITTEE_SIZE,
             BYTES_PER_LOGS_BLOOM,
             MAX_EXTRA_DATA_BYTES,
@@ -82,6 +132,8 @@ impl<
             }
         };
         self.db.put("bootstrap", value)?;
+        // the archive is replayed from the bootstrap, so a new bootstrap starts a new archive
+        self.archive.put("count", "0")?;
         Ok(())
     }
 
@@ -91,11 +143,22 @@ impl<
         LightClientStore<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
         Error,
     > {
-        Ok(serde_json::from_slice(&self.db.get("state")?.ok_or(
-            Error::Other {
-                description: "light_client_state not found".into(),
-            },
-        )?)?)
+        self.find_light_client_state()?.ok_or(Error::Other {
+            description: "light_client_state not found".into(),
+        })
+    }
+
+    /// returns None only if no state is stored, a state that cannot be read is an error
+    pub fn find_light_client_state(
+        &self,
+    ) -> Result<
+        Option<LightClientStore<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>,
+        Error,
+    > {
+        match self.db.get("state")? {
+            Some(value) => Ok(Some(serde_json::from_slice(&value)?)),
+            None => Ok(None),
+        }
     }
 
     pub fn store_light_client_state(
@@ -112,6 +175,118 @@ impl<
             }
         };
         self.db.put("state", value)?;
+        // the access control list is shared, so it must know that a client of any network has a state
+        self.acl.put("state_stored", "true")?;
+        self.index_finalized_header(state)
+    }
+
+    /// the finalized headers are indexed by slot and by block number, and never overwritten
+    fn index_finalized_header(
+        &self,
+        state: &LightClientStore<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    ) -> Result<(), Error> {
+        let beacon = &state.latest_finalized_header;
+        if self.get_finalized_header_at_slot(beacon.slot)?.is_some() {
+            return Ok(());
+        }
+        let header = FinalizedHeader {
+            beacon_header_root: hash_tree_root(beacon.clone())?,
+            beacon: beacon.clone(),
+            execution: state.latest_execution_payload_header.clone(),
+        };
+        self.db.put(
+            format!("finalized_header_slot_{}", beacon.slot),
+            serde_json::to_string(&header)?,
+        )?;
+        self.db.put(
+            format!("finalized_header_block_{}", header.execution.block_number()),
+            beacon.slot.to_string(),
+        )?;
+        Ok(())
+    }
+
+    pub fn get_finalized_header_at_slot(
+        &self,
+        slot: Slot,
+    ) -> Result<Option<FinalizedHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>, Error> {
+        match self.db.get(format!("finalized_header_slot_{}", slot))? {
+            Some(value) => Ok(Some(serde_json::from_slice(&value)?)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn get_finalized_header_at_block_number(
+        &self,
+        block_number: u64,
+    ) -> Result<Option<FinalizedHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>, Error> {
+        let slot = match self.db.get(format!("finalized_header_block_{}", block_number))? {
+            Some(value) => String::from_utf8_lossy(&value)
+                .parse::<u64>()
+                .map_err(|e| Error::Other {
+                    description: format!("invalid finalized header index: {}", e),
+                })?,
+            None => return Ok(None),
+        };
+        self.get_finalized_header_at_slot(slot.into())
+    }
+
+    /// number of the archived consensus updates
+    pub fn get_archived_update_count(&self) -> Result<u64, Error> {
+        match self.archive.get("count")? {
+            Some(value) => String::from_utf8_lossy(&value)
+                .parse()
+                .map_err(|e| Error::Other {
+                    description: format!("invalid archived update count: {}", e),
+                }),
+            None => Ok(0),
+        }
+    }
+
+    /// decodes the archived update with the fork it was encoded with
+    pub fn get_archived_update(
+        &self,
+        index: u64,
+    ) -> Result<
+        ConsensusUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        Error,
+    > {
+        let not_found = || Error::Other {
+            description: format!("archived update {} not found", index),
+        };
+        let version = self.archive.get(format!("version_{}", index))?.ok_or_else(not_found)?;
+        let bz = self.archive.get(format!("update_{}", index))?.ok_or_else(not_found)?;
+        Ok(consensus_update_info(ssz::decode_update(
+            &String::from_utf8_lossy(&version),
+            &bz,
+        )?))
+    }
+
+    /// appends the SSZ encoded updates and their fork to the archive, the count is written last
+    pub fn archive_updates(
+        &self,
+        updates: &[ConsensusUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>],
+    ) -> Result<(), Error> {
+        let count = self.get_archived_update_count()?;
+        for (index, update) in (count..).zip(updates) {
+            let (version, bz) = match update {
+                ConsensusUpdateInfo::Capella(update) => (
+                    "capella",
+                    ssz::encode(&ssz::capella::LightClientUpdate::try_from(update.0.clone())?)?,
+                ),
+                ConsensusUpdateInfo::Deneb(update) => (
+                    "deneb",
+                    ssz::encode(&ssz::deneb::LightClientUpdate::try_from(update.0.clone())?)?,
+                ),
+                ConsensusUpdateInfo::Electra(update) => (
+                    "electra",
+                    ssz::encode(&ssz::electra::LightClientUpdate::try_from(update.0.clone())?)?,
+                ),
+            };
+            self.archive.put(format!("version_{}", index), version)?;
+            self.archive.put(format!("update_{}", index), bz)?;
+        }
+        self.archive
+            .put("count", (count + updates.len() as u64).to_string())?;
         Ok(())
     }
 
@@ -145,6 +320,80 @@ impl<
         self.db.put("genesis", value)?;
         Ok(())
     }
+
+    /// returns None if the client has never been frozen or has been unfrozen
+    pub fn get_frozen_state(&self) -> Result<Option<FrozenState>, Error> {
+        // a failure to read the flag must not let a frozen client run
+        match self.db.get("frozen")? {
+            Some(value) => Ok(serde_json::from_slice(&value)?),
+            None => Ok(None),
+        }
+    }
+
+    /// `None` unfreezes the client
+    pub fn store_frozen_state(&self, frozen: Option<&FrozenState>) -> Result<(), Error> {
+        self.db.put("frozen", serde_json::to_string(&frozen)?)?;
+        Ok(())
+    }
+
+    pub fn ensure_not_frozen(&self) -> Result<(), Error> {
+        match self.get_frozen_state()? {
+            Some(frozen) => Err(Error::Frozen(frozen.misbehaviour_slot, frozen.kind)),
+            None => Ok(()),
+        }
+    }
+
+    /// returns None until the first owner is set
+    pub fn get_acl(&self) -> Result<Option<Acl>, Error> {
+        match self.acl.get("acl")? {
+            Some(value) => Ok(Some(serde_json::from_slice(&value)?)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn store_acl(&self, acl: &Acl) -> Result<(), Error> {
+        self.acl.put("acl", serde_json::to_string(acl)?)?;
+        Ok(())
+    }
+
+    /// the sender of the first bootstrap becomes the owner, unless a state was stored without an owner
+    pub fn ensure_owner(&self) -> Result<(), Error> {
+        if self.get_acl()?.is_none() && !self.has_state()? {
+            self.store_acl(&Acl::new(sender()?))?;
+        }
+        Ok(())
+    }
+
+    /// makes the sender the owner of a ledger without an acl. A ledger whose state was stored before the acl existed
+    /// can only be claimed by `legacy_owner`, the identity the app was built with for the migration
+    pub fn claim_owner(&self, legacy_owner: Option<&str>) -> Result<Acl, Error> {
+        let sender = sender()?;
+        if self.get_acl()?.is_some() {
+            return Err(Error::Other {
+                description: "the access control list already has an owner".into(),
+            });
+        }
+        if self.has_state()? && legacy_owner != Some(sender.as_str()) {
+            return Err(Error::Unauthorized(sender, Role::Owner));
+        }
+        let acl = Acl::new(sender);
+        self.store_acl(&acl)?;
+        Ok(acl)
+    }
+
+    /// checks that the sender has `role`. Without an owner, only reads are allowed once a state is stored
+    pub fn authorize(&self, role: Role) -> Result<(), Error> {
+        match self.get_acl()? {
+            Some(acl) => acl.authorize(&sender()?, role),
+            None if role > Role::Reader && self.has_state()? => Err(Error::Unauthorized(sender()?, role)),
+            None => Ok(()),
+        }
+    }
+
+    /// whether a state was ever stored, on any network
+    fn has_state(&self) -> Result<bool, Error> {
+        Ok(self.acl.get("state_stored")?.is_some() || self.db.get("state")?.is_some())
+    }
 }
 
 impl<
@@ -173,3 +422,107 @@ impl<
         self.config.preset.EPOCHS_PER_SYNC_COMMITTEE_PERIOD
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::consensus::src::compute::hash_tree_root;
+    use crate::light_client_cli::src::{
+        acl::{Acl, Role},
+        state::LightClientStore,
+        testing::{context, ledger_set, opts, set_sender},
+    };
+    use crate::light_client_verifier::src::consensus::test_utils::{
+        gen_bootstrap, gen_update, minimal_context, MockSyncCommittee,
+    };
+
+    #[test]
+    fn test_archive_round_trip() {
+        let ctx = context();
+        let vctx = minimal_context(32);
+        let signer = MockSyncCommittee::new(1);
+        let updates = vec![
+            gen_update(&vctx, &signer, 16.into(), 12.into(), Some(&signer.committee)),
+            gen_update(&vctx, &signer, 24.into(), 20.into(), None),
+        ];
+        assert_eq!(ctx.get_archived_update_count().unwrap(), 0);
+
+        ctx.archive_updates(&updates[..1]).unwrap();
+        ctx.archive_updates(&updates[1..]).unwrap();
+        assert_eq!(ctx.get_archived_update_count().unwrap(), 2);
+        for (index, update) in (0..).zip(&updates) {
+            assert_eq!(&ctx.get_archived_update(index).unwrap(), update);
+        }
+        assert!(ctx.get_archived_update(2).is_err());
+    }
+
+    #[test]
+    fn test_finalized_header_index() {
+        let ctx = context();
+        let vctx = minimal_context(32);
+        let signer = MockSyncCommittee::new(1);
+        let state = LightClientStore::from_bootstrap(&gen_bootstrap(&vctx, &signer.committee, 8.into()));
+        let update = gen_update(&vctx, &signer, 16.into(), 12.into(), Some(&signer.committee));
+        let new_state = state.apply_light_client_update(&vctx, &update).unwrap().unwrap();
+        ctx.store_light_client_state(&state).unwrap();
+        ctx.store_light_client_state(&new_state).unwrap();
+
+        // the block number of a generated header is its slot
+        for state in [&state, &new_state] {
+            let slot = state.latest_finalized_header.slot;
+            let header = ctx.get_finalized_header_at_slot(slot).unwrap().unwrap();
+            assert_eq!(header.beacon, state.latest_finalized_header);
+            assert_eq!(header.execution, state.latest_execution_payload_header);
+            assert_eq!(header.beacon_header_root, hash_tree_root(header.beacon.clone()).unwrap());
+            assert_eq!(ctx.get_finalized_header_at_block_number(slot.0).unwrap(), Some(header));
+        }
+        assert_eq!(ctx.get_finalized_header_at_slot(10.into()).unwrap(), None);
+        assert_eq!(ctx.get_finalized_header_at_block_number(10).unwrap(), None);
+
+        // an index entry that cannot be read is an error, not a missing header
+        ledger_set(&opts().ledger_table(), "finalized_header_slot_8", b"{").unwrap();
+        assert!(ctx.get_finalized_header_at_slot(8.into()).is_err());
+        assert!(ctx.get_finalized_header_at_block_number(8).is_err());
+    }
+
+    #[test]
+    fn test_owner() {
+        // the sender of the first bootstrap becomes the owner, the later senders do not
+        let ctx = context();
+        set_sender("owner");
+        ctx.ensure_owner().unwrap();
+        set_sender("other");
+        ctx.ensure_owner().unwrap();
+        assert_eq!(ctx.get_acl().unwrap(), Some(Acl::new("owner".into())));
+        assert!(ctx.authorize(Role::Updater).is_err());
+        assert!(ctx.claim_owner(None).is_err());
+    }
+
+    #[test]
+    fn test_claim_owner() {
+        // a fresh ledger can be claimed before its first bootstrap
+        let ctx = context();
+        set_sender("owner");
+        assert_eq!(ctx.claim_owner(None).unwrap(), Acl::new("owner".into()));
+        assert!(ctx.claim_owner(None).is_err());
+    }
+
+    #[test]
+    fn test_claim_legacy_owner() {
+        // a state stored before the acl existed
+        let ctx = context();
+        ctx.store_light_client_state(&Default::default()).unwrap();
+        set_sender("owner");
+        ctx.ensure_owner().unwrap();
+        assert_eq!(ctx.get_acl().unwrap(), None);
+        assert!(ctx.authorize(Role::Reader).is_ok());
+        assert!(ctx.authorize(Role::Updater).is_err());
+
+        // only the legacy owner the app was built with can claim it
+        assert!(ctx.claim_owner(None).is_err());
+        assert!(ctx.claim_owner(Some("legacy")).is_err());
+        set_sender("legacy");
+        assert_eq!(ctx.claim_owner(Some("legacy")).unwrap(), Acl::new("legacy".into()));
+        assert!(ctx.authorize(Role::Admin).is_ok());
+        assert!(ctx.claim_owner(Some("legacy")).is_err());
+    }
+}
@@ -1,5 +1,6 @@
 use super::errors::Error;
-use klave;
+#[cfg(test)]
+use super::testing::{ledger_get, ledger_set};
 
 pub trait DB {
     fn get<K>(&self, key: K) -> Result<Option<Vec<u8>>, Error>
@@ -30,9 +31,11 @@ impl DB for FileDB {
     where
         K: Into<String>,
     {
-        match klave::ledger::get_table(self.store_table.as_str()).get(key.into().as_str()) {
-            Ok(v) => Ok(Some(v.into())),
-            Err(e) => return Err(Error::Other { description: e.to_string() })
+        // the ledger reads a missing key as an empty value, any error is a failure to read the ledger
+        match ledger_get(self.store_table.as_str(), key.into().as_str()) {
+            Ok(v) if v.is_empty() => Ok(None),
+            Ok(v) => Ok(Some(v)),
+            Err(e) => Err(Error::Other { description: e })
         }
     }
 
@@ -44,9 +47,24 @@ impl DB for FileDB {
         let key = key.into();
         let value = value.as_ref().to_vec();
 
-        match klave::ledger::get_table(self.store_table.as_str()).set(key.as_str(), &value) {
+        match ledger_set(self.store_table.as_str(), key.as_str(), &value) {
             Ok(_) => Ok(()),
-            Err(e) => Err(Error::Other { description: e.to_string() }),
+            Err(e) => Err(Error::Other { description: e }),
         }
     }
 }
+
+#[cfg(not(test))]
+fn ledger_get(table: &str, key: &str) -> Result<Vec<u8>, String> {
+    klave::ledger::get_table(table)
+        .get(key)
+        .map(|v| v.into())
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(not(test))]
+fn ledger_set(table: &str, key: &str, value: &[u8]) -> Result<(), String> {
+    klave::ledger::get_table(table)
+        .set(key, value)
+        .map_err(|e| e.to_string())
+}
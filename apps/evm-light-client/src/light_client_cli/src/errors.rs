@@ -1,5 +1,6 @@
 use displaydoc::Display;
-use crate::consensus::src::sync_protocol::SyncCommitteePeriod;
+use super::acl::Role;
+use crate::consensus::src::{beacon::Slot, sync_protocol::SyncCommitteePeriod};
 
 #[derive(Debug, Display)]
 pub enum Error {
@@ -19,6 +20,18 @@ pub enum Error {
     UnexpectedAttestedPeriod(SyncCommitteePeriod, SyncCommitteePeriod, String),
     /// cannot rotate to next sync committee: `store={0} finalized={1}`
     CannotRotateNextSyncCommittee(SyncCommitteePeriod, SyncCommitteePeriod),
+    /// light client is frozen by a `{1}` misbehaviour at slot `{0}`
+    Frozen(Slot, String),
+    /// `{1}` misbehaviour detected at slot `{2}` against secondary beacon endpoint `{0}`
+    MisbehaviourDetected(String, String, Slot, Box<serde_json::Value>),
+    /// the finalized slot of the store moved: `expected={0} actual={1}`
+    FinalizedSlotMismatch(Slot, Slot),
+    /// the finalized slot of the store cannot go backwards: `stored={0} new={1}`
+    FinalizedSlotRegression(Slot, Slot),
+    /// update `{0}` does not follow from the store: `{1}`
+    UpdateRejected(u64, Box<Error>),
+    /// sender `{0}` is not allowed: the `{1}` role is required
+    Unauthorized(String, Role),
     /// other error: `{description}`
     Other { description: String },
 }
@@ -54,3 +67,61 @@ impl From<crate::consensus::src::errors::Error> for Error {
         Self::CommontError(value)
     }
 }
+
+impl Error {
+    /// stable code of the error kind, the codes of the wrapped errors are kept as they are
+    pub fn code(&self) -> &'static str {
+        match self {
+            Error::RPCError(e) => e.code(),
+            Error::IOError(_) => "CLIENT_IO_ERROR",
+            Error::SerdeError(_) => "CLIENT_SERDE_ERROR",
+            Error::VerifierError(e) => e.code(),
+            Error::CommontError(e) => e.code(),
+            Error::FinalizedHeaderNotFound => "CLIENT_FINALIZED_HEADER_NOT_FOUND",
+            Error::UnexpectedAttestedPeriod(..) => "CLIENT_UNEXPECTED_ATTESTED_PERIOD",
+            Error::CannotRotateNextSyncCommittee(..) => "CLIENT_CANNOT_ROTATE_SYNC_COMMITTEE",
+            Error::Frozen(..) => "CLIENT_FROZEN",
+            Error::MisbehaviourDetected(..) => "CLIENT_MISBEHAVIOUR_DETECTED",
+            Error::FinalizedSlotMismatch(..) => "CLIENT_FINALIZED_SLOT_MISMATCH",
+            Error::FinalizedSlotRegression(..) => "CLIENT_FINALIZED_SLOT_REGRESSION",
+            Error::UpdateRejected(..) => "CLIENT_UPDATE_REJECTED",
+            Error::Unauthorized(..) => "CLIENT_UNAUTHORIZED",
+            Error::Other { .. } => "CLIENT_ERROR",
+        }
+    }
+
+    /// structured fields of the error
+    pub fn details(&self) -> serde_json::Value {
+        use serde_json::json;
+        match self {
+            Error::RPCError(e) => e.details(),
+            Error::VerifierError(e) => e.details(),
+            Error::CommontError(e) => e.details(),
+            Error::UnexpectedAttestedPeriod(store, attested, reason) => {
+                json!({"store_period": store, "attested_period": attested, "reason": reason})
+            }
+            Error::CannotRotateNextSyncCommittee(store, finalized) => {
+                json!({"store_period": store, "finalized_period": finalized})
+            }
+            Error::Frozen(slot, kind) => json!({"misbehaviour_slot": slot, "kind": kind}),
+            // the misbehaviour has the same shape as the input of `light_client_submit_misbehaviour`
+            Error::MisbehaviourDetected(endpoint, kind, slot, misbehaviour) => json!({
+                "secondary_beacon_endpoint": endpoint,
+                "kind": kind,
+                "slot": slot,
+                "misbehaviour": misbehaviour,
+            }),
+            Error::FinalizedSlotMismatch(expected, actual) => {
+                json!({"expected_finalized_slot": expected, "finalized_slot": actual})
+            }
+            Error::FinalizedSlotRegression(stored, new) => {
+                json!({"finalized_slot": stored, "new_finalized_slot": new})
+            }
+            Error::UpdateRejected(index, e) => {
+                json!({"index": index, "cause": {"code": e.code(), "details": e.details()}})
+            }
+            Error::Unauthorized(sender, role) => json!({"sender": sender, "required_role": role}),
+            _ => serde_json::Value::Null,
+        }
+    }
+}
@@ -1,4 +1,5 @@
 #![allow(clippy::result_large_err)]
+pub mod acl;
 pub mod chain;
 pub mod cli;
 pub mod client;
@@ -7,4 +8,8 @@ pub mod context;
 pub mod db;
 pub mod errors;
 pub mod preset;
+pub mod response;
 pub mod state;
+pub mod ticket;
+#[cfg(test)]
+pub(crate) mod testing;
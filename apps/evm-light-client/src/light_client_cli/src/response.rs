@@ -0,0 +1,142 @@
+use super::errors::Error;
+use crate::consensus::src::errors::Error as ConsensusError;
+use crate::light_client_verifier::src::errors::Error as VerifierError;
+use crate::lodestar_rpc::src::errors::Error as RPCError;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt::Display;
+
+/// the request could not be parsed or is invalid
+pub const INVALID_REQUEST: &str = "INVALID_REQUEST";
+/// the command of the route failed without a more specific code
+pub const COMMAND_FAILED: &str = "COMMAND_FAILED";
+
+/// Response is the envelope of the single message a route sends back through the notifier:
+/// `{"ok": true, "data": ..}` or `{"ok": false, "error": {"code": .., "message": .., "details": ..}}`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Response<T> {
+    pub ok: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<T>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<ErrorBody>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorBody {
+    pub code: String,
+    pub message: String,
+    /// structured fields of the error, e.g. the expected and actual slots
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    pub details: Value,
+}
+
+impl ErrorBody {
+    pub fn new(code: impl Into<String>, message: impl Display) -> Self {
+        Self {
+            code: code.into(),
+            message: message.to_string(),
+            details: Value::Null,
+        }
+    }
+
+    /// maps the first error in the chain that has a code, otherwise falls back to `COMMAND_FAILED`
+    pub fn from_error(e: &anyhow::Error) -> Self {
+        let (code, details) = e
+            .chain()
+            .find_map(|cause| {
+                if let Some(e) = cause.downcast_ref::<Error>() {
+                    Some((e.code(), e.details()))
+                } else if let Some(e) = cause.downcast_ref::<VerifierError>() {
+                    Some((e.code(), e.details()))
+                } else if let Some(e) = cause.downcast_ref::<RPCError>() {
+                    Some((e.code(), e.details()))
+                } else {
+                    cause
+                        .downcast_ref::<ConsensusError>()
+                        .map(|e| (e.code(), e.details()))
+                }
+            })
+            .unwrap_or((COMMAND_FAILED, Value::Null));
+        Self {
+            code: code.into(),
+            message: e.to_string(),
+            details,
+        }
+    }
+}
+
+impl<T> Response<T> {
+    pub fn data(data: T) -> Self {
+        Self {
+            ok: true,
+            data: Some(data),
+            error: None,
+        }
+    }
+
+    pub fn error(code: impl Into<String>, message: impl Display) -> Self {
+        Self {
+            ok: false,
+            data: None,
+            error: Some(ErrorBody::new(code, message)),
+        }
+    }
+}
+
+/// sends the result of the route
+pub fn send_data<T: Serialize>(data: &T) {
+    send(&Response::data(data))
+}
+
+pub fn send_error(code: &str, message: impl Display) {
+    send(&Response::<()>::error(code, message))
+}
+
+/// sends the failure of the command with the code of the error
+pub fn send_failure(e: &anyhow::Error) {
+    send(&Response::<()> {
+        ok: false,
+        data: None,
+        error: Some(ErrorBody::from_error(e)),
+    })
+}
+
+fn send<T: Serialize>(response: &Response<T>) {
+    if let Err(e) = klave::notifier::send_json(response) {
+        let response = Response::<()>::error(COMMAND_FAILED, format!("failed to serialize the response: {:?}", e));
+        klave::notifier::send_string(&serde_json::json!(response).to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_response_envelope() {
+        assert_eq!(
+            serde_json::to_string(&Response::data(serde_json::json!({"slot": "1"}))).unwrap(),
+            r#"{"ok":true,"data":{"slot":"1"}}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&Response::<()>::error(INVALID_REQUEST, "missing field `network_name`")).unwrap(),
+            r#"{"ok":false,"error":{"code":"INVALID_REQUEST","message":"missing field `network_name`"}}"#
+        );
+    }
+
+    #[test]
+    fn test_error_codes() {
+        let e = anyhow::Error::from(Error::VerifierError(VerifierError::InsufficientParticipants(300, 512)));
+        let body = ErrorBody::from_error(&e);
+        assert_eq!(body.code, "VERIFIER_INSUFFICIENT_PARTICIPANTS");
+        assert_eq!(body.details, serde_json::json!({"actual": 300, "total": 512}));
+
+        let e = anyhow::Error::from(RPCError::EndpointUnavailable("https://a".into())).context("failed to fetch update");
+        assert_eq!(ErrorBody::from_error(&e).code, "RPC_UNAVAILABLE");
+
+        let body = ErrorBody::from_error(&anyhow::anyhow!("block not found: 1"));
+        assert_eq!(body.code, COMMAND_FAILED);
+        assert_eq!(body.details, Value::Null);
+    }
+}
@@ -1,16 +1,21 @@
 use crate::consensus::src::{
     beacon::{BeaconBlockHeader, Slot},
     compute::compute_sync_committee_period_at_slot,
-    fork::deneb::{ExecutionPayloadHeader, LightClientBootstrap},
+    fork::{deneb, versioned::ExecutionPayloadHeader},
     sync_protocol::SyncCommittee,
     types::{H256, U64},
 };
 use crate::light_client_verifier::src::{
     context::ChainConsensusVerificationContext,
     state::LightClientStoreReader,
-    updates::{ConsensusUpdate, ExecutionUpdate},
+    updates::{
+        versioned::{ConsensusUpdateInfo, LightClientBootstrapInfo, OptimisticUpdateInfo},
+        ConsensusUpdate, ExecutionUpdate, LightClientBootstrap, OptimisticUpdate,
+    },
 };
+use super::errors::Error;
 use serde;
+use std::str::FromStr;
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct LightClientStore<
@@ -19,10 +24,88 @@ pub struct LightClientStore<
     const MAX_EXTRA_DATA_BYTES: usize,
 > {
     pub latest_finalized_header: BeaconBlockHeader,
+    #[serde(deserialize_with = "deserialize_execution_payload_header")]
     pub latest_execution_payload_header:
         ExecutionPayloadHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
     pub current_sync_committee: SyncCommittee<SYNC_COMMITTEE_SIZE>,
     pub next_sync_committee: Option<SyncCommittee<SYNC_COMMITTEE_SIZE>>,
+    /// latest header attested to by the sync committee, which may not be finalized yet
+    #[serde(default)]
+    pub optimistic_header: Option<OptimisticHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct OptimisticHeader<const BYTES_PER_LOGS_BLOOM: usize, const MAX_EXTRA_DATA_BYTES: usize> {
+    pub beacon: BeaconBlockHeader,
+    pub execution: ExecutionPayloadHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+}
+
+/// FinalizedHeader is a finalized header kept in the historical index of the ledger
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct FinalizedHeader<const BYTES_PER_LOGS_BLOOM: usize, const MAX_EXTRA_DATA_BYTES: usize> {
+    pub beacon_header_root: H256,
+    pub beacon: BeaconBlockHeader,
+    pub execution: ExecutionPayloadHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+}
+
+impl<const BYTES_PER_LOGS_BLOOM: usize, const MAX_EXTRA_DATA_BYTES: usize>
+    FinalizedHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
+{
+    /// the roots that verified reads are anchored to
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "slot": self.beacon.slot,
+            "beacon_header_root": self.beacon_header_root,
+            "block_number": self.execution.block_number(),
+            "block_hash": self.execution.block_hash(),
+            "state_root": self.execution.state_root(),
+            "receipts_root": self.execution.receipts_root(),
+            "transactions_root": self.execution.transactions_root(),
+            "timestamp": self.execution.timestamp(),
+        })
+    }
+}
+
+/// StateSummary is the part of the light client state that verified reads are anchored to
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct StateSummary {
+    pub finalized_slot: Slot,
+    pub finalized_block_number: U64,
+    pub finalized_block_hash: H256,
+    pub finalized_state_root: H256,
+    /// slot of the optimistic header, if it is newer than the finalized header
+    pub optimistic_slot: Option<Slot>,
+}
+
+/// Anchor selects the header that verified reads are anchored to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Anchor {
+    #[default]
+    Finalized,
+    Optimistic,
+}
+
+impl core::fmt::Display for Anchor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Anchor::Finalized => write!(f, "finalized"),
+            Anchor::Optimistic => write!(f, "optimistic"),
+        }
+    }
+}
+
+impl FromStr for Anchor {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "finalized" => Ok(Anchor::Finalized),
+            "optimistic" => Ok(Anchor::Optimistic),
+            s => Err(Error::Other {
+                description: format!("unknown anchor: {}", s),
+            }),
+        }
+    }
 }
 
 impl<
@@ -32,21 +115,82 @@ impl<
     > LightClientStore<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
 {
     pub fn from_bootstrap(
-        bootstrap: LightClientBootstrap<
+        bootstrap: &LightClientBootstrapInfo<
             SYNC_COMMITTEE_SIZE,
             BYTES_PER_LOGS_BLOOM,
             MAX_EXTRA_DATA_BYTES,
         >,
-        latest_execution_payload_header: ExecutionPayloadHeader<
-            BYTES_PER_LOGS_BLOOM,
-            MAX_EXTRA_DATA_BYTES,
-        >,
     ) -> Self {
         Self {
-            latest_finalized_header: bootstrap.header.beacon,
-            latest_execution_payload_header,
-            current_sync_committee: bootstrap.current_sync_committee,
+            latest_finalized_header: bootstrap.beacon_header().clone(),
+            latest_execution_payload_header: bootstrap.execution_payload_header(),
+            current_sync_committee: bootstrap.current_sync_committee().clone(),
             next_sync_committee: None,
+            optimistic_header: None,
+        }
+    }
+
+    /// the optimistic header is only used while it is newer than the finalized header
+    pub fn anchor_headers(
+        &self,
+        anchor: Anchor,
+    ) -> (
+        &BeaconBlockHeader,
+        &ExecutionPayloadHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    ) {
+        match (anchor, self.optimistic_header.as_ref()) {
+            (Anchor::Optimistic, Some(header))
+                if header.beacon.slot > self.latest_finalized_header.slot =>
+            {
+                (&header.beacon, &header.execution)
+            }
+            _ => (
+                &self.latest_finalized_header,
+                &self.latest_execution_payload_header,
+            ),
+        }
+    }
+
+    /// ref. https://github.com/ethereum/consensus-specs/blob/087e7378b44f327cdad4549304fc308613b780c3/specs/altair/light-client/sync-protocol.md#process_light_client_optimistic_update
+    /// the update must be validated with the store before it is applied
+    pub fn apply_optimistic_update(
+        &self,
+        optimistic_update: &OptimisticUpdateInfo<
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >,
+    ) -> Option<Self> {
+        let attested_slot = optimistic_update.attested_beacon_header().slot;
+        let optimistic_slot = self
+            .optimistic_header
+            .as_ref()
+            .map_or(self.latest_finalized_header.slot, |header| {
+                header.beacon.slot.max(self.latest_finalized_header.slot)
+            });
+        if attested_slot <= optimistic_slot {
+            return None;
+        }
+        let mut new_store = self.clone();
+        new_store.optimistic_header = Some(OptimisticHeader {
+            beacon: optimistic_update.attested_beacon_header().clone(),
+            execution: optimistic_update.attested_execution_payload_header(),
+        });
+        Some(new_store)
+    }
+
+    pub fn summary(&self) -> StateSummary {
+        let finalized_slot = self.latest_finalized_header.slot;
+        StateSummary {
+            finalized_slot,
+            finalized_block_number: self.latest_execution_payload_header.block_number(),
+            finalized_block_hash: self.latest_execution_payload_header.block_hash(),
+            finalized_state_root: self.latest_execution_payload_header.state_root(),
+            optimistic_slot: self
+                .optimistic_header
+                .as_ref()
+                .map(|header| header.beacon.slot)
+                .filter(|slot| *slot > finalized_slot),
         }
     }
 
@@ -54,13 +198,23 @@ impl<
         self.latest_finalized_header.slot
     }
 
-    pub fn apply_light_client_update<
-        CC: ChainConsensusVerificationContext,
-        CU: ConsensusUpdate<SYNC_COMMITTEE_SIZE>,
-    >(
+    /// whichever path writes a new state, a bootstrap included, it must not move the finalized slot backwards
+    pub fn ensure_follows(&self, stored: &Self) -> Result<(), Error> {
+        let (stored_slot, new_slot) = (stored.current_slot(), self.current_slot());
+        if new_slot < stored_slot {
+            return Err(Error::FinalizedSlotRegression(stored_slot, new_slot));
+        }
+        Ok(())
+    }
+
+    pub fn apply_light_client_update<CC: ChainConsensusVerificationContext>(
         &self,
         ctx: &CC,
-        consensus_update: &CU,
+        consensus_update: &ConsensusUpdateInfo<
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >,
     ) -> Result<Option<Self>, super::errors::Error> {
         let mut new_store = self.clone();
         let store_period =
@@ -97,6 +251,8 @@ impl<
         };
         if consensus_update.finalized_beacon_header().slot > self.latest_finalized_header.slot {
             new_store.latest_finalized_header = consensus_update.finalized_beacon_header().clone();
+            new_store.latest_execution_payload_header =
+                consensus_update.finalized_execution_payload_header();
             updated = true;
         }
         if updated {
@@ -181,6 +337,51 @@ impl<
         }
         Ok(())
     }
+
+    fn ensure_relevant_optimistic_update<
+        OU: crate::light_client_verifier::src::updates::OptimisticUpdate<SYNC_COMMITTEE_SIZE>,
+    >(
+        &self,
+        update: &OU,
+    ) -> Result<(), crate::light_client_verifier::src::errors::Error> {
+        // as for an update without a next sync committee:
+        // assert update_attested_slot > store.finalized_header.beacon.slot
+        let attested_slot = update.attested_beacon_header().slot;
+        if attested_slot <= self.current_slot() {
+            return Err(crate::light_client_verifier::src::errors::Error::StaleOptimisticUpdate(
+                attested_slot,
+                self.current_slot(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Stores persisted before the execution payload header was versioned hold a bare deneb header
+fn deserialize_execution_payload_header<
+    'de,
+    D: serde::Deserializer<'de>,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    deserializer: D,
+) -> Result<ExecutionPayloadHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>, D::Error> {
+    #[derive(serde::Deserialize)]
+    #[serde(untagged)]
+    enum StoredExecutionPayloadHeader<
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    > {
+        Versioned(ExecutionPayloadHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>),
+        Deneb(deneb::ExecutionPayloadHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>),
+    }
+
+    Ok(
+        match <StoredExecutionPayloadHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES> as serde::Deserialize>::deserialize(deserializer)? {
+            StoredExecutionPayloadHeader::Versioned(header) => header,
+            StoredExecutionPayloadHeader::Deneb(header) => ExecutionPayloadHeader::Deneb(header),
+        },
+    )
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -208,3 +409,65 @@ impl ExecutionUpdate for ExecutionUpdateInfo {
         self.block_number_branch.clone()
     }
 }
+
+/// FrozenState is persisted when a valid misbehaviour is submitted; the client refuses to update or verify until it is unfrozen
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct FrozenState {
+    /// finalized slot of the store when the misbehaviour was submitted
+    pub store_slot: Slot,
+    /// slot of the conflicting finalized headers or attested headers
+    pub misbehaviour_slot: Slot,
+    /// `finalized_header` or `next_sync_committee`
+    pub kind: String,
+    /// trusted time in seconds
+    pub frozen_at: U64,
+    /// the submitted misbehaviour
+    pub evidence: serde_json::Value,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::consensus::src::preset::minimal::PRESET;
+    use crate::light_client_cli::src::testing::{MinimalStore, MinimalSyncCommittee};
+    use crate::light_client_verifier::src::{
+        consensus::{
+            test_utils::{gen_bootstrap, gen_optimistic_update, minimal_context},
+            SyncProtocolVerifier,
+        },
+        errors::Error,
+    };
+
+    #[test]
+    fn test_apply_optimistic_update() {
+        let vctx = minimal_context(32);
+        let signer = MinimalSyncCommittee::new(1);
+        let store = MinimalStore::from_bootstrap(&gen_bootstrap(&vctx, &signer.committee, 16.into()));
+        let verifier = SyncProtocolVerifier::<{ PRESET.SYNC_COMMITTEE_SIZE }, MinimalStore>::default();
+
+        // a header older than the finalized one, or the finalized one itself, is rejected
+        for attested_slot in [8u64, 16] {
+            let update = gen_optimistic_update(&vctx, &signer, attested_slot.into());
+            assert!(matches!(
+                verifier.validate_optimistic_update(&vctx, &store, &update),
+                Err(Error::StaleOptimisticUpdate(..))
+            ));
+            assert_eq!(store.apply_optimistic_update(&update), None);
+        }
+
+        let update = gen_optimistic_update(&vctx, &signer, 20.into());
+        verifier.validate_optimistic_update(&vctx, &store, &update).unwrap();
+        let new_store = store.apply_optimistic_update(&update).unwrap();
+        let optimistic_header = new_store.optimistic_header.as_ref().unwrap();
+        assert_eq!(optimistic_header.beacon.slot, 20.into());
+        assert_eq!(optimistic_header.execution.block_number(), 20.into());
+        assert_eq!(new_store.latest_finalized_header, store.latest_finalized_header);
+        assert_eq!(new_store.summary().optimistic_slot, Some(20.into()));
+
+        // the optimistic header only moves forward
+        let update = gen_optimistic_update(&vctx, &signer, 18.into());
+        assert_eq!(new_store.apply_optimistic_update(&update), None);
+        let update = gen_optimistic_update(&vctx, &signer, 24.into());
+        let newer = new_store.apply_optimistic_update(&update).unwrap();
+        assert_eq!(newer.optimistic_header.unwrap().beacon.slot, 24.into());
+    }
+}
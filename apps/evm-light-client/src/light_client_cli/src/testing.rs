@@ -0,0 +1,134 @@
+//! stands in for the ledger and the context of the enclave, which are only available in the enclave
+use super::{
+    chain::{Chain, Network},
+    cli::Opts,
+    client::LightClient,
+    errors::Error,
+    preset::MinimalContext,
+    state::LightClientStore,
+    ticket::TicketKey,
+};
+use crate::consensus::src::{config, preset::minimal};
+use crate::light_client_verifier::src::consensus::test_utils::{MockSyncCommittee, GENESIS_VALIDATORS_ROOT};
+use crate::lodestar_rpc::src::types::GenesisData;
+use sha2::{Digest, Sha256};
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+
+/// the trusted time of the enclave, long after the genesis of the minimal network
+pub const TRUSTED_TIME_SECS: u64 = 1_800_000_000;
+
+thread_local! {
+    static LEDGER: RefCell<BTreeMap<(String, String), Vec<u8>>> = RefCell::new(BTreeMap::new());
+    static SENDER: RefCell<String> = RefCell::new("owner".into());
+    static ENCLAVE_KEY_CREATED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// like the ledger, a missing key reads as an empty value
+pub fn ledger_get(table: &str, key: &str) -> Result<Vec<u8>, String> {
+    Ok(LEDGER.with(|ledger| {
+        ledger
+            .borrow()
+            .get(&(table.to_string(), key.to_string()))
+            .cloned()
+            .unwrap_or_default()
+    }))
+}
+
+pub fn ledger_set(table: &str, key: &str, value: &[u8]) -> Result<(), String> {
+    LEDGER.with(|ledger| {
+        ledger
+            .borrow_mut()
+            .insert((table.to_string(), key.to_string()), value.to_vec())
+    });
+    Ok(())
+}
+
+pub fn trusted_time_secs() -> u64 {
+    TRUSTED_TIME_SECS
+}
+
+pub fn sender() -> Result<String, Error> {
+    Ok(SENDER.with(|sender| sender.borrow().clone()))
+}
+
+/// the sender of the requests that follow
+pub fn set_sender(sender: &str) {
+    SENDER.with(|current| *current.borrow_mut() = sender.into());
+}
+
+/// EnclaveKey signs with a keyed hash in place of the key of the enclave, which exists once a persist created it
+pub struct EnclaveKey;
+
+impl EnclaveKey {
+    pub fn load() -> Result<Self, Error> {
+        if ENCLAVE_KEY_CREATED.with(Cell::get) {
+            Ok(Self)
+        } else {
+            Err(Error::Other {
+                description: "persist ticket key not found, it is created by the first persist".into(),
+            })
+        }
+    }
+
+    pub fn create() -> Result<(), Error> {
+        ENCLAVE_KEY_CREATED.with(|created| created.set(true));
+        Ok(())
+    }
+}
+
+impl TicketKey for EnclaveKey {
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error> {
+        Ok(Sha256::digest([b"enclave".as_slice(), message].concat()).to_vec())
+    }
+
+    fn verify(&self, message: &[u8], signature: &[u8]) -> Result<bool, Error> {
+        Ok(self.sign(message)? == signature)
+    }
+}
+
+pub type MinimalLightClient = LightClient<
+    { minimal::PRESET.BYTES_PER_LOGS_BLOOM },
+    { minimal::PRESET.MAX_EXTRA_DATA_BYTES },
+    { minimal::PRESET.SYNC_COMMITTEE_SIZE },
+>;
+
+pub type MinimalStore = LightClientStore<
+    { minimal::PRESET.SYNC_COMMITTEE_SIZE },
+    { minimal::PRESET.BYTES_PER_LOGS_BLOOM },
+    { minimal::PRESET.MAX_EXTRA_DATA_BYTES },
+>;
+
+pub type MinimalSyncCommittee = MockSyncCommittee<{ minimal::PRESET.SYNC_COMMITTEE_SIZE }>;
+
+pub fn opts() -> Opts {
+    Opts {
+        ledger_table: "light_client_minimal".into(),
+        beacon_endpoint: "http://localhost:9596".into(),
+        network: "minimal".into(),
+    }
+}
+
+/// a context on an empty ledger
+pub fn context() -> MinimalContext {
+    MinimalContext::build(Network::Minimal, opts()).unwrap()
+}
+
+pub fn genesis() -> GenesisData {
+    GenesisData {
+        genesis_validators_root: GENESIS_VALIDATORS_ROOT,
+        genesis_time: config::minimal::get_config().min_genesis_time,
+        genesis_fork_version: "0x00000001".into(),
+    }
+}
+
+pub fn light_client(ctx: MinimalContext) -> MinimalLightClient {
+    let chain = Chain::new(ctx.beacon_endpoint()).unwrap();
+    let genesis = genesis();
+    LightClient::new(
+        ctx,
+        chain,
+        genesis.genesis_time,
+        genesis.genesis_validators_root,
+    )
+}
@@ -0,0 +1,234 @@
+use super::{errors::Error, state::LightClientStore};
+use crate::consensus::src::types::H256;
+use sha2::{Digest, Sha256};
+
+#[cfg(not(test))]
+pub use enclave::EnclaveKey;
+#[cfg(test)]
+pub use super::testing::EnclaveKey;
+
+/// PersistTicket binds the result of a query to the `light_client_persist` transaction.
+/// The persist accepts the state without re-verifying it only if the ticket was issued against the stored state.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PersistTicket {
+    /// hash of the stored state the ticket was issued against, none before the first state is stored
+    pub prior_state_hash: Option<H256>,
+    pub state_hash: H256,
+    /// hash of the bootstrap, genesis and updates persisted with the state
+    pub info_hash: H256,
+    /// hex encoded signature of the enclave key
+    pub signature: String,
+}
+
+impl PersistTicket {
+    pub fn issue(
+        key: &impl TicketKey,
+        prior_state_hash: Option<H256>,
+        state_hash: H256,
+        info_hash: H256,
+    ) -> Result<Self, Error> {
+        let signature = key.sign(&message(prior_state_hash, state_hash, info_hash))?;
+        Ok(Self {
+            prior_state_hash,
+            state_hash,
+            info_hash,
+            signature: hex::encode(signature),
+        })
+    }
+
+    /// checks the signature and that the ticket covers the given hashes
+    pub fn verify(
+        &self,
+        key: &impl TicketKey,
+        prior_state_hash: Option<H256>,
+        state_hash: H256,
+        info_hash: H256,
+    ) -> Result<(), Error> {
+        if self.prior_state_hash != prior_state_hash {
+            return Err(Error::Other {
+                description: "persist ticket was issued against another stored state".into(),
+            });
+        }
+        if self.state_hash != state_hash || self.info_hash != info_hash {
+            return Err(Error::Other {
+                description: "persist ticket does not cover the given state and info".into(),
+            });
+        }
+        let signature = hex::decode(&self.signature).map_err(|e| Error::Other {
+            description: format!("invalid persist ticket signature: {}", e),
+        })?;
+        if key.verify(&message(prior_state_hash, state_hash, info_hash), &signature)? {
+            Ok(())
+        } else {
+            Err(Error::Other {
+                description: "persist ticket signature does not verify".into(),
+            })
+        }
+    }
+}
+
+/// TicketKey signs the tickets in the queries and verifies them in the persist transaction
+pub trait TicketKey {
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error>;
+    fn verify(&self, message: &[u8], signature: &[u8]) -> Result<bool, Error>;
+}
+
+#[cfg(not(test))]
+mod enclave {
+    use super::{Error, TicketKey};
+    use klave::crypto::subtle::{self, CryptoKey, EcKeyGenParams, EcdsaParams, KeyGenAlgorithm, SignAlgorithm};
+
+    /// name of the enclave key that signs the persist tickets, it never leaves the enclave
+    const TICKET_KEY_NAME: &str = "light_client_persist_ticket";
+
+    /// EnclaveKey is the ECDSA key of the enclave that signs the persist tickets, it never leaves the enclave
+    pub struct EnclaveKey(CryptoKey);
+
+    impl EnclaveKey {
+        /// a query cannot save a key, so the key must have been created by a transaction before
+        pub fn load() -> Result<Self, Error> {
+            subtle::load_key(TICKET_KEY_NAME).map(Self).map_err(|e| Error::Other {
+                description: format!("persist ticket key not found, it is created by the first persist: {}", e),
+            })
+        }
+
+        /// creates the key unless it exists, which only a transaction can do
+        pub fn create() -> Result<(), Error> {
+            if subtle::load_key(TICKET_KEY_NAME).is_ok() {
+                return Ok(());
+            }
+            let algorithm = KeyGenAlgorithm::Ecc(EcKeyGenParams {
+                named_curve: "P-256".to_string(),
+            });
+            let key = subtle::generate_key(&algorithm, false, &["sign", "verify"]).map_err(|e| Error::Other {
+                description: format!("failed to generate persist ticket key: {}", e),
+            })?;
+            subtle::save_key(&key, TICKET_KEY_NAME).map_err(|e| Error::Other {
+                description: format!("failed to save persist ticket key: {}", e),
+            })?;
+            Ok(())
+        }
+    }
+
+    impl TicketKey for EnclaveKey {
+        fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error> {
+            subtle::sign(&sign_algorithm(), &self.0, message).map_err(|e| Error::Other {
+                description: format!("failed to sign persist ticket: {}", e),
+            })
+        }
+
+        fn verify(&self, message: &[u8], signature: &[u8]) -> Result<bool, Error> {
+            subtle::verify(&sign_algorithm(), &self.0, message, signature).map_err(|e| Error::Other {
+                description: format!("failed to verify persist ticket: {}", e),
+            })
+        }
+    }
+
+    fn sign_algorithm() -> SignAlgorithm {
+        SignAlgorithm::Ecdsa(EcdsaParams {
+            hash: "SHA2-256".to_string(),
+        })
+    }
+}
+
+/// hash of the state as it is stored in the ledger
+pub fn state_hash<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    state: &LightClientStore<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+) -> Result<H256, Error> {
+    Ok(hash(&serde_json::to_vec(state)?))
+}
+
+/// hash of the infos persisted with the state, in the order of `PersistCommand`
+pub fn info_hash(infos: &[Option<&str>]) -> Result<H256, Error> {
+    Ok(hash(&serde_json::to_vec(infos)?))
+}
+
+fn message(prior_state_hash: Option<H256>, state_hash: H256, info_hash: H256) -> Vec<u8> {
+    let mut message = Vec::with_capacity(97);
+    match prior_state_hash {
+        Some(prior_state_hash) => {
+            message.push(1);
+            message.extend_from_slice(&prior_state_hash.0);
+        }
+        None => message.extend_from_slice(&[0; 33]),
+    }
+    message.extend_from_slice(&state_hash.0);
+    message.extend_from_slice(&info_hash.0);
+    message
+}
+
+fn hash(bz: &[u8]) -> H256 {
+    let mut output = H256::default();
+    output.0.copy_from_slice(Sha256::digest(bz).as_slice());
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// signs with a keyed hash in place of the enclave key
+    struct TestKey(&'static [u8]);
+
+    impl TicketKey for TestKey {
+        fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error> {
+            Ok(hash(&[self.0, message].concat()).0.to_vec())
+        }
+
+        fn verify(&self, message: &[u8], signature: &[u8]) -> Result<bool, Error> {
+            Ok(self.sign(message)? == signature)
+        }
+    }
+
+    #[test]
+    fn test_issue_and_verify() {
+        let key = TestKey(b"enclave");
+        let (prior, state, info) = (hash(b"prior"), hash(b"state"), hash(b"info"));
+
+        let ticket = PersistTicket::issue(&key, Some(prior), state, info).unwrap();
+        assert!(ticket.verify(&key, Some(prior), state, info).is_ok());
+        // the first ticket is issued against no stored state
+        let first = PersistTicket::issue(&key, None, prior, info).unwrap();
+        assert!(first.verify(&key, None, prior, info).is_ok());
+        assert!(first.verify(&key, Some(prior), prior, info).is_err());
+
+        // another state or info than the ones the ticket was issued for
+        assert!(ticket.verify(&key, Some(prior), hash(b"forged"), info).is_err());
+        assert!(ticket.verify(&key, Some(prior), state, hash(b"forged")).is_err());
+        // the ticket is reused after the stored state moved on to its state
+        assert!(ticket.verify(&key, Some(state), state, info).is_err());
+
+        // the hashes of the ticket are changed along with the ones it is checked against
+        let mut tampered = ticket.clone();
+        tampered.state_hash = hash(b"forged");
+        assert!(tampered.verify(&key, Some(prior), hash(b"forged"), info).is_err());
+        let mut tampered = ticket.clone();
+        tampered.info_hash = hash(b"forged");
+        assert!(tampered.verify(&key, Some(prior), state, hash(b"forged")).is_err());
+        let mut tampered = ticket.clone();
+        tampered.prior_state_hash = Some(state);
+        assert!(tampered.verify(&key, Some(state), state, info).is_err());
+
+        // the ticket is signed by another key
+        assert!(ticket.verify(&TestKey(b"other"), Some(prior), state, info).is_err());
+        let mut tampered = ticket;
+        tampered.signature = "0x".into();
+        assert!(tampered.verify(&key, Some(prior), state, info).is_err());
+    }
+
+    #[test]
+    fn test_message() {
+        let (state, info) = (hash(b"state"), hash(b"info"));
+        // no prior state is not the same as a zero prior state hash
+        assert_ne!(message(None, state, info), message(Some(H256::default()), state, info));
+        assert_eq!(message(None, state, info).len(), 97);
+        assert_ne!(
+            info_hash(&[Some("a"), None]).unwrap(),
+            info_hash(&[None, Some("a")]).unwrap()
+        );
+    }
+}
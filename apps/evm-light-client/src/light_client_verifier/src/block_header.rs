@@ -0,0 +1,151 @@
+use super::errors::Error;
+use crate::consensus::src::types::{Address, H256};
+use patricia_merkle_trie::keccak::keccak_256;
+use primitive_types::U256;
+
+/// ExecutionBlockHeader is an execution layer block header, whose hash is the `block_hash` of the execution payload
+/// https://github.com/ethereum/execution-specs/blob/master/src/ethereum/prague/blocks.py
+///
+/// The optional fields were added by later forks; a field can only be present if all the fields before it are.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExecutionBlockHeader {
+    pub parent_hash: H256,
+    pub ommers_hash: H256,
+    pub coinbase: Address,
+    pub state_root: H256,
+    pub transactions_root: H256,
+    pub receipts_root: H256,
+    pub logs_bloom: Vec<u8>,
+    pub difficulty: U256,
+    pub number: u64,
+    pub gas_limit: u64,
+    pub gas_used: u64,
+    pub timestamp: u64,
+    pub extra_data: Vec<u8>,
+    pub mix_hash: H256,
+    pub nonce: [u8; 8],
+    /// London
+    pub base_fee_per_gas: Option<U256>,
+    /// Shanghai
+    pub withdrawals_root: Option<H256>,
+    /// Cancun
+    pub blob_gas_used: Option<u64>,
+    pub excess_blob_gas: Option<u64>,
+    pub parent_beacon_block_root: Option<H256>,
+    /// Prague
+    pub requests_hash: Option<H256>,
+}
+
+impl ExecutionBlockHeader {
+    /// the RLP encoding of the header
+    pub fn to_rlp_bytes(&self) -> Result<Vec<u8>, Error> {
+        let optional_fields = [
+            self.base_fee_per_gas.is_some(),
+            self.withdrawals_root.is_some(),
+            self.blob_gas_used.is_some(),
+            self.excess_blob_gas.is_some(),
+            self.parent_beacon_block_root.is_some(),
+            self.requests_hash.is_some(),
+        ];
+        let present = optional_fields.iter().take_while(|present| **present).count();
+        if optional_fields[present..].iter().any(|present| *present) {
+            return Err(Error::MissingExecutionBlockHeaderField(present));
+        }
+
+        let mut s = rlp::RlpStream::new_list(15 + present);
+        s.append(&self.parent_hash.0.to_vec());
+        s.append(&self.ommers_hash.0.to_vec());
+        s.append(&self.coinbase.0.to_vec());
+        s.append(&self.state_root.0.to_vec());
+        s.append(&self.transactions_root.0.to_vec());
+        s.append(&self.receipts_root.0.to_vec());
+        s.append(&self.logs_bloom);
+        append_u256(&mut s, self.difficulty);
+        s.append(&self.number);
+        s.append(&self.gas_limit);
+        s.append(&self.gas_used);
+        s.append(&self.timestamp);
+        s.append(&self.extra_data);
+        s.append(&self.mix_hash.0.to_vec());
+        s.append(&self.nonce.to_vec());
+        if let Some(base_fee_per_gas) = self.base_fee_per_gas {
+            append_u256(&mut s, base_fee_per_gas);
+        }
+        if let Some(withdrawals_root) = self.withdrawals_root {
+            s.append(&withdrawals_root.0.to_vec());
+        }
+        if let Some(blob_gas_used) = self.blob_gas_used {
+            s.append(&blob_gas_used);
+        }
+        if let Some(excess_blob_gas) = self.excess_blob_gas {
+            s.append(&excess_blob_gas);
+        }
+        if let Some(parent_beacon_block_root) = self.parent_beacon_block_root {
+            s.append(&parent_beacon_block_root.0.to_vec());
+        }
+        if let Some(requests_hash) = self.requests_hash {
+            s.append(&requests_hash.0.to_vec());
+        }
+        Ok(s.out().to_vec())
+    }
+
+    /// keccak256 of the RLP encoding of the header
+    pub fn hash(&self) -> Result<H256, Error> {
+        Ok(H256(keccak_256(&self.to_rlp_bytes()?)))
+    }
+}
+
+/// an integer is encoded as its big-endian bytes without leading zeros
+fn append_u256(s: &mut rlp::RlpStream, value: U256) {
+    let mut bz = [0u8; 32];
+    value.to_big_endian(&mut bz);
+    let start = bz.iter().position(|b| *b != 0).unwrap_or(bz.len());
+    s.append(&bz[start..].to_vec());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    fn mainnet_genesis() -> ExecutionBlockHeader {
+        ExecutionBlockHeader {
+            ommers_hash: H256(hex!(
+                "1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347"
+            )),
+            state_root: H256(hex!(
+                "d7f8974fb5ac78d9ac099b9ad5018bedc2ce0a72dad1827a1709da30580f0544"
+            )),
+            transactions_root: H256(hex!(
+                "56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421"
+            )),
+            receipts_root: H256(hex!(
+                "56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421"
+            )),
+            logs_bloom: vec![0; 256],
+            difficulty: U256::from(0x400000000u64),
+            gas_limit: 5000,
+            extra_data: hex!("11bbe8db4e347b4e8c937c1c8370e4b5ed33adb3db69cbdb7a38e1e50b1b82fa")
+                .to_vec(),
+            nonce: hex!("0000000000000042"),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_block_header_hash() {
+        assert_eq!(
+            mainnet_genesis().hash().unwrap(),
+            H256(hex!(
+                "d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3"
+            ))
+        );
+
+        let mut header = mainnet_genesis();
+        header.base_fee_per_gas = Some(U256::from(7));
+        header.withdrawals_root = Some(Default::default());
+        assert!(header.hash().is_ok());
+        header.parent_beacon_block_root = Some(Default::default());
+        assert!(header.hash().is_err());
+    }
+}
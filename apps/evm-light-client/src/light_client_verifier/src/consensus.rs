@@ -2,9 +2,9 @@ use super::context::{ChainConsensusVerificationContext, ConsensusVerificationCon
 use super::errors::Error;
 use super::misbehaviour::Misbehaviour;
 use super::state::{get_sync_committee_at_period, LightClientStoreReader};
-use super::updates::{ConsensusUpdate, ExecutionUpdate, LightClientBootstrap};
+use super::updates::{ConsensusUpdate, ExecutionUpdate, LightClientBootstrap, OptimisticUpdate};
 use core::marker::PhantomData;
-use crate::consensus::src::beacon::{BeaconBlockHeader, Root, DOMAIN_SYNC_COMMITTEE};
+use crate::consensus::src::beacon::{BeaconBlockHeader, Root, Slot, DOMAIN_SYNC_COMMITTEE};
 use crate::consensus::src::bls::{fast_aggregate_verify, BLSPublicKey, BLSSignature};
 use crate::consensus::src::compute::{
     compute_domain, compute_epoch_at_slot, compute_fork_version, compute_signing_root,
@@ -13,7 +13,7 @@ use crate::consensus::src::compute::{
 use crate::consensus::src::context::ChainContext;
 use crate::consensus::src::fork::{ForkSpec, BELLATRIX_INDEX};
 use crate::consensus::src::merkle::is_valid_normalized_merkle_branch;
-use crate::consensus::src::sync_protocol::SyncCommittee;
+use crate::consensus::src::sync_protocol::{SyncAggregate, SyncCommittee};
 use crate::consensus::src::types::H256;
 
 /// SyncProtocolVerifier is a verifier of [light client sync protocol](https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/sync-protocol.md)
@@ -99,6 +99,59 @@ impl<const SYNC_COMMITTEE_SIZE: usize, ST: LightClientStoreReader<SYNC_COMMITTEE
         Ok(())
     }
 
+    /// validate an optimistic update with a committee from the trusted store
+    /// follow `process_light_client_optimistic_update` in the consensus spec
+    ///
+    /// If the return value is `Ok`, the attested header is signed by a sufficient number of the committee members,
+    /// but it is not finalized yet.
+    pub fn validate_optimistic_update<
+        CC: ChainConsensusVerificationContext,
+        OU: OptimisticUpdate<SYNC_COMMITTEE_SIZE>,
+    >(
+        &self,
+        ctx: &CC,
+        store: &ST,
+        optimistic_update: &OU,
+    ) -> Result<(), Error> {
+        optimistic_update.validate_basic(ctx)?;
+        store.ensure_relevant_optimistic_update(optimistic_update)?;
+        let attested_epoch =
+            compute_epoch_at_slot(ctx, optimistic_update.attested_beacon_header().slot);
+        if !ctx
+            .fork_parameters()
+            .is_fork(attested_epoch, BELLATRIX_INDEX)
+        {
+            return Err(Error::ForkNotSupported(attested_epoch));
+        }
+
+        let current_period = store.current_period(ctx);
+        let signature_period =
+            compute_sync_committee_period_at_slot(ctx, optimistic_update.signature_slot());
+        if current_period != signature_period && current_period + 1 != signature_period {
+            return Err(Error::StoreNotCoveredSignaturePeriod(
+                current_period,
+                signature_period,
+            ));
+        }
+        optimistic_update.is_valid_light_client_attested_header(ctx)?;
+
+        let Some(sync_committee) = get_sync_committee_at_period(ctx, store, signature_period) else {
+            return Err(Error::UnexpectedSingaturePeriod(
+                current_period,
+                signature_period,
+                "store does not have the sync committee corresponding to the update signature period"
+                    .into(),
+            ));
+        };
+        verify_sync_aggregate(
+            ctx,
+            optimistic_update.attested_beacon_header(),
+            optimistic_update.sync_aggregate(),
+            optimistic_update.signature_slot(),
+            &sync_committee,
+        )
+    }
+
     /// validate an execution update with trusted/verified beacon block body
     pub fn validate_execution_update<EU: ExecutionUpdate>(
         &self,
@@ -184,9 +237,29 @@ pub fn verify_sync_committee_attestation<
     ctx: &CC,
     consensus_update: &CU,
     sync_committee: &SyncCommittee<SYNC_COMMITTEE_SIZE>,
+) -> Result<(), Error> {
+    verify_sync_aggregate(
+        ctx,
+        consensus_update.attested_beacon_header(),
+        consensus_update.sync_aggregate(),
+        consensus_update.signature_slot(),
+        sync_committee,
+    )
+}
+
+/// verify that the sync aggregate signed at `signature_slot` is a sufficient attestation of `attested_header` by the sync committee
+pub fn verify_sync_aggregate<
+    const SYNC_COMMITTEE_SIZE: usize,
+    CC: ChainContext + ConsensusVerificationContext,
+>(
+    ctx: &CC,
+    attested_header: &BeaconBlockHeader,
+    sync_aggregate: &SyncAggregate<SYNC_COMMITTEE_SIZE>,
+    signature_slot: Slot,
+    sync_committee: &SyncCommittee<SYNC_COMMITTEE_SIZE>,
 ) -> Result<(), Error> {
     // ensure that suffienct participants exist
-    let participants = consensus_update.sync_aggregate().count_participants();
+    let participants = sync_aggregate.count_participants();
     // from the spec: `assert sum(sync_aggregate.sync_committee_bits) >= MIN_SYNC_COMMITTEE_PARTICIPANTS`
     if participants < ctx.min_sync_committee_participants() {
         return Err(Error::LessThanMinimalParticipants(
@@ -194,17 +267,16 @@ pub fn verify_sync_committee_attestation<
             ctx.min_sync_committee_participants(),
         ));
     } else if participants as u64 * ctx.signature_threshold().denominator()
-        < consensus_update.sync_aggregate().sync_committee_bits.len() as u64
+        < sync_aggregate.sync_committee_bits.len() as u64
             * ctx.signature_threshold().numerator()
     {
         return Err(Error::InsufficientParticipants(
             participants as u64,
-            consensus_update.sync_aggregate().sync_committee_bits.len() as u64,
+            sync_aggregate.sync_committee_bits.len() as u64,
         ));
     }
 
-    let participant_pubkeys: Vec<BLSPublicKey> = consensus_update
-        .sync_aggregate()
+    let participant_pubkeys: Vec<BLSPublicKey> = sync_aggregate
         .sync_committee_bits
         .iter()
         .zip(sync_committee.pubkeys.iter())
@@ -212,7 +284,7 @@ pub fn verify_sync_committee_attestation<
         .map(|t| t.1.clone().try_into().unwrap())
         .collect();
 
-    let fork_version_slot = consensus_update.signature_slot().max(1.into()) - 1;
+    let fork_version_slot = signature_slot.max(1.into()) - 1;
     let fork_version = compute_fork_version(ctx, compute_epoch_at_slot(ctx, fork_version_slot));
     let domain = compute_domain(
         ctx,
@@ -220,17 +292,12 @@ pub fn verify_sync_committee_attestation<
         Some(fork_version),
         Some(ctx.genesis_validators_root()),
     )?;
-    let signing_root =
-        compute_signing_root(consensus_update.attested_beacon_header().clone(), domain)?;
+    let signing_root = compute_signing_root(attested_header.clone(), domain)?;
 
     verify_bls_signatures(
         participant_pubkeys,
         signing_root,
-        consensus_update
-            .sync_aggregate()
-            .sync_committee_signature
-            .clone()
-            .try_into()?,
+        sync_aggregate.sync_committee_signature.clone().try_into()?,
     )
 }
 
@@ -374,3 +441,329 @@ pub fn verify_bls_signatures(
         Err(Error::InvalidBLSSignatures)
     }
 }
+
+/// builds the bootstraps and the signed updates of a mock chain, for the tests of the verifier and of the light client
+#[cfg(test)]
+pub mod test_utils {
+    use super::*;
+    use crate::consensus::src::bls::{
+        aggregate_public_key, BLSAggregateSignature, PublicKey, Signature,
+    };
+    use crate::consensus::src::config;
+    use crate::consensus::src::fork::deneb::{self, LightClientHeader};
+    use crate::consensus::src::types::U64;
+    use crate::light_client_verifier::src::context::{Fraction, LightClientContext};
+    use crate::light_client_verifier::src::updates::{
+        electra,
+        versioned::{
+            ConsensusUpdateInfo, LightClientBootstrapInfo, LightClientOptimisticUpdate,
+            OptimisticUpdateInfo,
+        },
+    };
+    use milagro_bls::SecretKey;
+    use sha2::{Digest, Sha256};
+    use ssz_rs::prelude::{Bitvector, Vector};
+    use std::collections::BTreeMap;
+
+    pub const GENESIS_VALIDATORS_ROOT: Root = H256([1; 32]);
+
+    /// the context of the minimal network whose clock is at `current_slot`
+    pub fn minimal_context(current_slot: u64) -> LightClientContext {
+        let config = config::minimal::get_config();
+        let genesis_time = config.min_genesis_time;
+        let current_timestamp = genesis_time + U64(current_slot) * config.preset.SECONDS_PER_SLOT;
+        LightClientContext::new_with_config(
+            config,
+            GENESIS_VALIDATORS_ROOT,
+            genesis_time,
+            Fraction::new(2, 3).unwrap(),
+            current_timestamp,
+        )
+    }
+
+    /// MockSyncCommittee is a sync committee whose members all sign with the key of its seed,
+    /// so that an update is signed once whatever the size of the committee
+    pub struct MockSyncCommittee<const SYNC_COMMITTEE_SIZE: usize> {
+        key: SecretKey,
+        pub committee: SyncCommittee<SYNC_COMMITTEE_SIZE>,
+    }
+
+    impl<const SYNC_COMMITTEE_SIZE: usize> MockSyncCommittee<SYNC_COMMITTEE_SIZE> {
+        pub fn new(seed: u8) -> Self {
+            let mut bz = [0u8; 32];
+            bz[31] = seed;
+            let key = SecretKey::from_bytes(&bz).unwrap();
+            let pubkeys = vec![BLSPublicKey::from_secret_key(&key); SYNC_COMMITTEE_SIZE];
+            let committee = SyncCommittee {
+                pubkeys: Vector::from_iter(pubkeys.iter().cloned().map(PublicKey::from)),
+                aggregate_pubkey: aggregate_public_key(&pubkeys).unwrap().into(),
+            };
+            Self { key, committee }
+        }
+
+        /// the sync aggregate of all the members attesting to `attested_header` at `signature_slot`
+        pub fn sign<CC: ChainContext + ConsensusVerificationContext>(
+            &self,
+            ctx: &CC,
+            attested_header: &BeaconBlockHeader,
+            signature_slot: Slot,
+        ) -> SyncAggregate<SYNC_COMMITTEE_SIZE> {
+            let fork_version_slot = signature_slot.max(1.into()) - 1;
+            let fork_version =
+                compute_fork_version(ctx, compute_epoch_at_slot(ctx, fork_version_slot));
+            let domain = compute_domain(
+                ctx,
+                DOMAIN_SYNC_COMMITTEE,
+                Some(fork_version),
+                Some(ctx.genesis_validators_root()),
+            )
+            .unwrap();
+            let signing_root = compute_signing_root(attested_header.clone(), domain).unwrap();
+            let signature = BLSSignature::new(signing_root.as_bytes(), &self.key);
+            let mut aggregate = BLSAggregateSignature::new();
+            let mut sync_committee_bits = Bitvector::<SYNC_COMMITTEE_SIZE>::default();
+            for index in 0..SYNC_COMMITTEE_SIZE {
+                aggregate.add(&signature);
+                sync_committee_bits.set(index, true);
+            }
+            SyncAggregate {
+                sync_committee_bits,
+                sync_committee_signature: Signature::try_from(aggregate.as_bytes().to_vec())
+                    .unwrap(),
+            }
+        }
+    }
+
+    /// the root of the tree that has the leaves at their generalized indices and zero elsewhere,
+    /// and the branch of each leaf
+    pub fn gen_merkle_tree(leaves: &[(u32, H256)]) -> (Root, Vec<Vec<H256>>) {
+        let mut nodes: BTreeMap<u32, H256> = leaves.iter().copied().collect();
+        let last = nodes.keys().last().copied().unwrap_or(1);
+        // the children of a node have greater indices, so both are known when the first of them is reached
+        for gindex in (2..=last).rev() {
+            let Some(node) = nodes.get(&gindex).copied() else {
+                continue;
+            };
+            let sibling = nodes.get(&(gindex ^ 1)).copied().unwrap_or_default();
+            let (left, right) = if gindex % 2 == 0 {
+                (node, sibling)
+            } else {
+                (sibling, node)
+            };
+            nodes
+                .entry(gindex / 2)
+                .or_insert_with(|| H256::from_slice(&Sha256::digest([left.0, right.0].concat())));
+        }
+        let branches = leaves
+            .iter()
+            .map(|(gindex, _)| {
+                let mut branch = Vec::new();
+                let mut gindex = *gindex;
+                while gindex > 1 {
+                    branch.push(nodes.get(&(gindex ^ 1)).copied().unwrap_or_default());
+                    gindex /= 2;
+                }
+                branch
+            })
+            .collect();
+        (nodes.get(&1).copied().unwrap_or_default(), branches)
+    }
+
+    /// a header at `slot` whose body holds an execution payload of block number `slot`
+    pub fn gen_header<
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        CC: ChainConsensusVerificationContext,
+    >(
+        ctx: &CC,
+        slot: Slot,
+        state_root: Root,
+    ) -> LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES> {
+        let mut execution_state_root = H256::default();
+        execution_state_root.0[24..].copy_from_slice(&slot.0.to_be_bytes());
+        let execution = deneb::ExecutionPayloadHeader {
+            state_root: execution_state_root,
+            block_number: slot,
+            ..Default::default()
+        };
+        let (body_root, branches) = gen_merkle_tree(&[(
+            ctx.compute_fork_spec(slot).execution_payload_gindex,
+            hash_tree_root(execution.clone()).unwrap(),
+        )]);
+        LightClientHeader {
+            beacon: BeaconBlockHeader {
+                slot,
+                state_root,
+                body_root,
+                ..Default::default()
+            },
+            execution,
+            execution_branch: branches[0].clone(),
+        }
+    }
+
+    /// a bootstrap at `slot` of the current sync committee `committee`
+    pub fn gen_bootstrap<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        CC: ChainConsensusVerificationContext,
+    >(
+        ctx: &CC,
+        committee: &SyncCommittee<SYNC_COMMITTEE_SIZE>,
+        slot: Slot,
+    ) -> LightClientBootstrapInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES> {
+        let (state_root, branches) = gen_merkle_tree(&[(
+            ctx.compute_fork_spec(slot).current_sync_committee_gindex,
+            hash_tree_root(committee.clone()).unwrap(),
+        )]);
+        LightClientBootstrapInfo::Electra(electra::LightClientBootstrapInfo(
+            deneb::LightClientBootstrap {
+                header: gen_header(ctx, slot, state_root),
+                current_sync_committee: committee.clone(),
+                current_sync_committee_branch: branches[0].clone(),
+            },
+        ))
+    }
+
+    /// an update of the header at `finalized_slot`, attested at `attested_slot` with the next sync committee if any,
+    /// and signed by `signer` at the slot after
+    pub fn gen_update<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        CC: ChainConsensusVerificationContext,
+    >(
+        ctx: &CC,
+        signer: &MockSyncCommittee<SYNC_COMMITTEE_SIZE>,
+        attested_slot: Slot,
+        finalized_slot: Slot,
+        next_sync_committee: Option<&SyncCommittee<SYNC_COMMITTEE_SIZE>>,
+    ) -> ConsensusUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES> {
+        let finalized_header = gen_header(ctx, finalized_slot, Default::default());
+        let spec = ctx.compute_fork_spec(attested_slot);
+        let mut leaves = vec![(
+            spec.finalized_root_gindex,
+            hash_tree_root(finalized_header.beacon.clone()).unwrap(),
+        )];
+        if let Some(committee) = next_sync_committee {
+            leaves.push((
+                spec.next_sync_committee_gindex,
+                hash_tree_root(committee.clone()).unwrap(),
+            ));
+        }
+        let (state_root, branches) = gen_merkle_tree(&leaves);
+        let attested_header = gen_header(ctx, attested_slot, state_root);
+        let signature_slot = attested_slot + 1;
+        ConsensusUpdateInfo::Electra(electra::ConsensusUpdateInfo(deneb::LightClientUpdate {
+            sync_aggregate: signer.sign(ctx, &attested_header.beacon, signature_slot),
+            attested_header,
+            next_sync_committee: next_sync_committee
+                .map(|committee| (committee.clone(), branches[1].clone())),
+            finalized_header,
+            finality_branch: branches[0].clone(),
+            signature_slot,
+        }))
+    }
+
+    /// an optimistic update of the header at `attested_slot`, signed by `signer` at the slot after
+    pub fn gen_optimistic_update<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        CC: ChainConsensusVerificationContext,
+    >(
+        ctx: &CC,
+        signer: &MockSyncCommittee<SYNC_COMMITTEE_SIZE>,
+        attested_slot: Slot,
+    ) -> OptimisticUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES> {
+        let attested_header = gen_header(ctx, attested_slot, Default::default());
+        let signature_slot = attested_slot + 1;
+        OptimisticUpdateInfo::Electra(LightClientOptimisticUpdate {
+            sync_aggregate: signer.sign(ctx, &attested_header.beacon, signature_slot),
+            attested_header,
+            signature_slot,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_utils::{gen_optimistic_update, minimal_context, MockSyncCommittee};
+    use super::*;
+    use crate::consensus::src::preset::minimal::PRESET;
+    use crate::consensus::src::sync_protocol::SyncCommitteePeriod;
+    use crate::light_client_verifier::src::updates::versioned::OptimisticUpdateInfo;
+
+    const SYNC_COMMITTEE_SIZE: usize = PRESET.SYNC_COMMITTEE_SIZE;
+
+    /// a store finalized at `finalized_slot` that only knows the current sync committee
+    struct MockStore {
+        finalized_slot: Slot,
+        current_sync_committee: SyncCommittee<SYNC_COMMITTEE_SIZE>,
+    }
+
+    impl LightClientStoreReader<SYNC_COMMITTEE_SIZE> for MockStore {
+        fn current_period<CC: ChainContext>(&self, ctx: &CC) -> SyncCommitteePeriod {
+            compute_sync_committee_period_at_slot(ctx, self.finalized_slot)
+        }
+
+        fn current_sync_committee(&self) -> Option<SyncCommittee<SYNC_COMMITTEE_SIZE>> {
+            Some(self.current_sync_committee.clone())
+        }
+
+        fn next_sync_committee(&self) -> Option<SyncCommittee<SYNC_COMMITTEE_SIZE>> {
+            None
+        }
+
+        fn ensure_relevant_update<CC: ChainContext, C: ConsensusUpdate<SYNC_COMMITTEE_SIZE>>(
+            &self,
+            _ctx: &CC,
+            _update: &C,
+        ) -> Result<(), Error> {
+            Ok(())
+        }
+
+        fn ensure_relevant_optimistic_update<OU: OptimisticUpdate<SYNC_COMMITTEE_SIZE>>(
+            &self,
+            update: &OU,
+        ) -> Result<(), Error> {
+            let attested_slot = update.attested_beacon_header().slot;
+            if attested_slot <= self.finalized_slot {
+                return Err(Error::StaleOptimisticUpdate(attested_slot, self.finalized_slot));
+            }
+            Ok(())
+        }
+    }
+
+    type Update = OptimisticUpdateInfo<SYNC_COMMITTEE_SIZE, 256, 32>;
+
+    #[test]
+    fn test_validate_optimistic_update() {
+        let ctx = minimal_context(32);
+        let signer = MockSyncCommittee::<SYNC_COMMITTEE_SIZE>::new(1);
+        let store = MockStore {
+            finalized_slot: 16.into(),
+            current_sync_committee: signer.committee.clone(),
+        };
+        let verifier = SyncProtocolVerifier::<SYNC_COMMITTEE_SIZE, MockStore>(PhantomData);
+
+        let update: Update = gen_optimistic_update(&ctx, &signer, 20.into());
+        verifier.validate_optimistic_update(&ctx, &store, &update).unwrap();
+
+        // the attested header must come after the finalized one
+        for attested_slot in [12u64, 16] {
+            let update: Update = gen_optimistic_update(&ctx, &signer, attested_slot.into());
+            assert!(matches!(
+                verifier.validate_optimistic_update(&ctx, &store, &update),
+                Err(Error::StaleOptimisticUpdate(attested, finalized))
+                    if attested == attested_slot.into() && finalized == 16.into()
+            ));
+        }
+
+        // signed by another committee than the one of the store
+        let other = MockSyncCommittee::<SYNC_COMMITTEE_SIZE>::new(2);
+        let update: Update = gen_optimistic_update(&ctx, &other, 20.into());
+        assert!(verifier.validate_optimistic_update(&ctx, &store, &update).is_err());
+    }
+}
@@ -47,8 +47,12 @@ pub enum Error {
     FinalizedHeaderNotFound,
     /// inconsistent slot order: `current={0} signature={1} attested={2} finalized={3}`
     InconsistentSlotOrder(Slot, Slot, Slot, Slot),
+    /// inconsistent slot order of optimistic update: `current={0} signature={1} attested={2}`
+    InconsistentOptimisticSlotOrder(Slot, Slot, Slot),
     /// irrelevant consensus updates error: `{0}`
     IrrelevantConsensusUpdates(String),
+    /// stale optimistic update: `attested={0} finalized={1}`
+    StaleOptimisticUpdate(Slot, Slot),
     /// trie error
     TrieError(BoxedTrieError),
     /// ethereum common error: `{0:?}`
@@ -73,10 +77,36 @@ pub enum Error {
     ExecutionValueExist,
     /// value mismatch error in execution layer: {0:?} != {1:?}
     ExecutionValueMismatch(Vec<u8>, Vec<u8>),
+    /// storage value must be at most 32 bytes: `length={0}`
+    InvalidStorageValueLength(usize),
+    /// execution block header must have the optional fields before a present one: `missing_index={0}`
+    MissingExecutionBlockHeaderField(usize),
+    /// execution block hash mismatch: `expected={0:?} actual={1:?}`
+    ExecutionBlockHashMismatch(H256, H256),
+    /// receipt not found in the receipts trie: `tx_index={0}`
+    ReceiptNotFound(u64),
+    /// log not found in the receipt: `tx_index={0} log_index={1:?}`
+    LogNotFound(u64, Option<usize>),
+    /// unsupported transaction type: `type={0}`
+    UnsupportedTransactionType(u8),
+    /// the transaction type requires a recipient: `type={0}`
+    MissingTransactionRecipient(u8),
+    /// invalid merkle branch of transaction: `error={0}`
+    InvalidTransactionMerkleBranch(MerkleError),
+    /// packed storage member must fit in a storage word: `offset={0} size={1}`
+    InvalidPackedStorageMember(usize, usize),
+    /// array element size must be at most 32 bytes or a multiple of 32 bytes: `size={0}`
+    InvalidArrayElementSize(usize),
+    /// invalid encoding of string or bytes in storage: `word={0:?}`
+    InvalidStorageBytesEncoding(H256),
+    /// unexpected number of data words of string or bytes in storage: `expected={0} actual={1}`
+    InvalidStorageBytesDataLength(usize, usize),
     /// invalid merkle branch of finalized beacon header: `error={0}`
     InvalidFinalizedBeaconHeaderMerkleBranch(MerkleError),
     /// invalid merkle branch of finalized execution payload: `error={0}`
     InvalidFinalizedExecutionPayload(MerkleError),
+    /// invalid merkle branch of attested execution payload: `error={0}`
+    InvalidAttestedExecutionPayload(MerkleError),
     /// invalid merkle branch of next sync committee: `error={0}`
     InvalidNextSyncCommitteeMerkleBranch(MerkleError),
     /// next sync committee must be empty: `actual={0:?}`
@@ -116,3 +146,174 @@ impl From<rlp::DecoderError> for Error {
         Self::RlpDecoderError(value)
     }
 }
+
+impl Error {
+    /// stable code of the error kind
+    pub fn code(&self) -> &'static str {
+        match self {
+            Error::ForkNotSupported(_) => "VERIFIER_FORK_NOT_SUPPORTED",
+            Error::UnexpectedSingaturePeriod(..) => "VERIFIER_UNEXPECTED_SIGNATURE_PERIOD",
+            Error::UnexpectedAttestedPeriod(..) => "VERIFIER_UNEXPECTED_ATTESTED_PERIOD",
+            Error::UnexpectedFinalizedPeriod(..) => "VERIFIER_UNEXPECTED_FINALIZED_PERIOD",
+            Error::StoreNotCoveredSignaturePeriod(..) => "VERIFIER_SIGNATURE_PERIOD_NOT_COVERED",
+            Error::CannotRotateNextSyncCommittee(..) => "VERIFIER_CANNOT_ROTATE_SYNC_COMMITTEE",
+            Error::NoNextSyncCommitteeInStore(..) => "VERIFIER_NO_NEXT_SYNC_COMMITTEE",
+            Error::NonEmptyBeaconHeaderAtGenesisSlot(_) => "VERIFIER_NON_EMPTY_GENESIS_HEADER",
+            Error::VerifyMembershipError() => "VERIFIER_MEMBERSHIP_ERROR",
+            Error::TrustedRootMismatch(..) => "VERIFIER_TRUSTED_ROOT_MISMATCH",
+            Error::LessThanMinimalParticipants(..) => "VERIFIER_LESS_THAN_MINIMAL_PARTICIPANTS",
+            Error::InsufficientParticipants(..) => "VERIFIER_INSUFFICIENT_PARTICIPANTS",
+            Error::EmptyExecutionPayloadStateRootBranch => "VERIFIER_EMPTY_STATE_ROOT_BRANCH",
+            Error::EmptyExecutionPayloadBlockNumberBranch => "VERIFIER_EMPTY_BLOCK_NUMBER_BRANCH",
+            Error::InvalidBLSSignatures => "VERIFIER_INVALID_BLS_SIGNATURES",
+            Error::FinalizedHeaderNotFound => "VERIFIER_FINALIZED_HEADER_NOT_FOUND",
+            Error::InconsistentSlotOrder(..) => "VERIFIER_INCONSISTENT_SLOT_ORDER",
+            Error::InconsistentOptimisticSlotOrder(..) => "VERIFIER_INCONSISTENT_OPTIMISTIC_SLOT_ORDER",
+            Error::IrrelevantConsensusUpdates(_) => "VERIFIER_IRRELEVANT_UPDATES",
+            Error::StaleOptimisticUpdate(..) => "VERIFIER_STALE_OPTIMISTIC_UPDATE",
+            Error::TrieError(_) => "VERIFIER_TRIE_ERROR",
+            Error::CommonError(e) => e.code(),
+            Error::RlpDecoderError(_) => "VERIFIER_RLP_DECODE_ERROR",
+            Error::NotFinalizedNextSyncCommittee(..) => "VERIFIER_NEXT_SYNC_COMMITTEE_NOT_FINALIZED",
+            Error::DifferentPeriodInNextSyncCommitteeMisbehaviour(..)
+            | Error::NoNextSyncCommitteeInNextSyncCommitteeMisbehaviour
+            | Error::SameNextSyncCommitteeInNextSyncCommitteeMisbehaviour(_)
+            | Error::DifferentSlotInFinalizedHeaderMisbehaviour(..)
+            | Error::SameFinalizedHeaderInFinalizedHeaderMisbehaviour(_) => "VERIFIER_INVALID_MISBEHAVIOUR",
+            Error::ExecutionValueNonExist => "VERIFIER_EXECUTION_VALUE_NOT_EXIST",
+            Error::ExecutionValueExist => "VERIFIER_EXECUTION_VALUE_EXIST",
+            Error::ExecutionValueMismatch(..) => "VERIFIER_EXECUTION_VALUE_MISMATCH",
+            Error::InvalidStorageValueLength(_) => "VERIFIER_INVALID_STORAGE_VALUE_LENGTH",
+            Error::MissingExecutionBlockHeaderField(_) => "VERIFIER_MISSING_BLOCK_HEADER_FIELD",
+            Error::ExecutionBlockHashMismatch(..) => "VERIFIER_BLOCK_HASH_MISMATCH",
+            Error::ReceiptNotFound(_) => "VERIFIER_RECEIPT_NOT_FOUND",
+            Error::LogNotFound(..) => "VERIFIER_LOG_NOT_FOUND",
+            Error::UnsupportedTransactionType(_) => "VERIFIER_UNSUPPORTED_TRANSACTION_TYPE",
+            Error::MissingTransactionRecipient(_) => "VERIFIER_MISSING_TRANSACTION_RECIPIENT",
+            Error::InvalidTransactionMerkleBranch(_) => "VERIFIER_INVALID_TRANSACTION_BRANCH",
+            Error::InvalidPackedStorageMember(..) => "VERIFIER_INVALID_PACKED_STORAGE_MEMBER",
+            Error::InvalidArrayElementSize(_) => "VERIFIER_INVALID_ARRAY_ELEMENT_SIZE",
+            Error::InvalidStorageBytesEncoding(_) => "VERIFIER_INVALID_STORAGE_BYTES_ENCODING",
+            Error::InvalidStorageBytesDataLength(..) => "VERIFIER_INVALID_STORAGE_BYTES_LENGTH",
+            Error::InvalidFinalizedBeaconHeaderMerkleBranch(_) => "VERIFIER_INVALID_FINALITY_BRANCH",
+            Error::InvalidFinalizedExecutionPayload(_) => "VERIFIER_INVALID_FINALIZED_EXECUTION_BRANCH",
+            Error::InvalidAttestedExecutionPayload(_) => "VERIFIER_INVALID_ATTESTED_EXECUTION_BRANCH",
+            Error::InvalidNextSyncCommitteeMerkleBranch(_) => "VERIFIER_INVALID_NEXT_SYNC_COMMITTEE_BRANCH",
+            Error::NonEmptyNextSyncCommittee(_) => "VERIFIER_NON_EMPTY_NEXT_SYNC_COMMITTEE",
+            Error::InvalidCurrentSyncCommitteeMerkleBranch(_) => "VERIFIER_INVALID_CURRENT_SYNC_COMMITTEE_BRANCH",
+            Error::InvalidExecutionStateRootMerkleBranch(_) => "VERIFIER_INVALID_STATE_ROOT_BRANCH",
+            Error::NoExecutionPayloadInBeaconBlock => "VERIFIER_NO_EXECUTION_PAYLOAD",
+            Error::InvalidExecutionBlockNumberMerkleBranch(_) => "VERIFIER_INVALID_BLOCK_NUMBER_BRANCH",
+            Error::InconsistentNextSyncCommittee(..) => "VERIFIER_INCONSISTENT_NEXT_SYNC_COMMITTEE",
+            Error::InvalidFraction(_) => "VERIFIER_INVALID_FRACTION",
+            Error::Other { .. } => "VERIFIER_ERROR",
+        }
+    }
+
+    /// structured fields of the error
+    pub fn details(&self) -> serde_json::Value {
+        use serde_json::json;
+        match self {
+            Error::ForkNotSupported(epoch) => json!({"epoch": epoch}),
+            Error::UnexpectedSingaturePeriod(store, signature, reason) => {
+                json!({"store_period": store, "signature_period": signature, "reason": reason})
+            }
+            Error::UnexpectedAttestedPeriod(store, attested, reason) => {
+                json!({"store_period": store, "attested_period": attested, "reason": reason})
+            }
+            Error::UnexpectedFinalizedPeriod(store, finalized, reason) => {
+                json!({"store_period": store, "finalized_period": finalized, "reason": reason})
+            }
+            Error::StoreNotCoveredSignaturePeriod(store, signature) => {
+                json!({"store_period": store, "signature_period": signature})
+            }
+            Error::CannotRotateNextSyncCommittee(store, finalized) => {
+                json!({"store_period": store, "finalized_period": finalized})
+            }
+            Error::NoNextSyncCommitteeInStore(store, signature) => {
+                json!({"store_period": store, "signature_period": signature})
+            }
+            Error::NonEmptyBeaconHeaderAtGenesisSlot(slot) => json!({"slot": slot}),
+            Error::TrustedRootMismatch(expected, actual) => {
+                json!({"expected_root": expected, "actual_root": actual})
+            }
+            Error::LessThanMinimalParticipants(actual, minimal) => {
+                json!({"actual": actual, "minimal": minimal})
+            }
+            Error::InsufficientParticipants(actual, total) => {
+                json!({"actual": actual, "total": total})
+            }
+            Error::InconsistentSlotOrder(current, signature, attested, finalized) => json!({
+                "current_slot": current,
+                "signature_slot": signature,
+                "attested_slot": attested,
+                "finalized_slot": finalized,
+            }),
+            Error::InconsistentOptimisticSlotOrder(current, signature, attested) => json!({
+                "current_slot": current,
+                "signature_slot": signature,
+                "attested_slot": attested,
+            }),
+            Error::StaleOptimisticUpdate(attested, finalized) => json!({
+                "attested_slot": attested,
+                "finalized_slot": finalized,
+            }),
+            Error::CommonError(e) => e.details(),
+            Error::NotFinalizedNextSyncCommittee(finalized, attested) => {
+                json!({"finalized_period": finalized, "attested_period": attested})
+            }
+            Error::DifferentPeriodInNextSyncCommitteeMisbehaviour(period_1, period_2) => {
+                json!({"period_1": period_1, "period_2": period_2})
+            }
+            Error::SameNextSyncCommitteeInNextSyncCommitteeMisbehaviour(aggregate_pubkey) => {
+                json!({"aggregate_pubkey": aggregate_pubkey})
+            }
+            Error::DifferentSlotInFinalizedHeaderMisbehaviour(slot_1, slot_2) => {
+                json!({"slot_1": slot_1, "slot_2": slot_2})
+            }
+            Error::SameFinalizedHeaderInFinalizedHeaderMisbehaviour(header) => {
+                json!({"finalized_header": header})
+            }
+            Error::ExecutionValueMismatch(expected, actual) => json!({
+                "expected": format!("0x{}", hex::encode(expected)),
+                "actual": format!("0x{}", hex::encode(actual)),
+            }),
+            Error::InvalidStorageValueLength(length) => json!({"length": length}),
+            Error::MissingExecutionBlockHeaderField(index) => json!({"missing_index": index}),
+            Error::ExecutionBlockHashMismatch(expected, actual) => {
+                json!({"expected_hash": expected, "actual_hash": actual})
+            }
+            Error::ReceiptNotFound(tx_index) => json!({"tx_index": tx_index}),
+            Error::LogNotFound(tx_index, log_index) => {
+                json!({"tx_index": tx_index, "log_index": log_index})
+            }
+            Error::UnsupportedTransactionType(tx_type) | Error::MissingTransactionRecipient(tx_type) => {
+                json!({"type": tx_type})
+            }
+            Error::InvalidPackedStorageMember(offset, size) => {
+                json!({"offset": offset, "size": size})
+            }
+            Error::InvalidArrayElementSize(size) => json!({"size": size}),
+            Error::InvalidStorageBytesEncoding(word) => json!({"word": word}),
+            Error::InvalidStorageBytesDataLength(expected, actual) => {
+                json!({"expected": expected, "actual": actual})
+            }
+            Error::InvalidTransactionMerkleBranch(e)
+            | Error::InvalidFinalizedBeaconHeaderMerkleBranch(e)
+            | Error::InvalidFinalizedExecutionPayload(e)
+            | Error::InvalidAttestedExecutionPayload(e)
+            | Error::InvalidNextSyncCommitteeMerkleBranch(e)
+            | Error::InvalidCurrentSyncCommitteeMerkleBranch(e)
+            | Error::InvalidExecutionStateRootMerkleBranch(e)
+            | Error::InvalidExecutionBlockNumberMerkleBranch(e) => {
+                json!({"merkle_error": e.code(), "merkle": e.details()})
+            }
+            Error::NonEmptyNextSyncCommittee(branch) => json!({"next_sync_committee_branch": branch}),
+            Error::InconsistentNextSyncCommittee(store, update) => {
+                json!({"store_aggregate_pubkey": store, "update_aggregate_pubkey": update})
+            }
+            Error::InvalidFraction(fraction) => json!({"fraction": fraction}),
+            _ => serde_json::Value::Null,
+        }
+    }
+}
@@ -1,11 +1,29 @@
+use super::block_header::ExecutionBlockHeader;
 use super::errors::Error;
-use crate::consensus::src::types::{Address, H256, U64};
+use super::receipt::{Log, Receipt};
+use crate::consensus::src::errors::Error as ConsensusError;
+use crate::consensus::src::{
+    beacon::Root,
+    merkle::{byte_list_root, get_chunks_depth, is_valid_merkle_branch},
+    types::{Address, H256, U64},
+};
+use std::collections::BTreeMap;
 use patricia_merkle_trie::{
     keccak::{keccak_256, KeccakHasher},
     EIP1186Layout, StorageProof,
 };
 use trie_db::{Trie, TrieDBBuilder};
 
+/// keccak256(rlp("")), the storage root of an account without storage
+pub const EMPTY_TRIE_ROOT: H256 = H256(hex_literal::hex!(
+    "56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421"
+));
+
+/// keccak256(""), the code hash of an account without code
+pub const EMPTY_CODE_HASH: H256 = H256(hex_literal::hex!(
+    "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+));
+
 /// ExecutionVerifier is a verifier of execution layer's state
 /// The proof spec follows EIP-1186: https://eips.ethereum.org/EIPS/eip-1186
 #[derive(Clone, Default, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -25,6 +43,91 @@ impl ExecutionVerifier {
         Ok(trie.get(&keccak_256(key))?)
     }
 
+    /// check if the hash of `header` matches `block_hash`
+    pub fn verify_block_header(
+        &self,
+        block_hash: H256,
+        header: &ExecutionBlockHeader,
+    ) -> Result<(), Error> {
+        let actual = header.hash()?;
+        if actual != block_hash {
+            return Err(Error::ExecutionBlockHashMismatch(block_hash, actual));
+        }
+        Ok(())
+    }
+
+    /// check if `transaction` is at `index` of the transactions of an execution payload with `transactions_root`
+    /// `branch` is a merkle branch of the SSZ list whose last element is the number of transactions
+    pub fn verify_transaction(
+        &self,
+        transactions_root: Root,
+        index: u64,
+        transaction: &[u8],
+        branch: &[H256],
+        max_bytes_per_transaction: usize,
+        max_transactions_per_payload: usize,
+    ) -> Result<(), Error> {
+        let length = match branch.last() {
+            Some(chunk) => u64::from_le_bytes(chunk.0[..8].try_into().unwrap()),
+            None => 0,
+        };
+        if index >= length {
+            return Err(ConsensusError::TransactionIndexOutOfRange(index, length).into());
+        }
+        is_valid_merkle_branch(
+            byte_list_root(transaction, max_bytes_per_transaction),
+            branch,
+            get_chunks_depth(max_transactions_per_payload) + 1,
+            index as u32,
+            transactions_root,
+        )
+        .map_err(Error::InvalidTransactionMerkleBranch)
+    }
+
+    /// get a receipt of the transaction at `tx_index` with a given proof against `receipts_root`
+    /// unlike the state trie, the keys of the receipts trie are `rlp(tx_index)` without hashing
+    pub fn verify_receipt(
+        &self,
+        receipts_root: H256,
+        tx_index: u64,
+        proof: Vec<Vec<u8>>,
+    ) -> Result<Option<Receipt>, Error> {
+        let db = StorageProof::new(proof).into_memory_db::<KeccakHasher>();
+        let root: primitive_types::H256 = receipts_root.into();
+        let trie = TrieDBBuilder::<EIP1186Layout<KeccakHasher>>::new(&db, &root).build();
+        match trie.get(&rlp::encode(&tx_index))? {
+            Some(value) => Ok(Some(Receipt::from_rlp_bytes(&value)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// check if `log` was emitted by the transaction at `tx_index`, and return its receipt and the index of the log in it
+    /// if `log_index` is given, the log must be at the index in the receipt; otherwise the first matching log is used
+    pub fn verify_log(
+        &self,
+        receipts_root: H256,
+        tx_index: u64,
+        log_index: Option<usize>,
+        log: &Log,
+        proof: Vec<Vec<u8>>,
+    ) -> Result<(Receipt, usize), Error> {
+        let receipt = self
+            .verify_receipt(receipts_root, tx_index, proof)?
+            .ok_or(Error::ReceiptNotFound(tx_index))?;
+        let log_index = match log_index {
+            Some(log_index) => match receipt.logs.get(log_index) {
+                Some(actual) if actual == log => log_index,
+                _ => return Err(Error::LogNotFound(tx_index, Some(log_index))),
+            },
+            None => receipt
+                .logs
+                .iter()
+                .position(|actual| actual == log)
+                .ok_or(Error::LogNotFound(tx_index, None))?,
+        };
+        Ok((receipt, log_index))
+    }
+
     /// check if a value corresponding to `key` exists
     pub fn verify_membership(
         &self,
@@ -72,6 +175,44 @@ impl ExecutionVerifier {
         }
     }
 
+    /// get a storage value corresponding to `slot` and `proof`
+    /// a slot that doesn't exist in the storage trie is reported as zero
+    pub fn verify_storage(
+        &self,
+        storage_root: H256,
+        slot: &H256,
+        proof: Vec<Vec<u8>>,
+    ) -> Result<H256, Error> {
+        if storage_root == EMPTY_TRIE_ROOT {
+            return Ok(H256::default());
+        }
+        match self.verify(storage_root, slot.as_bytes(), proof)? {
+            Some(value) => decode_storage_value(&value),
+            None => Ok(H256::default()),
+        }
+    }
+
+    /// verify an account with a given address and proof, and then its storage slots against the account's storage root
+    /// the slots of an account that doesn't exist are reported as zero
+    pub fn verify_account_storage(
+        &self,
+        root: H256,
+        address: &Address,
+        account_proof: Vec<Vec<u8>>,
+        storage_proofs: Vec<(H256, Vec<Vec<u8>>)>,
+    ) -> Result<(Option<Account>, BTreeMap<H256, H256>), Error> {
+        let account = self.verify_account(root, address, account_proof)?;
+        let storage_root = account
+            .as_ref()
+            .map(|account| account.storage_root)
+            .unwrap_or(EMPTY_TRIE_ROOT);
+        let storage = storage_proofs
+            .into_iter()
+            .map(|(slot, proof)| Ok((slot, self.verify_storage(storage_root, &slot, proof)?)))
+            .collect::<Result<_, Error>>()?;
+        Ok((account, storage))
+    }
+
     /// check if an account's storage root matches `storage_root`
     pub fn verify_account_storage_root(
         &self,
@@ -108,11 +249,23 @@ impl Account {
     }
 }
 
+/// decode a storage value, which is the RLP encoding of a big-endian word without leading zeros
+fn decode_storage_value(bz: &[u8]) -> Result<H256, Error> {
+    let value = rlp::Rlp::new(bz).data()?;
+    if value.len() > 32 {
+        return Err(Error::InvalidStorageValueLength(value.len()));
+    }
+    let mut word = [0u8; 32];
+    word[32 - value.len()..].copy_from_slice(value);
+    Ok(H256(word))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{Account, ExecutionVerifier};
+    use super::{Account, ExecutionVerifier, EMPTY_TRIE_ROOT};
     use crate::consensus::src::types::{Address, H256, U64};
     use hex_literal::hex;
+    use patricia_merkle_trie::keccak::keccak_256;
 
     #[test]
     fn test_account_deserialization() {
@@ -163,4 +316,40 @@ mod tests {
         assert!(res.is_ok());
         assert!(res.unwrap());
     }
+
+    #[test]
+    fn test_storage_verification() {
+        // a storage trie that only contains slot 0 with value 0x2a
+        let slot = H256::default();
+        let mut path = vec![0x20];
+        path.extend_from_slice(&keccak_256(slot.as_bytes()));
+        let mut leaf = rlp::RlpStream::new_list(2);
+        leaf.append(&path);
+        leaf.append(&rlp::encode(&0x2au8).to_vec());
+        let leaf = leaf.out().to_vec();
+        let storage_root = H256(keccak_256(&leaf));
+
+        let mut expected = H256::default();
+        expected.0[31] = 0x2a;
+        assert_eq!(
+            ExecutionVerifier
+                .verify_storage(storage_root, &slot, vec![leaf.clone()])
+                .unwrap(),
+            expected
+        );
+
+        let mut other_slot = H256::default();
+        other_slot.0[31] = 1;
+        assert!(ExecutionVerifier
+            .verify_storage(storage_root, &other_slot, vec![leaf.clone()])
+            .unwrap()
+            .is_zero());
+        assert!(ExecutionVerifier
+            .verify_storage(EMPTY_TRIE_ROOT, &slot, vec![])
+            .unwrap()
+            .is_zero());
+        assert!(ExecutionVerifier
+            .verify_storage(H256::default(), &slot, vec![leaf])
+            .is_err());
+    }
 }
@@ -1,7 +1,11 @@
+pub mod block_header;
 pub mod consensus;
 pub mod context;
 pub mod errors;
 pub mod execution;
 pub mod misbehaviour;
+pub mod receipt;
 pub mod state;
+pub mod storage_layout;
+pub mod transaction;
 pub mod updates;
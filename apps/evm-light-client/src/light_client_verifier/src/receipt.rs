@@ -0,0 +1,175 @@
+use super::errors::Error;
+use crate::consensus::src::types::{Address, H256};
+
+pub const LEGACY_TX_TYPE: u8 = 0x00;
+/// EIP-2930
+pub const ACCESS_LIST_TX_TYPE: u8 = 0x01;
+/// EIP-1559
+pub const DYNAMIC_FEE_TX_TYPE: u8 = 0x02;
+/// EIP-4844
+pub const BLOB_TX_TYPE: u8 = 0x03;
+/// EIP-7702
+pub const SET_CODE_TX_TYPE: u8 = 0x04;
+
+/// Log is a log emitted by a transaction
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Log {
+    pub address: Address,
+    pub topics: Vec<H256>,
+    #[serde(with = "crate::consensus::src::types::serde_hex")]
+    pub data: Vec<u8>,
+}
+
+/// ReceiptOutcome is the outcome of a transaction, which is a post-transaction state root before Byzantium
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReceiptOutcome {
+    PostState(H256),
+    Status(bool),
+}
+
+/// Receipt is a transaction receipt in the receipts trie of an execution block
+/// https://github.com/ethereum/execution-specs/blob/master/src/ethereum/prague/blocks.py
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Receipt {
+    pub tx_type: u8,
+    pub outcome: ReceiptOutcome,
+    pub cumulative_gas_used: u64,
+    #[serde(with = "crate::consensus::src::types::serde_hex")]
+    pub logs_bloom: Vec<u8>,
+    pub logs: Vec<Log>,
+}
+
+impl Receipt {
+    /// decode a receipt from its encoding in the receipts trie:
+    /// `rlp(receipt)` for a legacy transaction, or `tx_type || rlp(receipt)` for a typed transaction
+    pub fn from_rlp_bytes(bz: &[u8]) -> Result<Receipt, Error> {
+        let (tx_type, payload) = match bz.first() {
+            Some(&first) if first >= 0xc0 => (LEGACY_TX_TYPE, bz),
+            Some(&tx_type) => {
+                if !is_supported_tx_type(tx_type) {
+                    return Err(Error::UnsupportedTransactionType(tx_type));
+                }
+                (tx_type, &bz[1..])
+            }
+            None => return Err(rlp::DecoderError::RlpIsTooShort.into()),
+        };
+        let r = rlp::Rlp::new(payload);
+        let outcome = r.at(0)?;
+        let outcome = if outcome.size() == 32 {
+            ReceiptOutcome::PostState(H256::from_slice(outcome.data()?))
+        } else {
+            ReceiptOutcome::Status(outcome.as_val::<u8>()? == 1)
+        };
+        Ok(Receipt {
+            tx_type,
+            outcome,
+            cumulative_gas_used: r.val_at::<u64>(1)?,
+            logs_bloom: r.val_at::<Vec<u8>>(2)?,
+            logs: r
+                .at(3)?
+                .iter()
+                .map(|log| -> Result<Log, Error> {
+                    Ok(Log {
+                        address: Address::try_from(log.val_at::<Vec<u8>>(0)?.as_slice())?,
+                        topics: log
+                            .at(1)?
+                            .iter()
+                            .map(|topic| -> Result<H256, Error> {
+                                let topic = topic.data()?;
+                                if topic.len() != 32 {
+                                    return Err(rlp::DecoderError::RlpInvalidLength.into());
+                                }
+                                Ok(H256::from_slice(topic))
+                            })
+                            .collect::<Result<_, Error>>()?,
+                        data: log.val_at::<Vec<u8>>(2)?,
+                    })
+                })
+                .collect::<Result<_, Error>>()?,
+        })
+    }
+
+    /// encode the receipt as it is stored in the receipts trie
+    pub fn to_rlp_bytes(&self) -> Vec<u8> {
+        let mut s = rlp::RlpStream::new_list(4);
+        match &self.outcome {
+            ReceiptOutcome::PostState(root) => s.append(&root.0.to_vec()),
+            ReceiptOutcome::Status(status) => s.append(&(*status as u8)),
+        };
+        s.append(&self.cumulative_gas_used);
+        s.append(&self.logs_bloom);
+        s.begin_list(self.logs.len());
+        for log in self.logs.iter() {
+            s.begin_list(3);
+            s.append(&log.address.0.to_vec());
+            s.begin_list(log.topics.len());
+            for topic in log.topics.iter() {
+                s.append(&topic.0.to_vec());
+            }
+            s.append(&log.data);
+        }
+        let payload = s.out().to_vec();
+        if self.tx_type == LEGACY_TX_TYPE {
+            payload
+        } else {
+            [vec![self.tx_type], payload].concat()
+        }
+    }
+}
+
+pub fn is_supported_tx_type(tx_type: u8) -> bool {
+    matches!(
+        tx_type,
+        LEGACY_TX_TYPE | ACCESS_LIST_TX_TYPE | DYNAMIC_FEE_TX_TYPE | BLOB_TX_TYPE | SET_CODE_TX_TYPE
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn receipt(tx_type: u8) -> Receipt {
+        Receipt {
+            tx_type,
+            outcome: ReceiptOutcome::Status(true),
+            cumulative_gas_used: 21000,
+            logs_bloom: vec![0; 256],
+            logs: vec![Log {
+                address: Address([0xab; 20]),
+                topics: vec![H256([0x01; 32]), H256([0x02; 32])],
+                data: vec![0x2a; 40],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_receipt_encoding() {
+        for tx_type in [
+            LEGACY_TX_TYPE,
+            ACCESS_LIST_TX_TYPE,
+            DYNAMIC_FEE_TX_TYPE,
+            BLOB_TX_TYPE,
+            SET_CODE_TX_TYPE,
+        ] {
+            let receipt = receipt(tx_type);
+            let bz = receipt.to_rlp_bytes();
+            assert_eq!(bz[0] >= 0xc0, tx_type == LEGACY_TX_TYPE);
+            assert_eq!(Receipt::from_rlp_bytes(&bz).unwrap(), receipt);
+        }
+
+        let mut failed = receipt(LEGACY_TX_TYPE);
+        failed.outcome = ReceiptOutcome::Status(false);
+        assert_eq!(Receipt::from_rlp_bytes(&failed.to_rlp_bytes()).unwrap(), failed);
+        let mut pre_byzantium = receipt(LEGACY_TX_TYPE);
+        pre_byzantium.outcome = ReceiptOutcome::PostState(H256([0x03; 32]));
+        assert_eq!(
+            Receipt::from_rlp_bytes(&pre_byzantium.to_rlp_bytes()).unwrap(),
+            pre_byzantium
+        );
+
+        let mut bz = receipt(DYNAMIC_FEE_TX_TYPE).to_rlp_bytes();
+        bz[0] = 0x05;
+        assert!(Receipt::from_rlp_bytes(&bz).is_err());
+    }
+}
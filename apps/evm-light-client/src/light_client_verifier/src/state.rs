@@ -1,4 +1,7 @@
-use super::{errors::Error, updates::ConsensusUpdate};
+use super::{
+    errors::Error,
+    updates::{ConsensusUpdate, OptimisticUpdate},
+};
 use crate::consensus::src::{
     context::ChainContext,
     sync_protocol::{SyncCommittee, SyncCommitteePeriod},
@@ -23,6 +26,12 @@ pub trait LightClientStoreReader<const SYNC_COMMITTEE_SIZE: usize> {
         ctx: &CC,
         update: &C,
     ) -> Result<(), Error>;
+
+    /// Returns a error if the optimistic update does not attest a header after the finalized header of this store.
+    fn ensure_relevant_optimistic_update<OU: OptimisticUpdate<SYNC_COMMITTEE_SIZE>>(
+        &self,
+        update: &OU,
+    ) -> Result<(), Error>;
 }
 
 /// Returns the sync committee corresponding to the given signature period if available
@@ -0,0 +1,323 @@
+use super::errors::Error;
+use crate::consensus::src::types::{Address, H256};
+use patricia_merkle_trie::keccak::keccak_256;
+use primitive_types::U256;
+
+// The helpers follow the Solidity storage layout:
+// https://docs.soliditylang.org/en/latest/internals/layout_in_storage.html
+//
+// Slots computed here are meant to be read with `ExecutionVerifier::verify_account_storage`,
+// and the verified words are then decoded with `StoragePosition` or `decode_bytes`.
+
+/// MappingKey is a key of a Solidity mapping
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MappingKey {
+    /// value type key (integers, addresses, bools and fixed-size bytes), which is padded to 32 bytes
+    Word(H256),
+    /// `string` or `bytes` key, which is hashed without padding
+    Bytes(Vec<u8>),
+}
+
+impl MappingKey {
+    fn encode(&self) -> &[u8] {
+        match self {
+            Self::Word(word) => word.as_bytes(),
+            Self::Bytes(bytes) => bytes,
+        }
+    }
+}
+
+impl From<&Address> for MappingKey {
+    fn from(value: &Address) -> Self {
+        let mut word = [0u8; 32];
+        word[12..].copy_from_slice(&value.0);
+        Self::Word(H256(word))
+    }
+}
+
+impl From<U256> for MappingKey {
+    fn from(value: U256) -> Self {
+        Self::Word(u256_to_word(value))
+    }
+}
+
+impl From<H256> for MappingKey {
+    fn from(value: H256) -> Self {
+        Self::Word(value)
+    }
+}
+
+impl From<&str> for MappingKey {
+    fn from(value: &str) -> Self {
+        Self::Bytes(value.as_bytes().to_vec())
+    }
+}
+
+/// slot of `mapping[key]` where the mapping is declared at `slot`: `keccak256(key . slot)`
+pub fn mapping_slot(slot: H256, key: &MappingKey) -> H256 {
+    let mut preimage = key.encode().to_vec();
+    preimage.extend_from_slice(slot.as_bytes());
+    H256(keccak_256(&preimage))
+}
+
+/// slot of `mapping[keys[0]][keys[1]]...` where the outermost mapping is declared at `slot`
+pub fn nested_mapping_slot(slot: H256, keys: &[MappingKey]) -> H256 {
+    keys.iter().fold(slot, |slot, key| mapping_slot(slot, key))
+}
+
+/// first slot of the elements of a dynamic array declared at `slot`, whose length is stored at `slot` itself
+pub fn dynamic_array_data_slot(slot: H256) -> H256 {
+    H256(keccak_256(slot.as_bytes()))
+}
+
+/// slot `n` slots after `slot`, wrapping around like the EVM does
+pub fn slot_offset(slot: H256, n: U256) -> H256 {
+    u256_to_word(word_to_u256(&slot).overflowing_add(n).0)
+}
+
+/// StoragePosition is the location of a value that may be packed with other values in a storage slot
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StoragePosition {
+    pub slot: H256,
+    /// offset in bytes from the lower-order end of the word
+    pub offset: usize,
+    /// size in bytes
+    pub size: usize,
+}
+
+impl StoragePosition {
+    pub fn new(slot: H256, offset: usize, size: usize) -> Result<Self, Error> {
+        // `offset + size` may overflow
+        if size == 0 || offset >= 32 || size > 32 - offset {
+            return Err(Error::InvalidPackedStorageMember(offset, size));
+        }
+        Ok(Self { slot, offset, size })
+    }
+
+    /// position of a value occupying a whole slot
+    pub fn word(slot: H256) -> Self {
+        Self {
+            slot,
+            offset: 0,
+            size: 32,
+        }
+    }
+
+    /// big-endian bytes of the value in `word`, which is the verified value of `self.slot`
+    pub fn extract<'a>(&self, word: &'a H256) -> &'a [u8] {
+        let end = 32 - self.offset;
+        &word.0[end - self.size..end]
+    }
+
+    pub fn decode_uint(&self, word: &H256) -> U256 {
+        U256::from_big_endian(self.extract(word))
+    }
+
+    pub fn decode_bool(&self, word: &H256) -> bool {
+        !self.decode_uint(word).is_zero()
+    }
+
+    pub fn decode_address(&self, word: &H256) -> Result<Address, Error> {
+        if self.size != 20 {
+            return Err(Error::InvalidPackedStorageMember(self.offset, self.size));
+        }
+        Ok(Address::try_from(self.extract(word))?)
+    }
+}
+
+/// position of a member of a struct stored at `slot`, where the member starts `member_slot` slots
+/// after the struct and is packed at `offset` bytes with `size` bytes
+pub fn struct_member_position(
+    slot: H256,
+    member_slot: u64,
+    offset: usize,
+    size: usize,
+) -> Result<StoragePosition, Error> {
+    StoragePosition::new(slot_offset(slot, member_slot.into()), offset, size)
+}
+
+/// position of `array[index]` where the elements of the array start at `data_slot`
+///
+/// Elements of at most 32 bytes are packed `32 / element_size` to a slot; larger elements, e.g. structs, take
+/// `element_size / 32` slots each and `element_size` must be a multiple of 32.
+/// For a dynamic array, `data_slot` is `dynamic_array_data_slot(slot)`; for a fixed-size array, it is the slot of the array.
+pub fn array_element_position(
+    data_slot: H256,
+    index: u64,
+    element_size: usize,
+) -> Result<StoragePosition, Error> {
+    let index = U256::from(index);
+    match element_size {
+        0 => Err(Error::InvalidArrayElementSize(element_size)),
+        size if size <= 32 => {
+            let per_slot = U256::from(32 / size);
+            let offset = (index % per_slot).as_usize() * size;
+            StoragePosition::new(slot_offset(data_slot, index / per_slot), offset, size)
+        }
+        size if size % 32 == 0 => Ok(StoragePosition::word(slot_offset(
+            data_slot,
+            index.overflowing_mul(U256::from(size / 32)).0,
+        ))),
+        _ => Err(Error::InvalidArrayElementSize(element_size)),
+    }
+}
+
+/// BytesLayout is the layout of a `string` or `bytes` value stored at a slot
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BytesLayout {
+    /// a value of at most 31 bytes is stored in the slot itself
+    Short(Vec<u8>),
+    /// a longer value is stored in `data_slots` starting at `keccak256(slot)`
+    Long { length: usize, data_slots: Vec<H256> },
+}
+
+/// decode the verified `word` of the `string` or `bytes` declared at `slot`
+pub fn decode_bytes_layout(slot: H256, word: &H256) -> Result<BytesLayout, Error> {
+    if word.0[31] & 1 == 0 {
+        let length = (word.0[31] / 2) as usize;
+        if length > 31 {
+            return Err(Error::InvalidStorageBytesEncoding(*word));
+        }
+        return Ok(BytesLayout::Short(word.0[..length].to_vec()));
+    }
+    let length = word_to_u256(word) / 2;
+    if length < U256::from(32) || length > U256::from(u32::MAX) {
+        return Err(Error::InvalidStorageBytesEncoding(*word));
+    }
+    let length = length.as_usize();
+    let data_slot = dynamic_array_data_slot(slot);
+    let data_slots = (0..(length + 31) / 32)
+        .map(|i| slot_offset(data_slot, i.into()))
+        .collect();
+    Ok(BytesLayout::Long { length, data_slots })
+}
+
+/// decode a `string` or `bytes` value from its layout and the verified words of its data slots
+pub fn decode_bytes(layout: &BytesLayout, data: &[H256]) -> Result<Vec<u8>, Error> {
+    match layout {
+        BytesLayout::Short(value) => Ok(value.clone()),
+        BytesLayout::Long { length, data_slots } => {
+            if data.len() != data_slots.len() {
+                return Err(Error::InvalidStorageBytesDataLength(data_slots.len(), data.len()));
+            }
+            let mut value: Vec<u8> = data.iter().flat_map(|word| word.0).collect();
+            value.truncate(*length);
+            Ok(value)
+        }
+    }
+}
+
+/// slot of `balanceOf[holder]` of an ERC-20 token whose balances mapping is declared at `slot`
+pub fn erc20_balance_slot(slot: H256, holder: &Address) -> H256 {
+    mapping_slot(slot, &holder.into())
+}
+
+/// slot of `allowance[owner][spender]` of an ERC-20 token whose allowances mapping is declared at `slot`
+pub fn erc20_allowance_slot(slot: H256, owner: &Address, spender: &Address) -> H256 {
+    nested_mapping_slot(slot, &[owner.into(), spender.into()])
+}
+
+/// slot of `ownerOf[token_id]` of an ERC-721 token whose owners mapping is declared at `slot`
+pub fn erc721_owner_slot(slot: H256, token_id: U256) -> H256 {
+    mapping_slot(slot, &token_id.into())
+}
+
+fn word_to_u256(word: &H256) -> U256 {
+    U256::from_big_endian(word.as_bytes())
+}
+
+fn u256_to_word(value: U256) -> H256 {
+    let mut word = [0u8; 32];
+    value.to_big_endian(&mut word);
+    H256(word)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    fn slot(n: u64) -> H256 {
+        u256_to_word(n.into())
+    }
+
+    #[test]
+    fn test_mapping_slot() {
+        let owner = Address(hex!("abababababababababababababababababababab"));
+        let spender = Address(hex!("cdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcd"));
+        assert_eq!(
+            erc20_balance_slot(slot(0), &owner),
+            H256(hex!("1ca46e16b5e4c6a35293cd5cbda90f0f21759a3bc8478382752cb3b6b41041f3"))
+        );
+        assert_eq!(
+            erc20_allowance_slot(slot(1), &owner, &spender),
+            H256(hex!("b3ba244f3e6c2c8833274ebb5c7708b73f0e404728a229a37ae4f12cab7503b6"))
+        );
+        assert_eq!(
+            mapping_slot(slot(3), &"foo".into()),
+            H256(hex!("73fff2d1123ee7ca409487d5d39aae1fc98260c2c7b7aa404a29f5b8d335b6d5"))
+        );
+    }
+
+    #[test]
+    fn test_array_element_position() {
+        let data_slot = dynamic_array_data_slot(slot(2));
+        assert_eq!(
+            data_slot,
+            H256(hex!("405787fa12a823e0f2b7631cc41b3ba8828b3321ca811111fa75cd3aa3bb5ace"))
+        );
+        // uint64[]: 4 elements per slot
+        let position = array_element_position(data_slot, 5, 8).unwrap();
+        assert_eq!(position.slot, slot_offset(data_slot, 1.into()));
+        assert_eq!(position.offset, 8);
+        // struct of 2 slots
+        let position = array_element_position(data_slot, 3, 64).unwrap();
+        assert_eq!(position.slot, slot_offset(data_slot, 6.into()));
+        assert!(array_element_position(data_slot, 0, 48).is_err());
+
+        // struct { uint128 a; address b; bool c; } packs `c` after `b` in the second slot
+        let position = struct_member_position(slot(4), 1, 20, 1).unwrap();
+        assert_eq!(position.slot, slot(5));
+        let mut word = H256::default();
+        word.0[11] = 1;
+        word.0[12..].copy_from_slice(&[0xab; 20]);
+        assert!(position.decode_bool(&word));
+        assert_eq!(
+            StoragePosition::new(slot(5), 0, 20).unwrap().decode_address(&word).unwrap(),
+            Address([0xab; 20])
+        );
+        assert!(StoragePosition::new(slot(5), 20, 13).is_err());
+        assert!(StoragePosition::new(slot(5), 31, 1).is_ok());
+        assert!(StoragePosition::new(slot(5), 32, 1).is_err());
+        assert!(StoragePosition::new(slot(5), usize::MAX, 1).is_err());
+        assert!(StoragePosition::new(slot(5), 1, usize::MAX).is_err());
+    }
+
+    #[test]
+    fn test_decode_bytes() {
+        let mut word = H256::default();
+        word.0[..3].copy_from_slice(b"foo");
+        word.0[31] = 6;
+        let layout = decode_bytes_layout(slot(0), &word).unwrap();
+        assert_eq!(decode_bytes(&layout, &[]).unwrap(), b"foo".to_vec());
+
+        let value = [0x61u8; 40];
+        let layout = decode_bytes_layout(slot(2), &slot(81)).unwrap();
+        let data_slot = dynamic_array_data_slot(slot(2));
+        assert_eq!(
+            layout,
+            BytesLayout::Long {
+                length: 40,
+                data_slots: vec![data_slot, slot_offset(data_slot, 1.into())],
+            }
+        );
+        let mut second = H256::default();
+        second.0[..8].copy_from_slice(&value[32..]);
+        assert_eq!(
+            decode_bytes(&layout, &[H256(value[..32].try_into().unwrap()), second]).unwrap(),
+            value.to_vec()
+        );
+        assert!(decode_bytes(&layout, &[second]).is_err());
+        assert!(decode_bytes_layout(slot(0), &slot(3)).is_err());
+    }
+}
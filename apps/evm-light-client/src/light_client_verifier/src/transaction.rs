@@ -0,0 +1,314 @@
+use super::errors::Error;
+use super::receipt::{
+    is_supported_tx_type, ACCESS_LIST_TX_TYPE, BLOB_TX_TYPE, DYNAMIC_FEE_TX_TYPE, LEGACY_TX_TYPE,
+    SET_CODE_TX_TYPE,
+};
+use crate::consensus::src::types::{Address, H256};
+use patricia_merkle_trie::keccak::keccak_256;
+use primitive_types::U256;
+
+/// Transaction is a signed transaction decoded from its envelope in an execution payload
+///
+/// The fields that only some transaction types have are `None` or empty for the others.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Transaction {
+    pub tx_type: u8,
+    /// `None` for a legacy transaction without replay protection
+    pub chain_id: Option<u64>,
+    pub nonce: u64,
+    /// legacy and EIP-2930
+    pub gas_price: Option<U256>,
+    /// EIP-1559 and later
+    pub max_priority_fee_per_gas: Option<U256>,
+    pub max_fee_per_gas: Option<U256>,
+    pub gas_limit: u64,
+    /// `None` for a contract creation
+    pub to: Option<Address>,
+    pub value: U256,
+    pub input: Vec<u8>,
+    pub access_list: Vec<AccessListItem>,
+    /// EIP-4844
+    pub max_fee_per_blob_gas: Option<U256>,
+    pub blob_versioned_hashes: Vec<H256>,
+    /// EIP-7702
+    pub authorization_list: Vec<Authorization>,
+    /// `v` for a legacy transaction, otherwise the y parity
+    pub v: u64,
+    pub r: U256,
+    pub s: U256,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AccessListItem {
+    pub address: Address,
+    pub storage_keys: Vec<H256>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Authorization {
+    pub chain_id: U256,
+    pub address: Address,
+    pub nonce: u64,
+    pub y_parity: u64,
+    pub r: U256,
+    pub s: U256,
+}
+
+impl Transaction {
+    /// decode a transaction from its envelope:
+    /// `rlp(tx)` for a legacy transaction, or `tx_type || rlp(tx)` for a typed transaction
+    /// https://eips.ethereum.org/EIPS/eip-2718
+    pub fn from_envelope(bz: &[u8]) -> Result<Transaction, Error> {
+        let tx_type = match bz.first() {
+            Some(&first) if first >= 0xc0 => LEGACY_TX_TYPE,
+            Some(&tx_type) if tx_type != LEGACY_TX_TYPE && is_supported_tx_type(tx_type) => tx_type,
+            Some(&tx_type) => return Err(Error::UnsupportedTransactionType(tx_type)),
+            None => return Err(rlp::DecoderError::RlpIsTooShort.into()),
+        };
+        if tx_type == LEGACY_TX_TYPE {
+            let r = rlp::Rlp::new(bz);
+            expect_item_count(&r, 9)?;
+            let v = r.val_at::<u64>(6)?;
+            return Ok(Transaction {
+                tx_type,
+                // EIP-155: v = chain_id * 2 + 35 + y_parity
+                chain_id: if v >= 35 { Some((v - 35) / 2) } else { None },
+                nonce: r.val_at(0)?,
+                gas_price: Some(decode_u256(&r, 1)?),
+                gas_limit: r.val_at(2)?,
+                to: decode_to(&r, 3)?,
+                value: decode_u256(&r, 4)?,
+                input: r.val_at(5)?,
+                v,
+                r: decode_u256(&r, 7)?,
+                s: decode_u256(&r, 8)?,
+                ..Default::default()
+            });
+        }
+
+        let r = rlp::Rlp::new(&bz[1..]);
+        let mut tx = Transaction {
+            tx_type,
+            chain_id: Some(r.val_at(0)?),
+            nonce: r.val_at(1)?,
+            ..Default::default()
+        };
+        // the index of `gas_limit`, after which the fields are common to all typed transactions up to `access_list`
+        let i = if tx_type == ACCESS_LIST_TX_TYPE {
+            expect_item_count(&r, 11)?;
+            tx.gas_price = Some(decode_u256(&r, 2)?);
+            3
+        } else {
+            expect_item_count(
+                &r,
+                match tx_type {
+                    DYNAMIC_FEE_TX_TYPE => 12,
+                    BLOB_TX_TYPE => 14,
+                    SET_CODE_TX_TYPE => 13,
+                    _ => unreachable!(),
+                },
+            )?;
+            tx.max_priority_fee_per_gas = Some(decode_u256(&r, 2)?);
+            tx.max_fee_per_gas = Some(decode_u256(&r, 3)?);
+            4
+        };
+        tx.gas_limit = r.val_at(i)?;
+        tx.to = decode_to(&r, i + 1)?;
+        tx.value = decode_u256(&r, i + 2)?;
+        tx.input = r.val_at(i + 3)?;
+        tx.access_list = r
+            .at(i + 4)?
+            .iter()
+            .map(|item| -> Result<AccessListItem, Error> {
+                Ok(AccessListItem {
+                    address: Address::try_from(item.val_at::<Vec<u8>>(0)?.as_slice())?,
+                    storage_keys: item
+                        .at(1)?
+                        .iter()
+                        .map(|key| -> Result<H256, Error> { decode_h256(key.data()?) })
+                        .collect::<Result<_, Error>>()?,
+                })
+            })
+            .collect::<Result<_, Error>>()?;
+        let mut i = i + 5;
+        match tx_type {
+            BLOB_TX_TYPE => {
+                if tx.to.is_none() {
+                    return Err(Error::MissingTransactionRecipient(tx_type));
+                }
+                tx.max_fee_per_blob_gas = Some(decode_u256(&r, i)?);
+                tx.blob_versioned_hashes = r
+                    .at(i + 1)?
+                    .iter()
+                    .map(|hash| -> Result<H256, Error> { decode_h256(hash.data()?) })
+                    .collect::<Result<_, Error>>()?;
+                i += 2;
+            }
+            SET_CODE_TX_TYPE => {
+                if tx.to.is_none() {
+                    return Err(Error::MissingTransactionRecipient(tx_type));
+                }
+                tx.authorization_list = r
+                    .at(i)?
+                    .iter()
+                    .map(|auth| -> Result<Authorization, Error> {
+                        expect_item_count(&auth, 6)?;
+                        Ok(Authorization {
+                            chain_id: decode_u256(&auth, 0)?,
+                            address: Address::try_from(auth.val_at::<Vec<u8>>(1)?.as_slice())?,
+                            nonce: auth.val_at(2)?,
+                            y_parity: auth.val_at(3)?,
+                            r: decode_u256(&auth, 4)?,
+                            s: decode_u256(&auth, 5)?,
+                        })
+                    })
+                    .collect::<Result<_, Error>>()?;
+                i += 1;
+            }
+            _ => {}
+        }
+        tx.v = r.val_at(i)?;
+        tx.r = decode_u256(&r, i + 1)?;
+        tx.s = decode_u256(&r, i + 2)?;
+        Ok(tx)
+    }
+}
+
+/// hash of a transaction, which is keccak256 of its envelope
+pub fn transaction_hash(envelope: &[u8]) -> H256 {
+    H256(keccak_256(envelope))
+}
+
+fn expect_item_count(r: &rlp::Rlp, expected: usize) -> Result<(), Error> {
+    let actual = r.item_count()?;
+    if actual != expected {
+        return Err(rlp::DecoderError::RlpIncorrectListLen.into());
+    }
+    Ok(())
+}
+
+/// an integer is encoded as its big-endian bytes without leading zeros
+fn decode_u256(r: &rlp::Rlp, index: usize) -> Result<U256, Error> {
+    let bz = r.at(index)?.data()?;
+    if bz.len() > 32 {
+        return Err(rlp::DecoderError::RlpIsTooBig.into());
+    }
+    if bz.first() == Some(&0) {
+        return Err(rlp::DecoderError::Custom("integer with leading zeros").into());
+    }
+    Ok(U256::from_big_endian(bz))
+}
+
+fn decode_h256(bz: &[u8]) -> Result<H256, Error> {
+    if bz.len() != 32 {
+        return Err(rlp::DecoderError::RlpInvalidLength.into());
+    }
+    Ok(H256::from_slice(bz))
+}
+
+/// the recipient is empty for a contract creation
+fn decode_to(r: &rlp::Rlp, index: usize) -> Result<Option<Address>, Error> {
+    let bz = r.at(index)?.data()?;
+    if bz.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(Address::try_from(bz)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    #[test]
+    fn test_legacy_transaction() {
+        // the example of EIP-155
+        let bz = hex!("f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83");
+        let tx = Transaction::from_envelope(&bz).unwrap();
+        assert_eq!(tx.tx_type, LEGACY_TX_TYPE);
+        assert_eq!(tx.chain_id, Some(1));
+        assert_eq!(tx.nonce, 9);
+        assert_eq!(tx.gas_price, Some(U256::from(20_000_000_000u64)));
+        assert_eq!(tx.gas_limit, 21000);
+        assert_eq!(tx.to, Some(Address([0x35; 20])));
+        assert_eq!(tx.value, U256::from(1_000_000_000_000_000_000u64));
+        assert_eq!(tx.v, 37);
+    }
+
+    #[test]
+    fn test_typed_transaction() {
+        let mut s = rlp::RlpStream::new_list(13);
+        s.append(&1u64);
+        s.append(&2u64);
+        s.append(&3u64);
+        s.append(&4u64);
+        s.append(&21000u64);
+        s.append(&[0xab; 20].to_vec());
+        s.append(&0u64);
+        s.append(&vec![0x01u8, 0x02]);
+        s.begin_list(1);
+        s.begin_list(2);
+        s.append(&[0xcd; 20].to_vec());
+        s.begin_list(1);
+        s.append(&[0x01; 32].to_vec());
+        s.begin_list(1);
+        s.begin_list(6);
+        s.append(&1u64);
+        s.append(&[0xef; 20].to_vec());
+        s.append(&5u64);
+        s.append(&1u64);
+        s.append(&6u64);
+        s.append(&7u64);
+        s.append(&1u64);
+        s.append(&8u64);
+        s.append(&9u64);
+        let bz = [vec![SET_CODE_TX_TYPE], s.out().to_vec()].concat();
+
+        let tx = Transaction::from_envelope(&bz).unwrap();
+        assert_eq!(tx.tx_type, SET_CODE_TX_TYPE);
+        assert_eq!(tx.chain_id, Some(1));
+        assert_eq!(tx.max_fee_per_gas, Some(U256::from(4)));
+        assert_eq!(tx.to, Some(Address([0xab; 20])));
+        assert_eq!(tx.input, vec![0x01, 0x02]);
+        assert_eq!(tx.access_list[0].storage_keys, vec![H256([0x01; 32])]);
+        assert_eq!(tx.authorization_list[0].address, Address([0xef; 20]));
+        assert_eq!(tx.authorization_list[0].nonce, 5);
+        assert_eq!((tx.v, tx.r, tx.s), (1, U256::from(8), U256::from(9)));
+
+        // the same fields are one too few for a blob transaction
+        let bz = [vec![BLOB_TX_TYPE], bz[1..].to_vec()].concat();
+        assert!(Transaction::from_envelope(&bz).is_err());
+    }
+
+    #[test]
+    fn test_transaction_inclusion() {
+        use crate::consensus::src::{
+            compute::hash_tree_root, fork::deneb::prover::gen_transaction_proof, types::ByteList,
+        };
+        use crate::light_client_verifier::src::execution::ExecutionVerifier;
+
+        let envelopes: Vec<Vec<u8>> = (1..=3u8).map(|i| vec![i; 20 * i as usize]).collect();
+        let transactions = ssz_rs::List::<ByteList<64>, 16>::try_from(
+            envelopes
+                .iter()
+                .map(|tx| ByteList::try_from(tx.as_slice()).unwrap())
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
+        let transactions_root = hash_tree_root(transactions.clone()).unwrap();
+
+        let (root, branch) = gen_transaction_proof(&transactions, 1).unwrap();
+        assert_eq!(root, transactions_root);
+        assert!(ExecutionVerifier
+            .verify_transaction(root, 1, &envelopes[1], &branch, 64, 16)
+            .is_ok());
+        assert!(ExecutionVerifier
+            .verify_transaction(root, 1, &envelopes[0], &branch, 64, 16)
+            .is_err());
+        assert!(ExecutionVerifier
+            .verify_transaction(root, 3, &envelopes[1], &branch, 64, 16)
+            .is_err());
+        assert!(gen_transaction_proof(&transactions, 3).is_err());
+    }
+}
@@ -11,6 +11,9 @@ use crate::consensus::src::{
 pub mod bellatrix;
 pub mod capella;
 pub mod deneb;
+pub mod electra;
+pub mod fulu;
+pub mod versioned;
 
 pub trait LightClientBootstrap<const SYNC_COMMITTEE_SIZE: usize>:
     core::fmt::Debug + Clone + PartialEq + Eq
@@ -100,6 +103,54 @@ pub trait ConsensusUpdate<const SYNC_COMMITTEE_SIZE: usize>:
     }
 }
 
+/// OptimisticUpdate is an update info of the consensus layer whose attested header is not finalized yet
+///
+/// ref. https://github.com/ethereum/consensus-specs/blob/087e7378b44f327cdad4549304fc308613b780c3/specs/altair/light-client/sync-protocol.md#lightclientoptimisticupdate
+pub trait OptimisticUpdate<const SYNC_COMMITTEE_SIZE: usize>:
+    core::fmt::Debug + Clone + PartialEq + Eq
+{
+    /// header attested to by the sync committee
+    fn attested_beacon_header(&self) -> &BeaconBlockHeader;
+    /// root of execution payload corresponding to `attested_beacon_header.body_root`
+    fn attested_execution_root(&self) -> H256;
+    /// merkle branch of `execution_payload` within `BeaconBlockBody`
+    fn attested_execution_branch(&self) -> Vec<H256>;
+    /// sync committee aggregate signature
+    fn sync_aggregate(&self) -> &SyncAggregate<SYNC_COMMITTEE_SIZE>;
+    /// slot at which the aggregate signature was created (untrusted)
+    fn signature_slot(&self) -> Slot;
+
+    /// ref. https://github.com/ethereum/consensus-specs/blob/087e7378b44f327cdad4549304fc308613b780c3/specs/altair/light-client/sync-protocol.md#is_valid_light_client_header
+    fn is_valid_light_client_attested_header<C: ChainConsensusVerificationContext>(
+        &self,
+        ctx: &C,
+    ) -> Result<(), Error> {
+        let spec = ctx.compute_fork_spec(self.attested_beacon_header().slot);
+        is_valid_normalized_merkle_branch(
+            self.attested_execution_root(),
+            &self.attested_execution_branch(),
+            spec.execution_payload_gindex,
+            self.attested_beacon_header().body_root,
+        )
+        .map_err(Error::InvalidAttestedExecutionPayload)
+    }
+
+    /// validate the basic properties of the update
+    fn validate_basic<C: ConsensusVerificationContext>(&self, ctx: &C) -> Result<(), Error> {
+        // `assert current_slot >= update.signature_slot > update.attested_header.beacon.slot`
+        if !(ctx.current_slot() >= self.signature_slot()
+            && self.signature_slot() > self.attested_beacon_header().slot)
+        {
+            return Err(Error::InconsistentOptimisticSlotOrder(
+                ctx.current_slot(),
+                self.signature_slot(),
+                self.attested_beacon_header().slot,
+            ));
+        }
+        Ok(())
+    }
+}
+
 /// ExecutionUpdate is an update info of the execution payload
 pub trait ExecutionUpdate: core::fmt::Debug + Clone + PartialEq + Eq {
     /// `state_root` of the execution payload
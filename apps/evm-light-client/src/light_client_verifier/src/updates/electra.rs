@@ -0,0 +1,3 @@
+// the electra light client containers only differ from deneb in the length of their merkle branches,
+// and the gindices and depths that check them are taken from `ELECTRA_FORK_SPEC`, so the deneb update types are used as they are
+pub use super::deneb::{ConsensusUpdateInfo, ExecutionUpdateInfo, LightClientBootstrapInfo};
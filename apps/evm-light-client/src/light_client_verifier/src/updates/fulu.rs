@@ -0,0 +1,2 @@
+// fulu does not modify the light client containers, so the electra update types are used as they are
+pub use super::electra::{ConsensusUpdateInfo, ExecutionUpdateInfo, LightClientBootstrapInfo};
@@ -0,0 +1,316 @@
+use super::{capella, deneb, electra, ConsensusUpdate, LightClientBootstrap, OptimisticUpdate};
+use crate::consensus::src::{
+    beacon::{BeaconBlockHeader, Slot},
+    compute::hash_tree_root,
+    fork::versioned::ExecutionPayloadHeader,
+    sync_protocol::{SyncAggregate, SyncCommittee},
+    types::H256,
+};
+
+/// LightClientBootstrapInfo is a light client bootstrap of any fork that the light client can be initialized with
+///
+/// The variant is selected by the `version` of the beacon API response; `fulu` is decoded as electra.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "version", content = "data", rename_all = "lowercase")]
+pub enum LightClientBootstrapInfo<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+> {
+    Capella(
+        capella::LightClientBootstrapInfo<
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >,
+    ),
+    Deneb(
+        deneb::LightClientBootstrapInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    ),
+    #[serde(alias = "fulu")]
+    Electra(
+        electra::LightClientBootstrapInfo<
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >,
+    ),
+}
+
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    > LightClientBootstrapInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
+{
+    /// execution payload header corresponding to `beacon_header.body_root`
+    pub fn execution_payload_header(
+        &self,
+    ) -> ExecutionPayloadHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES> {
+        match self {
+            Self::Capella(bootstrap) => {
+                ExecutionPayloadHeader::Capella(bootstrap.header.execution.clone())
+            }
+            Self::Deneb(bootstrap) => ExecutionPayloadHeader::Deneb(bootstrap.header.execution.clone()),
+            Self::Electra(bootstrap) => {
+                ExecutionPayloadHeader::Electra(bootstrap.header.execution.clone())
+            }
+        }
+    }
+}
+
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    > LightClientBootstrap<SYNC_COMMITTEE_SIZE>
+    for LightClientBootstrapInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
+{
+    fn beacon_header(&self) -> &BeaconBlockHeader {
+        match self {
+            Self::Capella(bootstrap) => bootstrap.beacon_header(),
+            Self::Deneb(bootstrap) => bootstrap.beacon_header(),
+            Self::Electra(bootstrap) => bootstrap.beacon_header(),
+        }
+    }
+    fn current_sync_committee(&self) -> &SyncCommittee<SYNC_COMMITTEE_SIZE> {
+        match self {
+            Self::Capella(bootstrap) => bootstrap.current_sync_committee(),
+            Self::Deneb(bootstrap) => bootstrap.current_sync_committee(),
+            Self::Electra(bootstrap) => bootstrap.current_sync_committee(),
+        }
+    }
+    fn current_sync_committee_branch(&self) -> Vec<H256> {
+        match self {
+            Self::Capella(bootstrap) => bootstrap.current_sync_committee_branch(),
+            Self::Deneb(bootstrap) => bootstrap.current_sync_committee_branch(),
+            Self::Electra(bootstrap) => bootstrap.current_sync_committee_branch(),
+        }
+    }
+}
+
+/// ConsensusUpdateInfo is a consensus update of any fork that the light client can process
+///
+/// The variant is selected by the `version` of the beacon API response; `fulu` is decoded as electra.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "version", content = "data", rename_all = "lowercase")]
+pub enum ConsensusUpdateInfo<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+> {
+    Capella(
+        capella::ConsensusUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    ),
+    Deneb(deneb::ConsensusUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>),
+    #[serde(alias = "fulu")]
+    Electra(
+        electra::ConsensusUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    ),
+}
+
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    > ConsensusUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
+{
+    /// execution payload header corresponding to `finalized_beacon_header.body_root`
+    pub fn finalized_execution_payload_header(
+        &self,
+    ) -> ExecutionPayloadHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES> {
+        match self {
+            Self::Capella(update) => {
+                ExecutionPayloadHeader::Capella(update.finalized_header.execution.clone())
+            }
+            Self::Deneb(update) => {
+                ExecutionPayloadHeader::Deneb(update.finalized_header.execution.clone())
+            }
+            Self::Electra(update) => {
+                ExecutionPayloadHeader::Electra(update.finalized_header.execution.clone())
+            }
+        }
+    }
+}
+
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    > ConsensusUpdate<SYNC_COMMITTEE_SIZE>
+    for ConsensusUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
+{
+    fn attested_beacon_header(&self) -> &BeaconBlockHeader {
+        match self {
+            Self::Capella(update) => update.attested_beacon_header(),
+            Self::Deneb(update) => update.attested_beacon_header(),
+            Self::Electra(update) => update.attested_beacon_header(),
+        }
+    }
+    fn next_sync_committee(&self) -> Option<&SyncCommittee<SYNC_COMMITTEE_SIZE>> {
+        match self {
+            Self::Capella(update) => update.next_sync_committee(),
+            Self::Deneb(update) => update.next_sync_committee(),
+            Self::Electra(update) => update.next_sync_committee(),
+        }
+    }
+    fn next_sync_committee_branch(&self) -> Option<Vec<H256>> {
+        match self {
+            Self::Capella(update) => update.next_sync_committee_branch(),
+            Self::Deneb(update) => update.next_sync_committee_branch(),
+            Self::Electra(update) => update.next_sync_committee_branch(),
+        }
+    }
+    fn finalized_beacon_header(&self) -> &BeaconBlockHeader {
+        match self {
+            Self::Capella(update) => update.finalized_beacon_header(),
+            Self::Deneb(update) => update.finalized_beacon_header(),
+            Self::Electra(update) => update.finalized_beacon_header(),
+        }
+    }
+    fn finalized_beacon_header_branch(&self) -> Vec<H256> {
+        match self {
+            Self::Capella(update) => update.finalized_beacon_header_branch(),
+            Self::Deneb(update) => update.finalized_beacon_header_branch(),
+            Self::Electra(update) => update.finalized_beacon_header_branch(),
+        }
+    }
+    fn finalized_execution_root(&self) -> H256 {
+        match self {
+            Self::Capella(update) => update.finalized_execution_root(),
+            Self::Deneb(update) => update.finalized_execution_root(),
+            Self::Electra(update) => update.finalized_execution_root(),
+        }
+    }
+    fn finalized_execution_branch(&self) -> Vec<H256> {
+        match self {
+            Self::Capella(update) => update.finalized_execution_branch(),
+            Self::Deneb(update) => update.finalized_execution_branch(),
+            Self::Electra(update) => update.finalized_execution_branch(),
+        }
+    }
+    fn sync_aggregate(&self) -> &SyncAggregate<SYNC_COMMITTEE_SIZE> {
+        match self {
+            Self::Capella(update) => update.sync_aggregate(),
+            Self::Deneb(update) => update.sync_aggregate(),
+            Self::Electra(update) => update.sync_aggregate(),
+        }
+    }
+    fn signature_slot(&self) -> Slot {
+        match self {
+            Self::Capella(update) => update.signature_slot(),
+            Self::Deneb(update) => update.signature_slot(),
+            Self::Electra(update) => update.signature_slot(),
+        }
+    }
+}
+
+/// LightClientOptimisticUpdate is a header attested to by the sync committee of a fork whose light client header is `H`
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LightClientOptimisticUpdate<const SYNC_COMMITTEE_SIZE: usize, H> {
+    pub attested_header: H,
+    pub sync_aggregate: SyncAggregate<SYNC_COMMITTEE_SIZE>,
+    pub signature_slot: Slot,
+}
+
+/// OptimisticUpdateInfo is an optimistic update of any fork that the light client can process
+///
+/// The variant is selected by the `version` of the beacon API response; `fulu` is decoded as electra.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "version", content = "data", rename_all = "lowercase")]
+pub enum OptimisticUpdateInfo<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+> {
+    Capella(
+        LightClientOptimisticUpdate<
+            SYNC_COMMITTEE_SIZE,
+            crate::consensus::src::fork::capella::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        >,
+    ),
+    Deneb(
+        LightClientOptimisticUpdate<
+            SYNC_COMMITTEE_SIZE,
+            crate::consensus::src::fork::deneb::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        >,
+    ),
+    #[serde(alias = "fulu")]
+    Electra(
+        LightClientOptimisticUpdate<
+            SYNC_COMMITTEE_SIZE,
+            crate::consensus::src::fork::electra::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        >,
+    ),
+}
+
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    > OptimisticUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
+{
+    /// execution payload header corresponding to `attested_beacon_header.body_root`
+    pub fn attested_execution_payload_header(
+        &self,
+    ) -> ExecutionPayloadHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES> {
+        match self {
+            Self::Capella(update) => {
+                ExecutionPayloadHeader::Capella(update.attested_header.execution.clone())
+            }
+            Self::Deneb(update) => {
+                ExecutionPayloadHeader::Deneb(update.attested_header.execution.clone())
+            }
+            Self::Electra(update) => {
+                ExecutionPayloadHeader::Electra(update.attested_header.execution.clone())
+            }
+        }
+    }
+}
+
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    > OptimisticUpdate<SYNC_COMMITTEE_SIZE>
+    for OptimisticUpdateInfo<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
+{
+    fn attested_beacon_header(&self) -> &BeaconBlockHeader {
+        match self {
+            Self::Capella(update) => &update.attested_header.beacon,
+            Self::Deneb(update) => &update.attested_header.beacon,
+            Self::Electra(update) => &update.attested_header.beacon,
+        }
+    }
+    fn attested_execution_root(&self) -> H256 {
+        match self {
+            Self::Capella(update) => hash_tree_root(update.attested_header.execution.clone()),
+            Self::Deneb(update) => hash_tree_root(update.attested_header.execution.clone()),
+            Self::Electra(update) => hash_tree_root(update.attested_header.execution.clone()),
+        }
+        .unwrap()
+        .0
+        .into()
+    }
+    fn attested_execution_branch(&self) -> Vec<H256> {
+        match self {
+            Self::Capella(update) => update.attested_header.execution_branch.to_vec(),
+            Self::Deneb(update) => update.attested_header.execution_branch.to_vec(),
+            Self::Electra(update) => update.attested_header.execution_branch.to_vec(),
+        }
+    }
+    fn sync_aggregate(&self) -> &SyncAggregate<SYNC_COMMITTEE_SIZE> {
+        match self {
+            Self::Capella(update) => &update.sync_aggregate,
+            Self::Deneb(update) => &update.sync_aggregate,
+            Self::Electra(update) => &update.sync_aggregate,
+        }
+    }
+    fn signature_slot(&self) -> Slot {
+        match self {
+            Self::Capella(update) => update.signature_slot,
+            Self::Deneb(update) => update.signature_slot,
+            Self::Electra(update) => update.signature_slot,
+        }
+    }
+}
@@ -1,47 +1,175 @@
 use super::errors::Error;
+use super::ssz;
 use super::types::{
-    BeaconBlockResponse, BeaconBlockRootResponse, BeaconHeaderResponse,
+    BeaconBlockPayloadResponse, BeaconBlockResponse, BeaconBlockRootResponse, BeaconHeaderResponse,
     FinalityCheckpointsResponse, GenesisDataResponse, LightClientBootstrapResponse,
-    LightClientFinalityUpdateResponse, LightClientUpdatesResponse,
+    LightClientFinalityUpdateResponse, LightClientOptimisticUpdateResponse, LightClientUpdatesResponse,
+    SyncingStatusResponse,
 };
-use crate::consensus::src::beacon::Slot;
+use crate::consensus::src::beacon::{Epoch, ForkDigest, Slot};
 use crate::consensus::src::sync_protocol::SyncCommitteePeriod;
 use crate::consensus::src::types::H256;
 use log::debug;
 use url::Url;
 use serde::de::DeserializeOwned;
-use crate::klave_client::src::client::Client as KlaveClient;
+use crate::klave_client::src::client::{Client as KlaveClient, HttpStatusError};
 use http;
+use http::header::{HeaderValue, ACCEPT, CONTENT_TYPE};
+use std::cell::RefCell;
 
 type Result<T> = core::result::Result<T, Error>;
 
+/// Header carrying the fork of a versioned beacon API response
+const ETH_CONSENSUS_VERSION_HEADER: &str = "eth-consensus-version";
+
+/// Light client responses are requested as SSZ, with JSON as the fallback
+const ACCEPT_SSZ: &str = "application/octet-stream;q=1.0,application/json;q=0.9";
+
+const SSZ_CONTENT_TYPE: &str = "application/octet-stream";
+
+/// Number of attempts on an endpoint before failing over to the next one
+const MAX_ATTEMPTS_PER_ENDPOINT: usize = 2;
+
+/// Number of epochs an endpoint may see finality after the others without being stale,
+/// as the endpoints do not import the same blocks at the same time
+const MAX_FINALIZED_EPOCH_LAG: u64 = 1;
+
+/// EndpointHealth is the health of a beacon endpoint observed during the lifetime of the client
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct EndpointHealth {
+    pub endpoint: String,
+    pub healthy: bool,
+    pub failures: u64,
+    pub last_error: Option<String>,
+}
+
 pub struct RPCClient {
     http_client: KlaveClient,
-    endpoint: String,
+    /// endpoints in order of preference
+    endpoints: Vec<String>,
+    health: RefCell<Vec<EndpointHealth>>,
+    /// index of the endpoint that served the last successful request
+    served_by: RefCell<Option<usize>>,
+    /// fork of the SSZ encoded updates of a range, which are only tagged with their fork digest
+    fork_digests: Vec<(ForkDigest, String)>,
 }
 
 impl RPCClient {
-    pub fn new(endpoint: impl Into<String>) -> Self {
-        let url = Url::parse(&endpoint.into()).expect("Invalid URL");
-        if url.scheme() != "http" && url.scheme() != "https" {
-            panic!("Invalid URL scheme: {}", url.scheme());
-        }
-        if url.path() != "/" {
-            panic!("Invalid URL path: {}", url.path());
-        }
-        if url.host().is_none() {
-            panic!("Invalid URL host: {}", url.host().unwrap());
+    /// `endpoint` can be a comma separated list of endpoints, which are tried in order
+    pub fn new(endpoint: impl Into<String>) -> Result<Self> {
+        let endpoint = endpoint.into();
+        let endpoints: Vec<&str> = endpoint
+            .split(',')
+            .map(str::trim)
+            .filter(|e| !e.is_empty())
+            .collect();
+        if endpoints.is_empty() {
+            Self::with_endpoints([endpoint.as_str()])
+        } else {
+            Self::with_endpoints(endpoints)
         }
-        if url.query().is_some() {
-            panic!("Invalid URL query: {}", url.query().unwrap());
-        }
-        if url.fragment().is_some() {
-            panic!("Invalid URL fragment: {}", url.fragment().unwrap());
-        }
-        Self {
+    }
+
+    pub fn with_endpoints<I, S>(endpoints: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let endpoints: Vec<String> = endpoints
+            .into_iter()
+            .map(|endpoint| parse_endpoint(endpoint.into()))
+            .collect::<Result<_>>()?;
+        Ok(Self {
             http_client: KlaveClient::new(),
-            endpoint: url.as_str().strip_suffix("/").unwrap().to_string(),
+            health: RefCell::new(
+                endpoints
+                    .iter()
+                    .map(|endpoint| EndpointHealth {
+                        endpoint: endpoint.clone(),
+                        healthy: true,
+                        failures: 0,
+                        last_error: None,
+                    })
+                    .collect(),
+            ),
+            endpoints,
+            served_by: RefCell::new(None),
+            fork_digests: vec![],
+        })
+    }
+
+    /// Without the fork digests, a range of updates is requested as JSON
+    pub fn with_fork_digests(mut self, fork_digests: Vec<(ForkDigest, String)>) -> Self {
+        self.fork_digests = fork_digests;
+        self
+    }
+
+    /// the primary endpoint
+    pub fn endpoint(&self) -> &str {
+        &self.endpoints[0]
+    }
+
+    pub fn endpoints(&self) -> &[String] {
+        &self.endpoints
+    }
+
+    /// the endpoint that served the last successful request
+    pub fn served_by(&self) -> Option<String> {
+        self.served_by.borrow().map(|i| self.endpoints[i].clone())
+    }
+
+    pub fn endpoint_health(&self) -> Vec<EndpointHealth> {
+        self.health.borrow().clone()
+    }
+
+    /// marks the endpoints that are syncing or whose finalized checkpoint is more than `MAX_FINALIZED_EPOCH_LAG`
+    /// epochs behind the others as unhealthy
+    pub fn check_health(&self) {
+        if self.endpoints.len() < 2 {
+            return;
         }
+        let finalized_epochs: Vec<_> = self
+            .endpoints
+            .iter()
+            .enumerate()
+            .map(|(i, endpoint)| {
+                match self.request_get_from::<SyncingStatusResponse>(endpoint, "/eth/v1/node/syncing", None) {
+                    Ok(res) if res.data.is_syncing => {
+                        self.mark_unhealthy(i, format!("syncing: sync_distance={}", res.data.sync_distance));
+                        return None;
+                    }
+                    Ok(_) => (),
+                    Err(e) => {
+                        self.mark_unhealthy(i, e.to_string());
+                        return None;
+                    }
+                }
+                match self.request_get_from::<FinalityCheckpointsResponse>(
+                    endpoint,
+                    "/eth/v1/beacon/states/head/finality_checkpoints",
+                    None,
+                ) {
+                    Ok(res) => Some(res.data.finalized.epoch),
+                    Err(e) => {
+                        self.mark_unhealthy(i, e.to_string());
+                        None
+                    }
+                }
+            })
+            .collect();
+        for (i, epoch, latest) in stale_endpoints(&finalized_epochs) {
+            self.mark_unhealthy(
+                i,
+                format!("stale finalized checkpoint: epoch={} latest={}", epoch, latest),
+            );
+        }
+    }
+
+    fn mark_unhealthy(&self, i: usize, error: String) {
+        let mut health = self.health.borrow_mut();
+        health[i].healthy = false;
+        health[i].failures += 1;
+        health[i].last_error = Some(error);
     }
 
     // Beacon API
@@ -65,6 +193,28 @@ impl RPCClient {
             
     }
 
+    /// unlike `get_beacon_block_by_slot`, decodes the blocks of electra and later forks, up to their execution payload
+    pub fn get_beacon_block_payload_by_slot<
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const MAX_BYTES_PER_TRANSACTION: usize,
+        const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+    >(
+        &self,
+        slot: Slot,
+    ) -> Result<
+        BeaconBlockPayloadResponse<
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BYTES_PER_TRANSACTION,
+            MAX_TRANSACTIONS_PER_PAYLOAD,
+            MAX_WITHDRAWALS_PER_PAYLOAD,
+        >,
+    > {
+        self.request_get(format!("/eth/v2/beacon/blocks/{}", slot), None)
+    }
+
     pub fn get_finality_checkpoints(&self) -> Result<FinalityCheckpointsResponse> {
         self.request_get("/eth/v1/beacon/states/head/finality_checkpoints", None)
             
@@ -85,8 +235,31 @@ impl RPCClient {
             MAX_EXTRA_DATA_BYTES,
         >,
     > {
-        self.request_get("/eth/v1/beacon/light_client/finality_update", None)
-            
+        self.request_get_ssz(
+            "/eth/v1/beacon/light_client/finality_update",
+            |version, bz| ssz::decode_finality_update(version.unwrap_or_default(), bz),
+            None,
+        )
+    }
+
+    pub fn get_optimistic_update<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    >(
+        &self,
+    ) -> Result<
+        LightClientOptimisticUpdateResponse<
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >,
+    > {
+        self.request_get_ssz(
+            "/eth/v1/beacon/light_client/optimistic_update",
+            |version, bz| ssz::decode_optimistic_update(version.unwrap_or_default(), bz),
+            None,
+        )
     }
 
     pub fn get_bootstrap<
@@ -103,11 +276,11 @@ impl RPCClient {
             MAX_EXTRA_DATA_BYTES,
         >,
     > {
-        self.request_get(format!(
-            "/eth/v1/beacon/light_client/bootstrap/0x{}",
-            finalized_root
-        ), None)
-        
+        self.request_get_ssz(
+            format!("/eth/v1/beacon/light_client/bootstrap/0x{}", finalized_root),
+            |version, bz| ssz::decode_bootstrap(version.unwrap_or_default(), bz),
+            None,
+        )
     }
 
     pub fn get_light_client_updates<
@@ -123,12 +296,10 @@ impl RPCClient {
     > {
         let count = if count < 1 { 1 } else { count };
         for c in (1..=count).rev() {
-            let res = self
-                .request_get(format!(
-                    "/eth/v1/beacon/light_client/updates?start_period={}&count={}",
-                    start_period, c
-                ), None)
-                ;
+            let res = self.request_get_updates(format!(
+                "/eth/v1/beacon/light_client/updates?start_period={}&count={}",
+                start_period, c
+            ));
             if res.is_ok()
                 || !res
                     .as_ref()
@@ -155,16 +326,120 @@ impl RPCClient {
         LightClientUpdatesResponse<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
     > {
         let count = if count < 1 { 1 } else { count };
-        self.request_get(format!(
+        self.request_get_updates(format!(
             "/eth/v1/beacon/light_client/updates?start_period={}&count={}",
             start_period, count
-        ), None)
-        
+        ))
     }
 
     // Helper functions
     fn request_get<T: DeserializeOwned>(&self, path: impl Into<String>, display: Option<bool>) -> Result<T> {
-        let url_str = format!("{}{}", self.endpoint, path.into());
+        let res = self.send_get(path, None, display)?;
+        Ok(serde_json::from_slice(res.body()).map_err(Error::JSONDecodeError)?)
+    }
+
+    fn request_get_from<T: DeserializeOwned>(&self, endpoint: &str, path: &str, display: Option<bool>) -> Result<T> {
+        let res = self.send_get_to(endpoint, path, None, display)?;
+        Ok(serde_json::from_slice(res.body()).map_err(Error::JSONDecodeError)?)
+    }
+
+    /// Decodes a response whose payload depends on the fork.
+    /// Some beacon nodes only report the fork in the `Eth-Consensus-Version` header, so it is used
+    /// as the `version` of the response if the body does not carry one.
+    fn request_get_versioned<T: DeserializeOwned>(&self, path: impl Into<String>, display: Option<bool>) -> Result<T> {
+        let res = self.send_get(path, None, display)?;
+        decode_versioned_json(&res)
+    }
+
+    /// Requests the SSZ encoding of a light client response and decodes it with `decode`, which is given
+    /// the fork of the `Eth-Consensus-Version` header.
+    /// The JSON encoding is used instead if the endpoint does not serve SSZ, or if the payload cannot be decoded.
+    fn request_get_ssz<T: DeserializeOwned>(
+        &self,
+        path: impl Into<String>,
+        decode: impl Fn(Option<&str>, &[u8]) -> Result<T>,
+        display: Option<bool>,
+    ) -> Result<T> {
+        let path = path.into();
+        let res = self.send_get(path.as_str(), Some(ACCEPT_SSZ), display)?;
+        if !is_ssz(&res) {
+            return decode_versioned_json(&res);
+        }
+        match decode(consensus_version(&res).as_deref(), res.body()) {
+            Ok(value) => Ok(value),
+            Err(e) => {
+                debug!("ssz decode failed, falling back to json: path={} error={}", path, e);
+                self.request_get_versioned(path, display)
+            }
+        }
+    }
+
+    fn request_get_updates<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    >(
+        &self,
+        path: String,
+    ) -> Result<
+        LightClientUpdatesResponse<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    > {
+        if self.fork_digests.is_empty() {
+            return self.request_get(path, None);
+        }
+        self.request_get_ssz(path, |_, bz| ssz::decode_updates(bz, &self.fork_digests), None)
+    }
+
+    /// Sends the request to the healthy endpoints first, in order of preference.
+    fn send_get(
+        &self,
+        path: impl Into<String>,
+        accept: Option<&str>,
+        display: Option<bool>,
+    ) -> Result<http::Response<Vec<u8>>> {
+        let path = path.into();
+        self.failover(|endpoint| self.send_get_to(endpoint, &path, accept, display))
+    }
+
+    /// An endpoint is retried and then marked unhealthy only if it is unavailable; any other error,
+    /// e.g. a 404 for a period without update, is returned as is and leaves the endpoint healthy.
+    fn failover<T>(&self, send: impl Fn(&str) -> Result<T>) -> Result<T> {
+        let order: Vec<usize> = {
+            let health = self.health.borrow();
+            let (healthy, unhealthy): (Vec<usize>, Vec<usize>) =
+                (0..self.endpoints.len()).partition(|i| health[*i].healthy);
+            healthy.into_iter().chain(unhealthy).collect()
+        };
+        let mut last_error = None;
+        for i in order {
+            for _ in 0..MAX_ATTEMPTS_PER_ENDPOINT {
+                match send(&self.endpoints[i]) {
+                    Ok(res) => {
+                        self.served_by.replace(Some(i));
+                        return Ok(res);
+                    }
+                    Err(e @ (Error::EndpointUnavailable(_) | Error::RPCInternalServerError(_))) => {
+                        last_error = Some(e)
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+            if let Some(e) = last_error.as_ref() {
+                debug!("endpoint unavailable, failing over: endpoint={} error={}", self.endpoints[i], e);
+                self.mark_unhealthy(i, e.to_string());
+            }
+        }
+        Err(last_error.unwrap_or_else(|| Error::EndpointUnavailable("no endpoint available".into())))
+    }
+
+    fn send_get_to(
+        &self,
+        endpoint: &str,
+        path: &str,
+        accept: Option<&str>,
+        display: Option<bool>,
+    ) -> Result<http::Response<Vec<u8>>> {
+        let url_str = format!("{}{}", endpoint, path);
         debug!("request_get: url={}", url_str);        
         let url = match Url::parse(url_str.as_str()) {
             Ok(url) => url,
@@ -174,28 +449,110 @@ impl RPCClient {
                 });
             }
         };
-        let res = match self.http_client.get(url).send(display.unwrap_or(false)) {
+        let mut req = self.http_client.get(url);
+        if let Some(accept) = accept {
+            req = req.header(ACCEPT, HeaderValue::from_str(accept).map_err(|e| Error::HTTPError(e.into()))?);
+        }
+        let res = match req.send_bytes(display.unwrap_or(false)) {
             Ok(res) => res,
-            Err(e) => {
-                return Err(Error::Other {
-                    description: format!("{}, {}", e.to_string(), url_str),
-                });
-            }
+            // the transport only returns the response of a 2xx status, the others come back as an error
+            Err(e) => match e.downcast_ref::<HttpStatusError>() {
+                Some(e) => return Err(status_error(e.status, &e.body, &url_str)),
+                None => {
+                    return Err(Error::EndpointUnavailable(format!("{}, {}", e.to_string(), url_str)));
+                }
+            },
         };
-        match res.status() {
-            http::StatusCode::OK => {
-                let bytes = res.body().as_bytes();
-                debug!("request_get: response={}", String::from_utf8_lossy(&bytes));
-                Ok(serde_json::from_slice(&bytes).map_err(Error::JSONDecodeError)?)
-            }
-            http::StatusCode::INTERNAL_SERVER_ERROR => Err(Error::RPCInternalServerError(
-                serde_json::from_str::<InternalServerError>(res.body()).unwrap().message,
-            )),
-            _ => Err(Error::Other {
-                description: res.body().to_string(),
-            }),
+        if is_ssz(&res) {
+            debug!("request_get: response={} bytes", res.body().len());
+        } else {
+            debug!("request_get: response={}", String::from_utf8_lossy(res.body()));
+        }
+        Ok(res)
+    }
+}
+
+/// only a server error or a timeout makes the endpoint unavailable, a client error is an answer of the endpoint
+fn status_error(status: http::StatusCode, body: &str, url: &str) -> Error {
+    let message = || match serde_json::from_str::<InternalServerError>(body) {
+        Ok(e) => e.message,
+        Err(_) => body.to_string(),
+    };
+    if status == http::StatusCode::INTERNAL_SERVER_ERROR {
+        Error::RPCInternalServerError(format!("{}, {}", message(), url))
+    } else if status.is_server_error() || status == http::StatusCode::REQUEST_TIMEOUT {
+        Error::EndpointUnavailable(format!("{}, {}, {}", status, message(), url))
+    } else {
+        Error::Other {
+            description: body.to_string(),
+        }
+    }
+}
+
+fn is_ssz(res: &http::Response<Vec<u8>>) -> bool {
+    res.headers()
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .map_or(false, |v| v.starts_with(SSZ_CONTENT_TYPE))
+}
+
+fn consensus_version(res: &http::Response<Vec<u8>>) -> Option<String> {
+    res.headers()
+        .get(ETH_CONSENSUS_VERSION_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_lowercase)
+}
+
+fn decode_versioned_json<T: DeserializeOwned>(res: &http::Response<Vec<u8>>) -> Result<T> {
+    let mut value: serde_json::Value =
+        serde_json::from_slice(res.body()).map_err(Error::JSONDecodeError)?;
+    if let Some(object) = value.as_object_mut() {
+        if !object.contains_key("version") {
+            let version = consensus_version(res).ok_or_else(|| Error::Other {
+                description: "consensus version not found in the response".into(),
+            })?;
+            object.insert("version".into(), version.into());
         }
     }
+    Ok(serde_json::from_value(value).map_err(Error::JSONDecodeError)?)
+}
+
+/// the endpoints whose finalized epoch lags the latest one by more than `MAX_FINALIZED_EPOCH_LAG`,
+/// with their epoch and the latest one
+fn stale_endpoints(finalized_epochs: &[Option<Epoch>]) -> Vec<(usize, Epoch, Epoch)> {
+    let Some(latest) = finalized_epochs.iter().flatten().max().cloned() else {
+        return Vec::new();
+    };
+    finalized_epochs
+        .iter()
+        .enumerate()
+        .filter_map(|(i, epoch)| match epoch {
+            Some(epoch) if epoch.0 + MAX_FINALIZED_EPOCH_LAG < latest.0 => Some((i, *epoch, latest)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// validates the endpoint and strips the trailing slash
+fn parse_endpoint(endpoint: String) -> Result<String> {
+    let invalid = |reason: String| Error::InvalidEndpoint(format!("{}: {}", reason, endpoint));
+    let url = Url::parse(&endpoint).map_err(|e| invalid(e.to_string()))?;
+    if url.scheme() != "http" && url.scheme() != "https" {
+        return Err(invalid(format!("invalid scheme {}", url.scheme())));
+    }
+    if url.path() != "/" {
+        return Err(invalid(format!("invalid path {}", url.path())));
+    }
+    if url.host().is_none() {
+        return Err(invalid("missing host".into()));
+    }
+    if url.query().is_some() {
+        return Err(invalid("unexpected query".into()));
+    }
+    if url.fragment().is_some() {
+        return Err(invalid("unexpected fragment".into()));
+    }
+    Ok(url.as_str().trim_end_matches('/').to_string())
 }
 
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -205,3 +562,129 @@ struct InternalServerError {
     error: Option<String>,
     message: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consensus::src::fork::deneb;
+    use crate::consensus::src::sync_protocol::SyncAggregate;
+    use crate::lodestar_rpc::src::types::LightClientOptimisticUpdateData;
+
+    #[test]
+    fn test_failover() {
+        let client = RPCClient::with_endpoints(["http://a", "http://b"]).unwrap();
+        let not_found = || status_error(http::StatusCode::NOT_FOUND, "No partialUpdate available for period 1", "http://a");
+        let res: Result<()> = client.failover(|_| Err(not_found()));
+        assert!(res.unwrap_err().to_string().contains("No partialUpdate available for period"));
+        assert!(client.endpoint_health().iter().all(|health| health.healthy && health.failures == 0));
+
+        let res = client.failover(|endpoint| match endpoint {
+            "http://a" => Err(status_error(http::StatusCode::SERVICE_UNAVAILABLE, "", endpoint)),
+            _ => Ok(endpoint.to_string()),
+        });
+        assert_eq!(res.unwrap(), "http://b");
+        assert_eq!(client.served_by().as_deref(), Some("http://b"));
+        let health = client.endpoint_health();
+        assert!(!health[0].healthy && health[0].failures == 1);
+        assert!(health[1].healthy);
+
+        assert!(matches!(
+            status_error(http::StatusCode::GATEWAY_TIMEOUT, "", "http://a"),
+            Error::EndpointUnavailable(_)
+        ));
+        assert!(matches!(
+            status_error(http::StatusCode::BAD_REQUEST, "", "http://a"),
+            Error::Other { .. }
+        ));
+    }
+
+    #[test]
+    fn test_internal_server_error() {
+        let body = r#"{"statusCode":500,"error":"Internal Server Error","message":"state not available"}"#;
+        let e = status_error(http::StatusCode::INTERNAL_SERVER_ERROR, body, "http://a");
+        assert_eq!(e.code(), "RPC_INTERNAL_SERVER_ERROR");
+        assert!(e.to_string().contains("state not available"));
+
+        // an internal server error fails over, and is returned if every endpoint fails with it
+        let client = RPCClient::with_endpoints(["http://a", "http://b"]).unwrap();
+        let res: Result<()> =
+            client.failover(|endpoint| Err(status_error(http::StatusCode::INTERNAL_SERVER_ERROR, body, endpoint)));
+        assert_eq!(res.unwrap_err().code(), "RPC_INTERNAL_SERVER_ERROR");
+        assert!(client.endpoint_health().iter().all(|health| !health.healthy));
+    }
+
+    #[test]
+    fn test_parse_endpoint() {
+        assert_eq!(parse_endpoint("http://localhost:9596/".into()).unwrap(), "http://localhost:9596");
+        assert_eq!(parse_endpoint("https://a.example".into()).unwrap(), "https://a.example");
+        for endpoint in ["localhost:9596", "ftp://a.example", "http://a.example/eth", "http://a.example?k=v", "not a url"] {
+            let e = parse_endpoint(endpoint.into()).unwrap_err();
+            assert_eq!(e.code(), "RPC_INVALID_ENDPOINT", "{}", endpoint);
+        }
+        assert!(RPCClient::new("http://a, http://b/eth").is_err());
+        assert_eq!(RPCClient::new("http://a, http://b").unwrap().endpoints(), ["http://a", "http://b"]);
+    }
+
+    type OptimisticUpdate = LightClientOptimisticUpdateResponse<32, 256, 32>;
+
+    /// a JSON response of an optimistic update, the `version` of the body is only set if given
+    fn json_response(version: Option<&str>, header: Option<&str>) -> http::Response<Vec<u8>> {
+        let data = LightClientOptimisticUpdateData {
+            attested_header: deneb::LightClientHeader::<256, 32>::default(),
+            sync_aggregate: SyncAggregate::<32>::default(),
+            signature_slot: 9.into(),
+        };
+        let mut body = serde_json::json!({ "data": data });
+        if let Some(version) = version {
+            body["version"] = version.into();
+        }
+        let mut builder = http::Response::builder().header(CONTENT_TYPE, "application/json");
+        if let Some(header) = header {
+            builder = builder.header(ETH_CONSENSUS_VERSION_HEADER, header);
+        }
+        builder.body(serde_json::to_vec(&body).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_decode_versioned_json() {
+        let decode = |version, header| decode_versioned_json::<OptimisticUpdate>(&json_response(version, header));
+        assert!(matches!(decode(Some("deneb"), None).unwrap(), OptimisticUpdate::Deneb(_)));
+        assert!(matches!(decode(Some("electra"), None).unwrap(), OptimisticUpdate::Electra(_)));
+        // fulu did not change the light client types
+        assert!(matches!(decode(Some("fulu"), None).unwrap(), OptimisticUpdate::Electra(_)));
+        // the version of the body takes precedence over the header
+        assert!(matches!(decode(Some("deneb"), Some("electra")).unwrap(), OptimisticUpdate::Deneb(_)));
+        assert!(matches!(decode(Some("gloas"), None), Err(Error::JSONDecodeError(_))));
+    }
+
+    #[test]
+    fn test_consensus_version_header_fallback() {
+        let decode = |header| decode_versioned_json::<OptimisticUpdate>(&json_response(None, header));
+        assert!(matches!(decode(Some("deneb")).unwrap(), OptimisticUpdate::Deneb(_)));
+        // the header is case-insensitive
+        assert!(matches!(decode(Some("Fulu")).unwrap(), OptimisticUpdate::Electra(_)));
+        match decode(None).unwrap_err() {
+            Error::Other { description } => assert!(description.contains("consensus version not found")),
+            e => panic!("unexpected error: {}", e),
+        }
+
+        let res = json_response(None, Some("ELECTRA"));
+        assert_eq!(consensus_version(&res).as_deref(), Some("electra"));
+        assert!(!is_ssz(&res));
+        let res = http::Response::builder()
+            .header(CONTENT_TYPE, SSZ_CONTENT_TYPE)
+            .body(vec![])
+            .unwrap();
+        assert!(is_ssz(&res));
+        assert_eq!(consensus_version(&res), None);
+    }
+
+    #[test]
+    fn test_stale_endpoints() {
+        // a lag of one epoch is the normal lag between endpoints
+        let epochs = [Some(10.into()), Some(9.into()), None, Some(8.into())];
+        assert_eq!(stale_endpoints(&epochs), vec![(3, 8.into(), 10.into())]);
+        assert!(stale_endpoints(&[Some(10.into()), Some(9.into())]).is_empty());
+        assert!(stale_endpoints(&[None, None]).is_empty());
+    }
+}
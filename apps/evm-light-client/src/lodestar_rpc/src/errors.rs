@@ -6,8 +6,20 @@ pub enum Error {
     HTTPError(http::Error),
     /// RPC internal server error: `{0}`
     RPCInternalServerError(String),
+    /// endpoint unavailable: `{0}`
+    EndpointUnavailable(String),
+    /// invalid endpoint: `{0}`
+    InvalidEndpoint(String),
     /// json decode error: `{0}`
     JSONDecodeError(serde_json::Error),
+    /// ssz decode error: `{0:?}`
+    SSZDecodeError(ssz_rs::DeserializeError),
+    /// ssz encode error: `{0:?}`
+    SSZEncodeError(ssz_rs::SerializeError),
+    /// unknown consensus version: `{0}`
+    UnknownConsensusVersion(String),
+    /// unknown fork digest: `{0}`
+    UnknownForkDigest(String),
     /// other error: `{description}`
     Other { description: String },
 }
@@ -18,4 +30,45 @@ impl From<http::Error> for Error {
     }
 }
 
+impl From<ssz_rs::DeserializeError> for Error {
+    fn from(value: ssz_rs::DeserializeError) -> Self {
+        Self::SSZDecodeError(value)
+    }
+}
+
+impl From<ssz_rs::SerializeError> for Error {
+    fn from(value: ssz_rs::SerializeError) -> Self {
+        Self::SSZEncodeError(value)
+    }
+}
+
 impl std::error::Error for Error {}
+
+impl Error {
+    /// stable code of the error kind
+    pub fn code(&self) -> &'static str {
+        match self {
+            Error::HTTPError(_) => "RPC_HTTP_ERROR",
+            Error::RPCInternalServerError(_) => "RPC_INTERNAL_SERVER_ERROR",
+            Error::EndpointUnavailable(_) => "RPC_UNAVAILABLE",
+            Error::InvalidEndpoint(_) => "RPC_INVALID_ENDPOINT",
+            Error::JSONDecodeError(_) => "RPC_JSON_DECODE_ERROR",
+            Error::SSZDecodeError(_) => "RPC_SSZ_DECODE_ERROR",
+            Error::SSZEncodeError(_) => "RPC_SSZ_ENCODE_ERROR",
+            Error::UnknownConsensusVersion(_) => "RPC_UNKNOWN_CONSENSUS_VERSION",
+            Error::UnknownForkDigest(_) => "RPC_UNKNOWN_FORK_DIGEST",
+            Error::Other { .. } => "RPC_ERROR",
+        }
+    }
+
+    /// structured fields of the error
+    pub fn details(&self) -> serde_json::Value {
+        use serde_json::json;
+        match self {
+            Error::UnknownConsensusVersion(version) => json!({"version": version}),
+            Error::UnknownForkDigest(fork_digest) => json!({"fork_digest": fork_digest}),
+            Error::InvalidEndpoint(endpoint) => json!({"endpoint": endpoint}),
+            _ => serde_json::Value::Null,
+        }
+    }
+}
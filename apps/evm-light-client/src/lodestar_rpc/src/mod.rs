@@ -1,3 +1,4 @@
 pub mod client;
 pub mod errors;
+pub mod ssz;
 pub mod types;
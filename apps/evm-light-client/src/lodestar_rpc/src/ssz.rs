@@ -0,0 +1,517 @@
+use super::errors::Error;
+use super::types::{
+    LightClientBootstrapData, LightClientBootstrapResponse, LightClientFinalityUpdateData,
+    LightClientFinalityUpdateResponse, LightClientOptimisticUpdateData,
+    LightClientOptimisticUpdateResponse, LightClientUpdateData, LightClientUpdateResponse,
+    LightClientUpdatesResponse,
+};
+use crate::consensus::src::{
+    beacon::{BeaconBlockHeader, ForkDigest, Slot},
+    fork,
+    sync_protocol::{SyncAggregate, SyncCommittee},
+    types::H256,
+};
+use ssz_rs::{Deserialize, SimpleSerialize, Sized, Vector};
+use ssz_rs_derive::SimpleSerialize;
+
+/// floorlog2(EXECUTION_PAYLOAD_GINDEX)
+const EXECUTION_PAYLOAD_DEPTH: usize = 4;
+
+/// Defines the SSZ containers of the light client API payloads of a fork.
+/// They only differ from the JSON payloads in that the branches are fixed length vectors.
+/// https://github.com/ethereum/consensus-specs/blob/dev/specs/capella/light-client/sync-protocol.md#containers
+macro_rules! light_client_containers {
+    (
+        $fork:ident,
+        current_sync_committee_depth = $current_sync_committee_depth:expr,
+        next_sync_committee_depth = $next_sync_committee_depth:expr,
+        finality_depth = $finality_depth:expr
+    ) => {
+        pub mod $fork {
+            use super::*;
+
+            #[derive(Clone, Debug, Default, PartialEq, Eq, SimpleSerialize)]
+            pub struct LightClientHeader<
+                const BYTES_PER_LOGS_BLOOM: usize,
+                const MAX_EXTRA_DATA_BYTES: usize,
+            > {
+                pub beacon: BeaconBlockHeader,
+                pub execution:
+                    fork::$fork::ExecutionPayloadHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+                pub execution_branch: Vector<H256, EXECUTION_PAYLOAD_DEPTH>,
+            }
+
+            impl<const BYTES_PER_LOGS_BLOOM: usize, const MAX_EXTRA_DATA_BYTES: usize>
+                From<LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>
+                for fork::$fork::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
+            {
+                fn from(value: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>) -> Self {
+                    Self {
+                        beacon: value.beacon,
+                        execution: value.execution,
+                        execution_branch: value.execution_branch.to_vec(),
+                    }
+                }
+            }
+
+            impl<const BYTES_PER_LOGS_BLOOM: usize, const MAX_EXTRA_DATA_BYTES: usize>
+                TryFrom<fork::$fork::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>
+                for LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
+            {
+                type Error = Error;
+
+                fn try_from(
+                    value: fork::$fork::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+                ) -> Result<Self, Error> {
+                    Ok(Self {
+                        beacon: value.beacon,
+                        execution: value.execution,
+                        execution_branch: to_branch(value.execution_branch)?,
+                    })
+                }
+            }
+
+            #[derive(Clone, Debug, Default, PartialEq, Eq, SimpleSerialize)]
+            pub struct LightClientBootstrap<
+                const SYNC_COMMITTEE_SIZE: usize,
+                const BYTES_PER_LOGS_BLOOM: usize,
+                const MAX_EXTRA_DATA_BYTES: usize,
+            > {
+                pub header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+                pub current_sync_committee: SyncCommittee<SYNC_COMMITTEE_SIZE>,
+                pub current_sync_committee_branch: Vector<H256, $current_sync_committee_depth>,
+            }
+
+            impl<
+                    const SYNC_COMMITTEE_SIZE: usize,
+                    const BYTES_PER_LOGS_BLOOM: usize,
+                    const MAX_EXTRA_DATA_BYTES: usize,
+                >
+                From<LightClientBootstrap<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>
+                for LightClientBootstrapData<
+                    SYNC_COMMITTEE_SIZE,
+                    fork::$fork::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+                >
+            {
+                fn from(
+                    value: LightClientBootstrap<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+                ) -> Self {
+                    Self {
+                        header: value.header.into(),
+                        current_sync_committee: value.current_sync_committee,
+                        current_sync_committee_branch: value.current_sync_committee_branch.to_vec(),
+                    }
+                }
+            }
+
+            #[derive(Clone, Debug, Default, PartialEq, Eq, SimpleSerialize)]
+            pub struct LightClientUpdate<
+                const SYNC_COMMITTEE_SIZE: usize,
+                const BYTES_PER_LOGS_BLOOM: usize,
+                const MAX_EXTRA_DATA_BYTES: usize,
+            > {
+                pub attested_header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+                pub next_sync_committee: SyncCommittee<SYNC_COMMITTEE_SIZE>,
+                pub next_sync_committee_branch: Vector<H256, $next_sync_committee_depth>,
+                pub finalized_header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+                pub finality_branch: Vector<H256, $finality_depth>,
+                pub sync_aggregate: SyncAggregate<SYNC_COMMITTEE_SIZE>,
+                pub signature_slot: Slot,
+            }
+
+            impl<
+                    const SYNC_COMMITTEE_SIZE: usize,
+                    const BYTES_PER_LOGS_BLOOM: usize,
+                    const MAX_EXTRA_DATA_BYTES: usize,
+                >
+                From<LightClientUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>
+                for LightClientUpdateData<
+                    SYNC_COMMITTEE_SIZE,
+                    fork::$fork::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+                >
+            {
+                fn from(
+                    value: LightClientUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+                ) -> Self {
+                    Self {
+                        attested_header: value.attested_header.into(),
+                        next_sync_committee: value.next_sync_committee,
+                        next_sync_committee_branch: value.next_sync_committee_branch.to_vec(),
+                        finalized_header: value.finalized_header.into(),
+                        finality_branch: value.finality_branch.to_vec(),
+                        sync_aggregate: value.sync_aggregate,
+                        signature_slot: value.signature_slot,
+                    }
+                }
+            }
+
+            /// a missing next sync committee is encoded as zeroes, as the API does
+            impl<
+                    const SYNC_COMMITTEE_SIZE: usize,
+                    const BYTES_PER_LOGS_BLOOM: usize,
+                    const MAX_EXTRA_DATA_BYTES: usize,
+                >
+                TryFrom<fork::$fork::LightClientUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>
+                for LightClientUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
+            {
+                type Error = Error;
+
+                fn try_from(
+                    value: fork::$fork::LightClientUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+                ) -> Result<Self, Error> {
+                    let (next_sync_committee, next_sync_committee_branch) = match value.next_sync_committee {
+                        Some((committee, branch)) => (committee, to_branch(branch)?),
+                        None => Default::default(),
+                    };
+                    Ok(Self {
+                        attested_header: value.attested_header.try_into()?,
+                        next_sync_committee,
+                        next_sync_committee_branch,
+                        finalized_header: value.finalized_header.try_into()?,
+                        finality_branch: to_branch(value.finality_branch)?,
+                        sync_aggregate: value.sync_aggregate,
+                        signature_slot: value.signature_slot,
+                    })
+                }
+            }
+
+            #[derive(Clone, Debug, Default, PartialEq, Eq, SimpleSerialize)]
+            pub struct LightClientFinalityUpdate<
+                const SYNC_COMMITTEE_SIZE: usize,
+                const BYTES_PER_LOGS_BLOOM: usize,
+                const MAX_EXTRA_DATA_BYTES: usize,
+            > {
+                pub attested_header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+                pub finalized_header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+                pub finality_branch: Vector<H256, $finality_depth>,
+                pub sync_aggregate: SyncAggregate<SYNC_COMMITTEE_SIZE>,
+                pub signature_slot: Slot,
+            }
+
+            impl<
+                    const SYNC_COMMITTEE_SIZE: usize,
+                    const BYTES_PER_LOGS_BLOOM: usize,
+                    const MAX_EXTRA_DATA_BYTES: usize,
+                >
+                From<LightClientFinalityUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>
+                for LightClientFinalityUpdateData<
+                    SYNC_COMMITTEE_SIZE,
+                    fork::$fork::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+                >
+            {
+                fn from(
+                    value: LightClientFinalityUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+                ) -> Self {
+                    Self {
+                        attested_header: value.attested_header.into(),
+                        finalized_header: value.finalized_header.into(),
+                        finality_branch: value.finality_branch.to_vec(),
+                        sync_aggregate: value.sync_aggregate,
+                        signature_slot: value.signature_slot,
+                    }
+                }
+            }
+
+            #[derive(Clone, Debug, Default, PartialEq, Eq, SimpleSerialize)]
+            pub struct LightClientOptimisticUpdate<
+                const SYNC_COMMITTEE_SIZE: usize,
+                const BYTES_PER_LOGS_BLOOM: usize,
+                const MAX_EXTRA_DATA_BYTES: usize,
+            > {
+                pub attested_header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+                pub sync_aggregate: SyncAggregate<SYNC_COMMITTEE_SIZE>,
+                pub signature_slot: Slot,
+            }
+
+            impl<
+                    const SYNC_COMMITTEE_SIZE: usize,
+                    const BYTES_PER_LOGS_BLOOM: usize,
+                    const MAX_EXTRA_DATA_BYTES: usize,
+                >
+                From<LightClientOptimisticUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>
+                for LightClientOptimisticUpdateData<
+                    SYNC_COMMITTEE_SIZE,
+                    fork::$fork::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+                >
+            {
+                fn from(
+                    value: LightClientOptimisticUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+                ) -> Self {
+                    Self {
+                        attested_header: value.attested_header.into(),
+                        sync_aggregate: value.sync_aggregate,
+                        signature_slot: value.signature_slot,
+                    }
+                }
+            }
+        }
+    };
+}
+
+light_client_containers!(
+    capella,
+    current_sync_committee_depth = 5,
+    next_sync_committee_depth = 5,
+    finality_depth = 6
+);
+light_client_containers!(
+    deneb,
+    current_sync_committee_depth = 5,
+    next_sync_committee_depth = 5,
+    finality_depth = 6
+);
+// the gindices of the beacon state fields moved in electra
+light_client_containers!(
+    electra,
+    current_sync_committee_depth = 6,
+    next_sync_committee_depth = 6,
+    finality_depth = 7
+);
+
+/// a merkle branch must have the depth of the container it is encoded in
+fn to_branch<const DEPTH: usize>(branch: Vec<H256>) -> Result<Vector<H256, DEPTH>, Error> {
+    if branch.len() != DEPTH {
+        return Err(Error::Other {
+            description: format!("branch of length {} where {} is expected", branch.len(), DEPTH),
+        });
+    }
+    Ok(Vector::from_iter(branch))
+}
+
+pub fn encode<T: SimpleSerialize>(value: &T) -> Result<Vec<u8>, Error> {
+    Ok(ssz_rs::serialize(value)?)
+}
+
+/// Decodes the SSZ encoded payload of the fork given by the `Eth-Consensus-Version` header.
+/// `fulu` payloads are identical to electra.
+macro_rules! decode_versioned {
+    ($response:ident, $container:ident, $version:expr, $bz:expr) => {
+        match $version {
+            "capella" => $response::Capella(capella::$container::deserialize($bz)?.into()),
+            "deneb" => $response::Deneb(deneb::$container::deserialize($bz)?.into()),
+            "electra" | "fulu" => $response::Electra(electra::$container::deserialize($bz)?.into()),
+            version => return Err(Error::UnknownConsensusVersion(version.into())),
+        }
+    };
+}
+
+pub fn decode_bootstrap<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    version: &str,
+    bz: &[u8],
+) -> Result<
+    LightClientBootstrapResponse<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    Error,
+> {
+    Ok(decode_versioned!(LightClientBootstrapResponse, LightClientBootstrap, version, bz))
+}
+
+pub fn decode_finality_update<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    version: &str,
+    bz: &[u8],
+) -> Result<
+    LightClientFinalityUpdateResponse<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    Error,
+> {
+    Ok(decode_versioned!(LightClientFinalityUpdateResponse, LightClientFinalityUpdate, version, bz))
+}
+
+pub fn decode_optimistic_update<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    version: &str,
+    bz: &[u8],
+) -> Result<
+    LightClientOptimisticUpdateResponse<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    Error,
+> {
+    Ok(decode_versioned!(LightClientOptimisticUpdateResponse, LightClientOptimisticUpdate, version, bz))
+}
+
+pub fn decode_update<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    version: &str,
+    bz: &[u8],
+) -> Result<
+    LightClientUpdateResponse<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    Error,
+> {
+    Ok(decode_versioned!(LightClientUpdateResponse, LightClientUpdate, version, bz))
+}
+
+/// Decodes a range of updates, where each update is a chunk of
+/// `length (uint64, little-endian) || fork_digest || ssz(update)`, and `length` covers the fork digest and the update.
+/// The fork of each update is looked up by its fork digest in `fork_digests`.
+/// https://github.com/ethereum/beacon-APIs/blob/master/apis/beacon/light_client/updates.yaml
+pub fn decode_updates<
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    bz: &[u8],
+    fork_digests: &[(ForkDigest, String)],
+) -> Result<
+    LightClientUpdatesResponse<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    Error,
+> {
+    let mut updates = vec![];
+    let mut rest = bz;
+    while !rest.is_empty() {
+        let truncated = || Error::Other {
+            description: format!("truncated update chunk: offset={}", bz.len() - rest.len()),
+        };
+        if rest.len() < 8 {
+            return Err(truncated());
+        }
+        let (length, chunk) = rest.split_at(8);
+        let length = u64::from_le_bytes(length.try_into().unwrap()) as usize;
+        if length < 4 || chunk.len() < length {
+            return Err(truncated());
+        }
+        let (digest, update) = chunk[..length].split_at(4);
+        let version = fork_digests
+            .iter()
+            .find(|(fork_digest, _)| fork_digest.0 == digest)
+            .map(|(_, version)| version)
+            .ok_or_else(|| Error::UnknownForkDigest(format!("0x{}", hex::encode(digest))))?;
+        updates.push(decode_update(version, update)?);
+        rest = &chunk[length..];
+    }
+    Ok(LightClientUpdatesResponse(updates))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Updates = LightClientUpdatesResponse<32, 256, 32>;
+
+    /// encodes an update and a bootstrap of the fork, and checks that they decode to the same values as the JSON payloads
+    macro_rules! assert_round_trip {
+        ($fork:ident, $version:expr, $variant:ident) => {{
+            let mut update = $fork::LightClientUpdate::<32, 256, 32>::default();
+            update.attested_header.beacon.slot = 96.into();
+            update.finalized_header.beacon.slot = 64.into();
+            update.finality_branch =
+                Vector::from_iter(vec![H256([1; 32]); update.finality_branch.len()]);
+            update.next_sync_committee_branch =
+                Vector::from_iter(vec![H256([2; 32]); update.next_sync_committee_branch.len()]);
+            update.signature_slot = 97.into();
+            let bz = encode(&update).unwrap();
+            let decoded = decode_update::<32, 256, 32>($version, &bz).unwrap();
+            assert_eq!(
+                serde_json::to_value(&decoded).unwrap(),
+                serde_json::to_value(&LightClientUpdateResponse::$variant(update.clone().into())).unwrap()
+            );
+
+            // without a next sync committee, the branch is encoded as zeroes
+            update.next_sync_committee_branch = Default::default();
+            let LightClientUpdateResponse::$variant(data) =
+                decode_update::<32, 256, 32>($version, &encode(&update).unwrap()).unwrap()
+            else {
+                panic!("unexpected fork");
+            };
+            let data: fork::$fork::LightClientUpdate<32, 256, 32> = data.into();
+            assert!(data.next_sync_committee.is_none());
+            assert_eq!($fork::LightClientUpdate::try_from(data).unwrap(), update);
+
+            let mut bootstrap = $fork::LightClientBootstrap::<32, 256, 32>::default();
+            bootstrap.header.beacon.slot = 64.into();
+            bootstrap.current_sync_committee_branch =
+                Vector::from_iter(vec![H256([3; 32]); bootstrap.current_sync_committee_branch.len()]);
+            let decoded = decode_bootstrap::<32, 256, 32>($version, &encode(&bootstrap).unwrap()).unwrap();
+            assert_eq!(
+                serde_json::to_value(&decoded).unwrap(),
+                serde_json::to_value(&LightClientBootstrapResponse::$variant(bootstrap.into())).unwrap()
+            );
+            bz
+        }};
+    }
+
+    fn chunk(digest: [u8; 4], update: &[u8]) -> Vec<u8> {
+        let mut chunk = ((update.len() + 4) as u64).to_le_bytes().to_vec();
+        chunk.extend_from_slice(&digest);
+        chunk.extend_from_slice(update);
+        chunk
+    }
+
+    #[test]
+    fn test_decode_round_trip() {
+        let capella = assert_round_trip!(capella, "capella", Capella);
+        let deneb = assert_round_trip!(deneb, "deneb", Deneb);
+        let electra = assert_round_trip!(electra, "electra", Electra);
+        let fulu = assert_round_trip!(electra, "fulu", Electra);
+
+        // a branch must have the depth of the fork
+        assert!(matches!(
+            electra::LightClientUpdate::try_from(fork::deneb::LightClientUpdate::<32, 256, 32>::default()),
+            Err(Error::Other { .. })
+        ));
+
+        // the branches of electra are one level deeper, so the encodings of the forks are not interchangeable
+        assert!(decode_update::<32, 256, 32>("deneb", &electra).is_err());
+        assert!(decode_update::<32, 256, 32>("electra", &deneb).is_err());
+        assert!(matches!(
+            decode_update::<32, 256, 32>("altair", &capella),
+            Err(Error::UnknownConsensusVersion(_))
+        ));
+
+        // a range spanning the forks, where fulu has one digest per blob schedule entry
+        let digests: Vec<_> = [
+            ([1, 0, 0, 0], "capella"),
+            ([2, 0, 0, 0], "deneb"),
+            ([3, 0, 0, 0], "electra"),
+            ([4, 0, 0, 0], "fulu"),
+            ([4, 0, 0, 1], "fulu"),
+        ]
+        .into_iter()
+        .map(|(digest, version)| (ForkDigest(digest), version.to_string()))
+        .collect();
+        let bz = [
+            chunk([1, 0, 0, 0], &capella),
+            chunk([2, 0, 0, 0], &deneb),
+            chunk([3, 0, 0, 0], &electra),
+            chunk([4, 0, 0, 0], &fulu),
+            chunk([4, 0, 0, 1], &fulu),
+        ]
+        .concat();
+        let updates: Updates = decode_updates(&bz, &digests).unwrap();
+        let versions: Vec<_> = updates
+            .0
+            .iter()
+            .map(|update| serde_json::to_value(update).unwrap()["version"].clone())
+            .collect();
+        assert_eq!(versions, ["capella", "deneb", "electra", "electra", "electra"]);
+    }
+
+    #[test]
+    fn test_decode_updates_chunks() {
+        assert!(decode_updates::<32, 256, 32>(&[], &[]).unwrap().0.is_empty());
+
+        let digests = vec![(ForkDigest([1, 2, 3, 4]), "deneb".to_string())];
+        // the length prefix is shorter than 8 bytes
+        let res: Result<Updates, _> = decode_updates(&[4, 0, 0], &digests);
+        assert!(matches!(res, Err(Error::Other { .. })));
+        // the chunk is shorter than its length
+        let res: Result<Updates, _> = decode_updates(&[8, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4], &digests);
+        assert!(matches!(res, Err(Error::Other { .. })));
+        // the fork digest is not known
+        let res: Result<Updates, _> = decode_updates(&chunk([5, 6, 7, 8], &[]), &digests);
+        assert!(matches!(res, Err(Error::UnknownForkDigest(_))));
+        // the update itself is not a valid encoding
+        let res: Result<Updates, _> = decode_updates(&chunk([1, 2, 3, 4], &[]), &digests);
+        assert!(matches!(res, Err(Error::SSZDecodeError(_))));
+    }
+}
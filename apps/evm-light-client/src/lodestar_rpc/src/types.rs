@@ -1,7 +1,7 @@
 use crate::consensus::src::{
     beacon::{BeaconBlockHeader, Checkpoint, Root, Slot},
     bls::Signature,
-    fork::deneb::{LightClientBootstrap, LightClientHeader, LightClientUpdate},
+    fork::{capella, deneb, electra},
     preset::mainnet::DenebBeaconBlock,
     sync_protocol::{SyncAggregate, SyncCommittee},
     types::{H256, U64},
@@ -62,6 +62,127 @@ pub struct BeaconBlockData {
     pub signature: Signature,
 }
 
+/// Since electra, the attestations of a block no longer decode as a deneb block, while its execution payload
+/// is unchanged. The blocks of deneb and later forks are decoded up to their execution payload.
+/// `fulu` blocks are decoded as electra.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "version", content = "data", rename_all = "lowercase")]
+pub enum BeaconBlockPayloadResponse<
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const MAX_BYTES_PER_TRANSACTION: usize,
+    const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+> {
+    Deneb(
+        BeaconBlockPayloadData<
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BYTES_PER_TRANSACTION,
+            MAX_TRANSACTIONS_PER_PAYLOAD,
+            MAX_WITHDRAWALS_PER_PAYLOAD,
+        >,
+    ),
+    #[serde(alias = "fulu")]
+    Electra(
+        BeaconBlockPayloadData<
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BYTES_PER_TRANSACTION,
+            MAX_TRANSACTIONS_PER_PAYLOAD,
+            MAX_WITHDRAWALS_PER_PAYLOAD,
+        >,
+    ),
+}
+
+impl<
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const MAX_BYTES_PER_TRANSACTION: usize,
+        const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+    >
+    BeaconBlockPayloadResponse<
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+    >
+{
+    pub fn slot(&self) -> Slot {
+        match self {
+            Self::Deneb(data) | Self::Electra(data) => data.message.slot,
+        }
+    }
+
+    pub fn execution_payload(
+        &self,
+    ) -> &deneb::ExecutionPayload<
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+    > {
+        match self {
+            Self::Deneb(data) | Self::Electra(data) => &data.message.body.execution_payload,
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BeaconBlockPayloadData<
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const MAX_BYTES_PER_TRANSACTION: usize,
+    const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+> {
+    pub message: BeaconBlockPayloadMessage<
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+    >,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BeaconBlockPayloadMessage<
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const MAX_BYTES_PER_TRANSACTION: usize,
+    const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+> {
+    pub slot: Slot,
+    pub body: BeaconBlockPayloadBody<
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+    >,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BeaconBlockPayloadBody<
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const MAX_BYTES_PER_TRANSACTION: usize,
+    const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+> {
+    pub execution_payload: deneb::ExecutionPayload<
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+    >,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct FinalityCheckpointsResponse {
     pub data: FinalityCheckpoints,
@@ -76,29 +197,68 @@ pub struct FinalityCheckpoints {
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct LightClientFinalityUpdateResponse<
-    const SYNC_COMMITTEE_SIZE: usize,
-    const BYTES_PER_LOGS_BLOOM: usize,
-    const MAX_EXTRA_DATA_BYTES: usize,
-> {
-    pub data: LightClientFinalityUpdateData<
-        SYNC_COMMITTEE_SIZE,
-        BYTES_PER_LOGS_BLOOM,
-        MAX_EXTRA_DATA_BYTES,
-    >,
+pub struct SyncingStatusResponse {
+    pub data: SyncingStatus,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SyncingStatus {
+    pub head_slot: Slot,
+    pub sync_distance: U64,
+    pub is_syncing: bool,
+}
 
-pub struct LightClientFinalityUpdateData<
+/// Light client API responses are tagged with the fork of their payload, either in the `version` field
+/// or in the `Eth-Consensus-Version` header. `fulu` payloads are identical to electra.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "version", content = "data", rename_all = "lowercase")]
+pub enum LightClientFinalityUpdateResponse<
     const SYNC_COMMITTEE_SIZE: usize,
     const BYTES_PER_LOGS_BLOOM: usize,
     const MAX_EXTRA_DATA_BYTES: usize,
 > {
+    Capella(
+        LightClientFinalityUpdateData<
+            SYNC_COMMITTEE_SIZE,
+            capella::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        >,
+    ),
+    Deneb(
+        LightClientFinalityUpdateData<
+            SYNC_COMMITTEE_SIZE,
+            deneb::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        >,
+    ),
+    #[serde(alias = "fulu")]
+    Electra(
+        LightClientFinalityUpdateData<
+            SYNC_COMMITTEE_SIZE,
+            electra::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        >,
+    ),
+}
+
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    > LightClientFinalityUpdateResponse<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
+{
+    pub fn finalized_beacon_header(&self) -> &BeaconBlockHeader {
+        match self {
+            Self::Capella(data) => &data.finalized_header.beacon,
+            Self::Deneb(data) => &data.finalized_header.beacon,
+            Self::Electra(data) => &data.finalized_header.beacon,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LightClientFinalityUpdateData<const SYNC_COMMITTEE_SIZE: usize, H> {
     /// Header attested to by the sync committee
-    pub attested_header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    pub attested_header: H,
     /// Finalized header corresponding to `attested_header.state_root`
-    pub finalized_header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    pub finalized_header: H,
     /// Finality branch of the finalized header
     pub finality_branch: Vec<H256>,
     /// Sync committee aggregate signature
@@ -115,16 +275,43 @@ impl<
     From<
         LightClientFinalityUpdateData<
             SYNC_COMMITTEE_SIZE,
-            BYTES_PER_LOGS_BLOOM,
-            MAX_EXTRA_DATA_BYTES,
+            capella::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
         >,
-    > for LightClientUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
+    > for capella::LightClientUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
 {
     fn from(
         value: LightClientFinalityUpdateData<
             SYNC_COMMITTEE_SIZE,
-            BYTES_PER_LOGS_BLOOM,
-            MAX_EXTRA_DATA_BYTES,
+            capella::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        >,
+    ) -> Self {
+        Self {
+            attested_header: value.attested_header,
+            next_sync_committee: None,
+            finalized_header: value.finalized_header,
+            finality_branch: value.finality_branch,
+            sync_aggregate: value.sync_aggregate,
+            signature_slot: value.signature_slot,
+        }
+    }
+}
+
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    >
+    From<
+        LightClientFinalityUpdateData<
+            SYNC_COMMITTEE_SIZE,
+            deneb::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        >,
+    > for deneb::LightClientUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
+{
+    fn from(
+        value: LightClientFinalityUpdateData<
+            SYNC_COMMITTEE_SIZE,
+            deneb::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
         >,
     ) -> Self {
         Self {
@@ -139,22 +326,74 @@ impl<
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct LightClientBootstrapResponse<
+#[serde(tag = "version", content = "data", rename_all = "lowercase")]
+pub enum LightClientOptimisticUpdateResponse<
     const SYNC_COMMITTEE_SIZE: usize,
     const BYTES_PER_LOGS_BLOOM: usize,
     const MAX_EXTRA_DATA_BYTES: usize,
 > {
-    pub data:
-        LightClientBootstrapData<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    Capella(
+        LightClientOptimisticUpdateData<
+            SYNC_COMMITTEE_SIZE,
+            capella::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        >,
+    ),
+    Deneb(
+        LightClientOptimisticUpdateData<
+            SYNC_COMMITTEE_SIZE,
+            deneb::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        >,
+    ),
+    #[serde(alias = "fulu")]
+    Electra(
+        LightClientOptimisticUpdateData<
+            SYNC_COMMITTEE_SIZE,
+            electra::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        >,
+    ),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LightClientOptimisticUpdateData<const SYNC_COMMITTEE_SIZE: usize, H> {
+    /// Header attested to by the sync committee
+    pub attested_header: H,
+    /// Sync committee aggregate signature
+    pub sync_aggregate: SyncAggregate<SYNC_COMMITTEE_SIZE>,
+    /// Slot at which the aggregate signature was created (untrusted)
+    pub signature_slot: Slot,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct LightClientBootstrapData<
+#[serde(tag = "version", content = "data", rename_all = "lowercase")]
+pub enum LightClientBootstrapResponse<
     const SYNC_COMMITTEE_SIZE: usize,
     const BYTES_PER_LOGS_BLOOM: usize,
     const MAX_EXTRA_DATA_BYTES: usize,
 > {
-    pub header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    Capella(
+        LightClientBootstrapData<
+            SYNC_COMMITTEE_SIZE,
+            capella::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        >,
+    ),
+    Deneb(
+        LightClientBootstrapData<
+            SYNC_COMMITTEE_SIZE,
+            deneb::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        >,
+    ),
+    #[serde(alias = "fulu")]
+    Electra(
+        LightClientBootstrapData<
+            SYNC_COMMITTEE_SIZE,
+            electra::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        >,
+    ),
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct LightClientBootstrapData<const SYNC_COMMITTEE_SIZE: usize, H> {
+    pub header: H,
     pub current_sync_committee: SyncCommittee<SYNC_COMMITTEE_SIZE>,
     pub current_sync_committee_branch: Vec<H256>,
 }
@@ -164,14 +403,45 @@ impl<
         const BYTES_PER_LOGS_BLOOM: usize,
         const MAX_EXTRA_DATA_BYTES: usize,
     >
-    From<LightClientBootstrapData<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>
-    for LightClientBootstrap<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
+    From<
+        LightClientBootstrapData<
+            SYNC_COMMITTEE_SIZE,
+            capella::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        >,
+    >
+    for capella::LightClientBootstrap<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
 {
     fn from(
         value: LightClientBootstrapData<
             SYNC_COMMITTEE_SIZE,
-            BYTES_PER_LOGS_BLOOM,
-            MAX_EXTRA_DATA_BYTES,
+            capella::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        >,
+    ) -> Self {
+        Self {
+            header: value.header,
+            current_sync_committee: value.current_sync_committee,
+            current_sync_committee_branch: value.current_sync_committee_branch,
+        }
+    }
+}
+
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    >
+    From<
+        LightClientBootstrapData<
+            SYNC_COMMITTEE_SIZE,
+            deneb::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        >,
+    >
+    for deneb::LightClientBootstrap<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
+{
+    fn from(
+        value: LightClientBootstrapData<
+            SYNC_COMMITTEE_SIZE,
+            deneb::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
         >,
     ) -> Self {
         Self {
@@ -193,27 +463,41 @@ pub struct LightClientUpdatesResponse<
     >,
 );
 
+/// Each update in the range carries its own `version`, as a range may span a fork boundary
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct LightClientUpdateResponse<
+#[serde(tag = "version", content = "data", rename_all = "lowercase")]
+pub enum LightClientUpdateResponse<
     const SYNC_COMMITTEE_SIZE: usize,
     const BYTES_PER_LOGS_BLOOM: usize,
     const MAX_EXTRA_DATA_BYTES: usize,
 > {
-    pub version: String,
-    pub data:
-        LightClientUpdateData<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    Capella(
+        LightClientUpdateData<
+            SYNC_COMMITTEE_SIZE,
+            capella::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        >,
+    ),
+    Deneb(
+        LightClientUpdateData<
+            SYNC_COMMITTEE_SIZE,
+            deneb::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        >,
+    ),
+    #[serde(alias = "fulu")]
+    Electra(
+        LightClientUpdateData<
+            SYNC_COMMITTEE_SIZE,
+            electra::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        >,
+    ),
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct LightClientUpdateData<
-    const SYNC_COMMITTEE_SIZE: usize,
-    const BYTES_PER_LOGS_BLOOM: usize,
-    const MAX_EXTRA_DATA_BYTES: usize,
-> {
-    pub attested_header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+pub struct LightClientUpdateData<const SYNC_COMMITTEE_SIZE: usize, H> {
+    pub attested_header: H,
     pub next_sync_committee: SyncCommittee<SYNC_COMMITTEE_SIZE>,
     pub next_sync_committee_branch: Vec<H256>,
-    pub finalized_header: LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+    pub finalized_header: H,
     pub finality_branch: Vec<H256>,
     pub sync_aggregate: SyncAggregate<SYNC_COMMITTEE_SIZE>,
     pub signature_slot: Slot,
@@ -223,14 +507,52 @@ impl<
         const SYNC_COMMITTEE_SIZE: usize,
         const BYTES_PER_LOGS_BLOOM: usize,
         const MAX_EXTRA_DATA_BYTES: usize,
-    > From<LightClientUpdateData<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>>
-    for LightClientUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
+    >
+    From<
+        LightClientUpdateData<
+            SYNC_COMMITTEE_SIZE,
+            capella::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        >,
+    > for capella::LightClientUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
 {
     fn from(
         value: LightClientUpdateData<
             SYNC_COMMITTEE_SIZE,
-            BYTES_PER_LOGS_BLOOM,
-            MAX_EXTRA_DATA_BYTES,
+            capella::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        >,
+    ) -> Self {
+        let next_sync_committee = if value.next_sync_committee == Default::default() {
+            None
+        } else {
+            Some((value.next_sync_committee, value.next_sync_committee_branch))
+        };
+        Self {
+            attested_header: value.attested_header,
+            next_sync_committee,
+            finalized_header: value.finalized_header,
+            finality_branch: value.finality_branch,
+            sync_aggregate: value.sync_aggregate,
+            signature_slot: value.signature_slot,
+        }
+    }
+}
+
+impl<
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    >
+    From<
+        LightClientUpdateData<
+            SYNC_COMMITTEE_SIZE,
+            deneb::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
+        >,
+    > for deneb::LightClientUpdate<SYNC_COMMITTEE_SIZE, BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>
+{
+    fn from(
+        value: LightClientUpdateData<
+            SYNC_COMMITTEE_SIZE,
+            deneb::LightClientHeader<BYTES_PER_LOGS_BLOOM, MAX_EXTRA_DATA_BYTES>,
         >,
     ) -> Self {
         let next_sync_committee = if value.next_sync_committee == Default::default() {
@@ -248,3 +570,47 @@ impl<
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consensus::src::types::ByteList;
+    use serde_json::json;
+
+    type Payload = BeaconBlockPayloadResponse<256, 32, 1073741824, 1048576, 16>;
+
+    #[test]
+    fn test_decode_block_payload() {
+        let mut execution_payload = deneb::ExecutionPayload::<256, 32, 1073741824, 1048576, 16>::default();
+        execution_payload.block_number = 64.into();
+        execution_payload.transactions =
+            ssz_rs::List::try_from(vec![ByteList::try_from([2, 1].as_slice()).unwrap()]).unwrap();
+        // the electra body fields that do not decode as deneb are skipped
+        let block = |version: &str| {
+            json!({
+                "version": version,
+                "execution_optimistic": false,
+                "finalized": true,
+                "data": {
+                    "message": {
+                        "slot": "64",
+                        "proposer_index": "1",
+                        "body": {
+                            "attestations": [{"aggregation_bits": "0x01", "committee_bits": "0x01"}],
+                            "execution_payload": execution_payload,
+                            "execution_requests": {"deposits": [], "withdrawals": [], "consolidations": []},
+                        },
+                    },
+                    "signature": "0x00",
+                },
+            })
+        };
+        for (version, electra) in [("deneb", false), ("electra", true), ("fulu", true)] {
+            let res: Payload = serde_json::from_value(block(version)).unwrap();
+            assert_eq!(matches!(res, Payload::Electra(_)), electra, "{}", version);
+            assert_eq!(res.slot(), 64.into());
+            assert_eq!(res.execution_payload(), &execution_payload);
+        }
+        assert!(serde_json::from_value::<Payload>(block("capella")).is_err());
+    }
+}
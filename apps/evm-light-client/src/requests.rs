@@ -0,0 +1,806 @@
+use crate::light_client_cli::src::{
+    acl::Role,
+    chain::Network,
+    cli::{Cli, Opts},
+    commands::{
+        AccountCommand, AclCommand, AddMemberCommand, AuditCommand, BlockCommand, ClaimOwnerCommand, CommitCommand, Command, ExecutionHeaderCommand, FinalizedHeaderCommand,
+        HeaderCommand, InitCommand, MisbehaviourCommand, OptimisticUpdateCommand, PersistCommand, ReceiptCommand,
+        RemoveMemberCommand, StateCommand, StorageCommand, TransactionCommand, UnfreezeCommand, UpdateCommand,
+    },
+    state::Anchor,
+};
+use serde::{de, Deserialize, Deserializer};
+use serde_json::Value;
+use std::str::FromStr;
+
+static LEDGER_TABLE: &str = "light_client";
+
+/// Request is the JSON input of a route, which is validated and mapped to a command
+pub trait Request: de::DeserializeOwned {
+    /// whether the command fetches from the beacon endpoint
+    const REQUIRES_BEACON_ENDPOINT: bool = true;
+
+    fn network(&self) -> &NetworkParams;
+
+    fn into_command(self) -> Result<Command, String>;
+
+    fn parse(cmd: &str) -> Result<Cli, String> {
+        let request: Self =
+            serde_json::from_str(cmd).map_err(|e| format!("invalid request: {}", e))?;
+        request.into_cli()
+    }
+
+    fn into_cli(self) -> Result<Cli, String> {
+        let opts = self.network().opts(Self::REQUIRES_BEACON_ENDPOINT)?;
+        Ok(Cli {
+            opts,
+            cmd: self.into_command()?,
+        })
+    }
+}
+
+/// NetworkParams are the parameters common to all routes
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct NetworkParams {
+    pub network_name: String,
+    /// endpoint or array of endpoints, tried in order
+    #[serde(default)]
+    pub beacon_endpoint: Option<Endpoints>,
+}
+
+impl NetworkParams {
+    fn opts(&self, requires_beacon_endpoint: bool) -> Result<Opts, String> {
+        Network::from_str(&self.network_name).map_err(|e| e.to_string())?;
+        let beacon_endpoint = match &self.beacon_endpoint {
+            Some(Endpoints::One(endpoint)) => endpoint.clone(),
+            Some(Endpoints::Many(endpoints)) => endpoints.join(","),
+            None => String::default(),
+        };
+        if requires_beacon_endpoint && beacon_endpoint.is_empty() {
+            return Err("beacon_endpoint not found".into());
+        }
+        Ok(Opts {
+            ledger_table: format!("{}_{}", LEDGER_TABLE, self.network_name),
+            beacon_endpoint,
+            network: self.network_name.clone(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum Endpoints {
+    One(String),
+    Many(Vec<String>),
+}
+
+/// Quantity is an integer given as a JSON number, a decimal string or a 0x-prefixed hex string
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Quantity(pub u64);
+
+impl<'de> Deserialize<'de> for Quantity {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match Value::deserialize(deserializer)? {
+            Value::Number(n) => n
+                .as_u64()
+                .map(Quantity)
+                .ok_or_else(|| de::Error::custom(format!("invalid quantity: {}", n))),
+            Value::String(s) => match s.strip_prefix("0x") {
+                Some(hex) => u64::from_str_radix(hex, 16),
+                None => s.parse(),
+            }
+            .map(Quantity)
+            .map_err(|_| de::Error::custom(format!("invalid quantity: {}", s))),
+            v => Err(de::Error::custom(format!("invalid quantity: {}", v))),
+        }
+    }
+}
+
+/// JsonArg is a JSON argument of a command, given either as JSON or as a string of JSON
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JsonArg(pub String);
+
+impl<'de> Deserialize<'de> for JsonArg {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match Value::deserialize(deserializer)? {
+            Value::Null => Err(de::Error::custom("invalid json argument: null")),
+            Value::String(s) => Ok(JsonArg(s)),
+            v => Ok(JsonArg(v.to_string())),
+        }
+    }
+}
+
+fn json_arg(arg: Option<JsonArg>) -> Option<String> {
+    arg.map(|arg| arg.0)
+}
+
+fn quantity(value: Option<Quantity>) -> Option<u64> {
+    value.map(|q| q.0)
+}
+
+/// checks that `value` is a 0x-prefixed hex string of `len` bytes
+fn validate_hex(name: &str, value: &str, len: usize) -> Result<(), String> {
+    match value.strip_prefix("0x").map(hex::decode) {
+        Some(Ok(bytes)) if bytes.len() == len => Ok(()),
+        _ => Err(format!("{} must be a 0x-prefixed hex string of {} bytes: {}", name, len, value)),
+    }
+}
+
+fn validate_anchor(anchor: &Option<String>) -> Result<(), String> {
+    match anchor {
+        Some(anchor) => Anchor::from_str(anchor).map(|_| ()).map_err(|e| e.to_string()),
+        None => Ok(()),
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct InitRequest {
+    #[serde(flatten)]
+    pub network: NetworkParams,
+    pub trusted_block_root: Option<String>,
+    /// the trusted block root is the root of the header at this slot
+    pub untrusted_slot: Option<Quantity>,
+}
+
+impl Request for InitRequest {
+    fn network(&self) -> &NetworkParams {
+        &self.network
+    }
+
+    fn into_command(self) -> Result<Command, String> {
+        if let Some(root) = &self.trusted_block_root {
+            validate_hex("trusted_block_root", root, 32)?;
+        }
+        Ok(Command::Init(InitCommand {
+            trusted_block_root: self.trusted_block_root,
+            untrusted_slot: quantity(self.untrusted_slot),
+        }))
+    }
+}
+
+/// the fields are the `data` of the response of an update or init route.
+/// Without a ticket, the state is derived from the bootstrap or the updates and `state_info` is only checked against it
+#[derive(Clone, Debug, Deserialize)]
+pub struct PersistRequest {
+    #[serde(flatten)]
+    pub network: NetworkParams,
+    pub bootstrap_info: Option<JsonArg>,
+    pub state_info: Option<JsonArg>,
+    pub genesis_info: Option<JsonArg>,
+    /// array of the consensus updates that led to `state_info`, appended to the archive
+    pub updates_info: Option<JsonArg>,
+    pub optimistic_update_info: Option<JsonArg>,
+    /// ticket of the query that returned `state_info`, the state is then persisted without being verified again
+    pub ticket: Option<JsonArg>,
+}
+
+impl Request for PersistRequest {
+    fn network(&self) -> &NetworkParams {
+        &self.network
+    }
+
+    fn into_command(self) -> Result<Command, String> {
+        Ok(Command::Persist(PersistCommand {
+            bootstrap_info: json_arg(self.bootstrap_info),
+            state_info: json_arg(self.state_info),
+            genesis_info: json_arg(self.genesis_info),
+            updates_info: json_arg(self.updates_info),
+            optimistic_update_info: json_arg(self.optimistic_update_info),
+            ticket: json_arg(self.ticket),
+        }))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UpdateRequest {
+    #[serde(flatten)]
+    pub network: NetworkParams,
+    /// beacon endpoints the updates are cross-checked against
+    #[serde(default)]
+    pub secondary_beacon_endpoints: Vec<String>,
+}
+
+impl Request for UpdateRequest {
+    fn network(&self) -> &NetworkParams {
+        &self.network
+    }
+
+    fn into_command(self) -> Result<Command, String> {
+        Ok(Command::Update(UpdateCommand {
+            secondary_beacon_endpoints: self.secondary_beacon_endpoints,
+            target: None,
+        }))
+    }
+}
+
+/// the beacon endpoint is only required to fetch the updates when `updates_info` is not given
+#[derive(Clone, Debug, Deserialize)]
+pub struct UpdateAndCommitRequest {
+    #[serde(flatten)]
+    pub network: NetworkParams,
+    /// the commit fails if the stored finalized slot is another one
+    pub expected_finalized_slot: Quantity,
+    /// array of consensus updates, e.g. the `updates_info` of an update route
+    pub updates_info: Option<JsonArg>,
+    /// beacon endpoints the fetched updates are cross-checked against
+    #[serde(default)]
+    pub secondary_beacon_endpoints: Vec<String>,
+}
+
+impl Request for UpdateAndCommitRequest {
+    const REQUIRES_BEACON_ENDPOINT: bool = false;
+
+    fn network(&self) -> &NetworkParams {
+        &self.network
+    }
+
+    fn into_command(self) -> Result<Command, String> {
+        if self.updates_info.is_none() && self.network.beacon_endpoint.is_none() {
+            return Err("either updates_info or beacon_endpoint is required".into());
+        }
+        Ok(Command::Commit(CommitCommand {
+            expected_finalized_slot: self.expected_finalized_slot.0,
+            updates_info: json_arg(self.updates_info),
+            secondary_beacon_endpoints: self.secondary_beacon_endpoints,
+        }))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct OptimisticUpdateRequest {
+    #[serde(flatten)]
+    pub network: NetworkParams,
+}
+
+impl Request for OptimisticUpdateRequest {
+    fn network(&self) -> &NetworkParams {
+        &self.network
+    }
+
+    fn into_command(self) -> Result<Command, String> {
+        Ok(Command::OptimisticUpdate(OptimisticUpdateCommand {}))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UpdateForBlockNumberRequest {
+    #[serde(flatten)]
+    pub network: NetworkParams,
+    #[serde(default)]
+    pub secondary_beacon_endpoints: Vec<String>,
+    pub block_number: Quantity,
+}
+
+impl Request for UpdateForBlockNumberRequest {
+    fn network(&self) -> &NetworkParams {
+        &self.network
+    }
+
+    fn into_command(self) -> Result<Command, String> {
+        Ok(Command::Update(UpdateCommand {
+            secondary_beacon_endpoints: self.secondary_beacon_endpoints,
+            target: Some(format!("{}bn", self.block_number.0)),
+        }))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UpdateForPeriodRequest {
+    #[serde(flatten)]
+    pub network: NetworkParams,
+    #[serde(default)]
+    pub secondary_beacon_endpoints: Vec<String>,
+    pub period: Quantity,
+}
+
+impl Request for UpdateForPeriodRequest {
+    fn network(&self) -> &NetworkParams {
+        &self.network
+    }
+
+    fn into_command(self) -> Result<Command, String> {
+        Ok(Command::Update(UpdateCommand {
+            secondary_beacon_endpoints: self.secondary_beacon_endpoints,
+            target: Some(format!("{}period", self.period.0)),
+        }))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UpdateForSlotRequest {
+    #[serde(flatten)]
+    pub network: NetworkParams,
+    #[serde(default)]
+    pub secondary_beacon_endpoints: Vec<String>,
+    pub slot: Quantity,
+}
+
+impl Request for UpdateForSlotRequest {
+    fn network(&self) -> &NetworkParams {
+        &self.network
+    }
+
+    fn into_command(self) -> Result<Command, String> {
+        Ok(Command::Update(UpdateCommand {
+            secondary_beacon_endpoints: self.secondary_beacon_endpoints,
+            target: Some(format!("{}slot", self.slot.0)),
+        }))
+    }
+}
+
+/// without a slot, the latest finalized header is fetched
+#[derive(Clone, Debug, Deserialize)]
+pub struct FetchHeaderRequest {
+    #[serde(flatten)]
+    pub network: NetworkParams,
+    pub slot: Option<Quantity>,
+}
+
+impl Request for FetchHeaderRequest {
+    fn network(&self) -> &NetworkParams {
+        &self.network
+    }
+
+    fn into_command(self) -> Result<Command, String> {
+        Ok(Command::Header(HeaderCommand {
+            slot: quantity(self.slot),
+        }))
+    }
+}
+
+/// without a slot, the latest finalized block is fetched
+#[derive(Clone, Debug, Deserialize)]
+pub struct FetchBlockRequest {
+    #[serde(flatten)]
+    pub network: NetworkParams,
+    pub slot: Option<Quantity>,
+}
+
+impl Request for FetchBlockRequest {
+    fn network(&self) -> &NetworkParams {
+        &self.network
+    }
+
+    fn into_command(self) -> Result<Command, String> {
+        Ok(Command::Block(BlockCommand {
+            slot: quantity(self.slot),
+        }))
+    }
+}
+
+/// the verified reads are anchored to the latest finalized header, or to the optimistic one with `anchor`
+#[derive(Clone, Debug, Deserialize)]
+pub struct VerifyAccountRequest {
+    #[serde(flatten)]
+    pub network: NetworkParams,
+    pub address: String,
+    pub block_number: Option<Quantity>,
+    pub anchor: Option<String>,
+    /// eth_getProof response, otherwise fetched from `execution_endpoint`
+    pub account_proof: Option<JsonArg>,
+    pub execution_endpoint: Option<String>,
+}
+
+impl Request for VerifyAccountRequest {
+    const REQUIRES_BEACON_ENDPOINT: bool = false;
+
+    fn network(&self) -> &NetworkParams {
+        &self.network
+    }
+
+    fn into_command(self) -> Result<Command, String> {
+        validate_hex("address", &self.address, 20)?;
+        validate_anchor(&self.anchor)?;
+        Ok(Command::Account(AccountCommand {
+            address: self.address,
+            block_number: quantity(self.block_number),
+            anchor: self.anchor,
+            account_proof: json_arg(self.account_proof),
+            execution_endpoint: self.execution_endpoint,
+        }))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct VerifyStorageRequest {
+    #[serde(flatten)]
+    pub network: NetworkParams,
+    pub address: String,
+    /// 0x-prefixed storage slots
+    pub slots: Vec<String>,
+    pub block_number: Option<Quantity>,
+    pub anchor: Option<String>,
+    /// eth_getProof response including the storage proofs, otherwise fetched from `execution_endpoint`
+    pub account_proof: Option<JsonArg>,
+    pub execution_endpoint: Option<String>,
+}
+
+impl Request for VerifyStorageRequest {
+    const REQUIRES_BEACON_ENDPOINT: bool = false;
+
+    fn network(&self) -> &NetworkParams {
+        &self.network
+    }
+
+    fn into_command(self) -> Result<Command, String> {
+        validate_hex("address", &self.address, 20)?;
+        validate_anchor(&self.anchor)?;
+        if self.slots.is_empty() {
+            return Err("slots must not be empty".into());
+        }
+        Ok(Command::Storage(StorageCommand {
+            address: self.address,
+            slots: self.slots,
+            block_number: quantity(self.block_number),
+            anchor: self.anchor,
+            account_proof: json_arg(self.account_proof),
+            execution_endpoint: self.execution_endpoint,
+        }))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct VerifyLogRequest {
+    #[serde(flatten)]
+    pub network: NetworkParams,
+    /// {address, topics, data}
+    pub log: JsonArg,
+    pub tx_index: Option<Quantity>,
+    pub tx_hash: Option<String>,
+    /// index of the log in the receipt, otherwise the first matching log
+    pub log_index: Option<Quantity>,
+    pub block_number: Option<Quantity>,
+    pub anchor: Option<String>,
+    /// array of 0x-prefixed receipt trie nodes, otherwise built from `execution_endpoint`
+    pub receipt_proof: Option<JsonArg>,
+    pub execution_endpoint: Option<String>,
+}
+
+impl Request for VerifyLogRequest {
+    const REQUIRES_BEACON_ENDPOINT: bool = false;
+
+    fn network(&self) -> &NetworkParams {
+        &self.network
+    }
+
+    fn into_command(self) -> Result<Command, String> {
+        if let Some(tx_hash) = &self.tx_hash {
+            validate_hex("tx_hash", tx_hash, 32)?;
+        }
+        validate_anchor(&self.anchor)?;
+        if self.tx_index.is_none() && self.tx_hash.is_none() {
+            return Err("either tx_index or tx_hash is required".into());
+        }
+        Ok(Command::Receipt(ReceiptCommand {
+            log: self.log.0,
+            tx_index: quantity(self.tx_index),
+            tx_hash: self.tx_hash,
+            log_index: quantity(self.log_index).map(|i| i as usize),
+            block_number: quantity(self.block_number),
+            anchor: self.anchor,
+            receipt_proof: json_arg(self.receipt_proof),
+            execution_endpoint: self.execution_endpoint,
+        }))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct VerifyExecutionHeaderRequest {
+    #[serde(flatten)]
+    pub network: NetworkParams,
+    pub block_number: Option<Quantity>,
+    pub anchor: Option<String>,
+    /// eth_getBlockByNumber response, otherwise fetched from `execution_endpoint`
+    pub block: Option<JsonArg>,
+    pub execution_endpoint: Option<String>,
+}
+
+impl Request for VerifyExecutionHeaderRequest {
+    const REQUIRES_BEACON_ENDPOINT: bool = false;
+
+    fn network(&self) -> &NetworkParams {
+        &self.network
+    }
+
+    fn into_command(self) -> Result<Command, String> {
+        validate_anchor(&self.anchor)?;
+        Ok(Command::ExecutionHeader(ExecutionHeaderCommand {
+            block_number: quantity(self.block_number),
+            anchor: self.anchor,
+            block: json_arg(self.block),
+            execution_endpoint: self.execution_endpoint,
+        }))
+    }
+}
+
+/// the beacon endpoint is only required to build the proof from the anchored beacon block
+#[derive(Clone, Debug, Deserialize)]
+pub struct VerifyTransactionRequest {
+    #[serde(flatten)]
+    pub network: NetworkParams,
+    pub tx_hash: Option<String>,
+    pub tx_index: Option<Quantity>,
+    pub block_number: Option<Quantity>,
+    pub anchor: Option<String>,
+    /// 0x-prefixed envelope, given with `transaction_proof`
+    pub transaction: Option<String>,
+    /// array of 0x-prefixed branch nodes against the transactions root
+    pub transaction_proof: Option<JsonArg>,
+}
+
+impl Request for VerifyTransactionRequest {
+    const REQUIRES_BEACON_ENDPOINT: bool = false;
+
+    fn network(&self) -> &NetworkParams {
+        &self.network
+    }
+
+    fn into_command(self) -> Result<Command, String> {
+        if let Some(tx_hash) = &self.tx_hash {
+            validate_hex("tx_hash", tx_hash, 32)?;
+        }
+        validate_anchor(&self.anchor)?;
+        Ok(Command::Transaction(TransactionCommand {
+            tx_hash: self.tx_hash,
+            tx_index: quantity(self.tx_index),
+            block_number: quantity(self.block_number),
+            anchor: self.anchor,
+            transaction: self.transaction,
+            transaction_proof: json_arg(self.transaction_proof),
+        }))
+    }
+}
+
+/// only reads the ledger, so no beacon endpoint is needed
+#[derive(Clone, Debug, Deserialize)]
+pub struct GetStateRequest {
+    #[serde(flatten)]
+    pub network: NetworkParams,
+}
+
+impl Request for GetStateRequest {
+    const REQUIRES_BEACON_ENDPOINT: bool = false;
+
+    fn network(&self) -> &NetworkParams {
+        &self.network
+    }
+
+    fn into_command(self) -> Result<Command, String> {
+        Ok(Command::State(StateCommand {}))
+    }
+}
+
+/// replays the archived updates, so no beacon endpoint is needed
+#[derive(Clone, Debug, Deserialize)]
+pub struct AuditRequest {
+    #[serde(flatten)]
+    pub network: NetworkParams,
+}
+
+impl Request for AuditRequest {
+    const REQUIRES_BEACON_ENDPOINT: bool = false;
+
+    fn network(&self) -> &NetworkParams {
+        &self.network
+    }
+
+    fn into_command(self) -> Result<Command, String> {
+        Ok(Command::Audit(AuditCommand {}))
+    }
+}
+
+/// reads a past finalized header from the historical index of the ledger
+#[derive(Clone, Debug, Deserialize)]
+pub struct GetHeaderAtSlotRequest {
+    #[serde(flatten)]
+    pub network: NetworkParams,
+    pub slot: Quantity,
+}
+
+impl Request for GetHeaderAtSlotRequest {
+    const REQUIRES_BEACON_ENDPOINT: bool = false;
+
+    fn network(&self) -> &NetworkParams {
+        &self.network
+    }
+
+    fn into_command(self) -> Result<Command, String> {
+        Ok(Command::FinalizedHeader(FinalizedHeaderCommand {
+            slot: Some(self.slot.0),
+            block_number: None,
+        }))
+    }
+}
+
+/// the returned header carries the verified state root of the block
+#[derive(Clone, Debug, Deserialize)]
+pub struct GetHeaderAtBlockNumberRequest {
+    #[serde(flatten)]
+    pub network: NetworkParams,
+    pub block_number: Quantity,
+}
+
+impl Request for GetHeaderAtBlockNumberRequest {
+    const REQUIRES_BEACON_ENDPOINT: bool = false;
+
+    fn network(&self) -> &NetworkParams {
+        &self.network
+    }
+
+    fn into_command(self) -> Result<Command, String> {
+        Ok(Command::FinalizedHeader(FinalizedHeaderCommand {
+            slot: None,
+            block_number: Some(self.block_number.0),
+        }))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SubmitMisbehaviourRequest {
+    #[serde(flatten)]
+    pub network: NetworkParams,
+    /// {"FinalizedHeader": {consensus_update_1, consensus_update_2}} or {"NextSyncCommittee": {..}}
+    pub misbehaviour: JsonArg,
+}
+
+impl Request for SubmitMisbehaviourRequest {
+    const REQUIRES_BEACON_ENDPOINT: bool = false;
+
+    fn network(&self) -> &NetworkParams {
+        &self.network
+    }
+
+    fn into_command(self) -> Result<Command, String> {
+        Ok(Command::Misbehaviour(MisbehaviourCommand {
+            misbehaviour: self.misbehaviour.0,
+        }))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UnfreezeRequest {
+    #[serde(flatten)]
+    pub network: NetworkParams,
+}
+
+impl Request for UnfreezeRequest {
+    const REQUIRES_BEACON_ENDPOINT: bool = false;
+
+    fn network(&self) -> &NetworkParams {
+        &self.network
+    }
+
+    fn into_command(self) -> Result<Command, String> {
+        Ok(Command::Unfreeze(UnfreezeCommand {}))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AddMemberRequest {
+    #[serde(flatten)]
+    pub network: NetworkParams,
+    /// Klave sender identity of the member
+    pub member: String,
+    /// reader, updater, admin or owner
+    pub role: String,
+}
+
+impl Request for AddMemberRequest {
+    const REQUIRES_BEACON_ENDPOINT: bool = false;
+
+    fn network(&self) -> &NetworkParams {
+        &self.network
+    }
+
+    fn into_command(self) -> Result<Command, String> {
+        Role::from_str(&self.role).map_err(|e| e.to_string())?;
+        Ok(Command::AddMember(AddMemberCommand {
+            member: self.member,
+            role: self.role,
+        }))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RemoveMemberRequest {
+    #[serde(flatten)]
+    pub network: NetworkParams,
+    pub member: String,
+}
+
+impl Request for RemoveMemberRequest {
+    const REQUIRES_BEACON_ENDPOINT: bool = false;
+
+    fn network(&self) -> &NetworkParams {
+        &self.network
+    }
+
+    fn into_command(self) -> Result<Command, String> {
+        Ok(Command::RemoveMember(RemoveMemberCommand {
+            member: self.member,
+        }))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct GetAclRequest {
+    #[serde(flatten)]
+    pub network: NetworkParams,
+}
+
+impl Request for GetAclRequest {
+    const REQUIRES_BEACON_ENDPOINT: bool = false;
+
+    fn network(&self) -> &NetworkParams {
+        &self.network
+    }
+
+    fn into_command(self) -> Result<Command, String> {
+        Ok(Command::Acl(AclCommand {}))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ClaimOwnerRequest {
+    #[serde(flatten)]
+    pub network: NetworkParams,
+}
+
+impl Request for ClaimOwnerRequest {
+    const REQUIRES_BEACON_ENDPOINT: bool = false;
+
+    fn network(&self) -> &NetworkParams {
+        &self.network
+    }
+
+    fn into_command(self) -> Result<Command, String> {
+        Ok(Command::ClaimOwner(ClaimOwnerCommand {}))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quantity() {
+        for v in [r#"16"#, r#""16""#, r#""0x10""#] {
+            assert_eq!(serde_json::from_str::<Quantity>(v).unwrap(), Quantity(16));
+        }
+        for v in [r#"-1"#, r#""0xzz""#, r#""""#, r#"null"#] {
+            assert!(serde_json::from_str::<Quantity>(v).is_err());
+        }
+    }
+
+    #[test]
+    fn test_parse_request() {
+        let cli = UpdateForPeriodRequest::parse(
+            r#"{"network_name": "mainnet", "beacon_endpoint": ["https://a", "https://b"], "period": "0x10"}"#,
+        )
+        .unwrap();
+        assert_eq!(cli.opts.beacon_endpoint, "https://a,https://b");
+        assert_eq!(cli.opts.ledger_table, "light_client_mainnet");
+        assert!(matches!(cli.cmd, Command::Update(UpdateCommand { target: Some(t), .. }) if t == "16period"));
+
+        // the proof may be given as JSON or as a string of JSON
+        let cli = VerifyAccountRequest::parse(
+            r#"{"network_name": "mainnet", "address": "0x0000000000000000000000000000000000000001", "account_proof": {"balance": "0x0"}}"#,
+        )
+        .unwrap();
+        assert!(matches!(cli.cmd, Command::Account(AccountCommand { account_proof: Some(p), .. }) if p == r#"{"balance":"0x0"}"#));
+
+        assert!(UpdateRequest::parse(r#"{"network_name": "mainnet"}"#).is_err());
+        assert!(UpdateAndCommitRequest::parse(r#"{"network_name": "mainnet", "expected_finalized_slot": 1}"#).is_err());
+        assert!(UpdateAndCommitRequest::parse(
+            r#"{"network_name": "mainnet", "expected_finalized_slot": 1, "updates_info": []}"#
+        )
+        .is_ok());
+        assert!(UpdateRequest::parse(r#"{"network_name": "unknown", "beacon_endpoint": "https://a"}"#).is_err());
+        assert!(VerifyAccountRequest::parse(r#"{"network_name": "mainnet", "address": "0x01"}"#).is_err());
+        assert!(VerifyAccountRequest::parse(
+            r#"{"network_name": "mainnet", "address": "0x0000000000000000000000000000000000000001", "anchor": "latest"}"#
+        )
+        .is_err());
+        assert!(AddMemberRequest::parse(r#"{"network_name": "mainnet", "member": "a", "role": "superuser"}"#).is_err());
+    }
+}